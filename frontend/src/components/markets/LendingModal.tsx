@@ -65,10 +65,6 @@ export const LendingModal: React.FC<LendingModalProps> = ({
         const tokenQuantity = parseFloat(amount);
         const pricePerToken = 0.00001; // Target: 0.00001 SOL per token
 
-        // Get admin wallet (issuer) - should come from SRWA token data
-        // For now, use hardcoded devnet admin
-        const adminVault = new PublicKey('9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin');
-
         // Get token mint
         const tokenMint = new PublicKey(srwaPool.tokenContract);
 
@@ -112,14 +108,12 @@ export const LendingModal: React.FC<LendingModalProps> = ({
           actualPricePerToken: actualPricePerToken,
           actualTotalSol: actualTotalSol,
           totalLamports: quantityInAtomicUnits * pricePerAtomicUnitLamports,
-          adminVault: adminVault.toBase58(),
         });
 
         const result = await createOrder({
           mint: tokenMint,
           quantity: quantityInAtomicUnits,
           pricePerTokenLamports: pricePerAtomicUnitLamports,
-          adminVault,
         });
 
         toast.success('✅ Purchase order created!', {
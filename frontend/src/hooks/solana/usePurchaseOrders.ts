@@ -14,6 +14,7 @@ import { toast } from 'sonner';
 import { getConfig, MarginfiClient } from '@mrgnlabs/marginfi-client-v2';
 
 const PURCHASE_ORDER_SEED = 'purchase_order';
+const ORDER_ESCROW_SEED = 'order_escrow';
 const WSOL_MINT_DEVNET = new PublicKey('So11111111111111111111111111111111111111112');
 
 export interface PurchaseOrderAccount {
@@ -25,7 +26,11 @@ export interface PurchaseOrderAccount {
     quantity: BN;
     pricePerTokenLamports: BN;
     totalLamports: BN;
-    status: { pending: {} } | { approved: {} } | { rejected: {} } | { cancelled: {} };
+    quoteMint: PublicKey | null;
+    maxPricePerToken: BN | null;
+    filledQuantity: BN;
+    expiresAt: BN | null;
+    status: { pending: {} } | { approved: {} } | { rejected: {} } | { cancelled: {} } | { expired: {} };
     createdAt: BN;
     updatedAt: BN;
     processedBy: PublicKey | null;
@@ -34,7 +39,7 @@ export interface PurchaseOrderAccount {
   };
 }
 
-export type PurchaseOrderStatus = 'pending' | 'approved' | 'rejected' | 'cancelled';
+export type PurchaseOrderStatus = 'pending' | 'approved' | 'rejected' | 'cancelled' | 'expired';
 
 export function usePurchaseOrders() {
   const { connection } = useConnection();
@@ -82,7 +87,8 @@ export function usePurchaseOrders() {
       mint: PublicKey;
       quantity: number;
       pricePerTokenLamports: number;
-      adminVault: PublicKey;
+      /** Unix timestamp (seconds) after which anyone can expire the order and refund it */
+      expiresAt?: number;
     }) => {
       if (!publicKey || !signTransaction || !program) {
         throw new Error('Wallet not connected or program not loaded');
@@ -97,7 +103,7 @@ export function usePurchaseOrders() {
       try {
         isCreatingOrder.current = true;
 
-        const { mint, quantity, pricePerTokenLamports, adminVault } = params;
+        const { mint, quantity, pricePerTokenLamports, expiresAt } = params;
 
         console.log('[usePurchaseOrders] Checking program provider:', {
           hasProgram: !!program,
@@ -140,18 +146,36 @@ export function usePurchaseOrders() {
           program.programId
         );
 
+        // The order's SOL is held by its escrow until approval or refund
+        const [escrowPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from(ORDER_ESCROW_SEED), purchaseOrderPda.toBuffer()],
+          program.programId
+        );
+
+        // Offering of the mint, read on-chain only if it exists
+        const [offeringPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from('offering'), mint.toBuffer()],
+          new PublicKey(PROGRAM_IDS.srwaFactory)
+        );
+
         console.log('[usePurchaseOrders] PDA:', purchaseOrderPda.toBase58());
 
         let signature: string;
 
         try {
           signature = await program.methods
-            .createPurchaseOrder(new BN(quantity), new BN(pricePerTokenLamports), new BN(timestamp))
-            .accounts({
+            .createPurchaseOrder(
+              new BN(quantity),
+              new BN(pricePerTokenLamports),
+              new BN(timestamp),
+              expiresAt != null ? new BN(expiresAt) : null
+            )
+            .accountsPartial({
               investor: publicKey,
               mint,
+              offering: offeringPda,
               purchaseOrder: purchaseOrderPda,
-              adminVault,
+              escrow: escrowPda,
               systemProgram: SystemProgram.programId,
             })
             .rpc({
@@ -548,6 +572,7 @@ export function usePurchaseOrders() {
     if ('approved' in status) return 'approved';
     if ('rejected' in status) return 'rejected';
     if ('cancelled' in status) return 'cancelled';
+    if ('expired' in status) return 'expired';
     return 'pending';
   };
 
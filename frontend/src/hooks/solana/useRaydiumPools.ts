@@ -3,6 +3,47 @@ import { PublicKey, SystemProgram } from '@solana/web3.js';
 import { useProgramsSafe } from '@/contexts/ProgramContext';
 import { useWallet } from '@/contexts/wallet/WalletContext';
 import { useQuery, useQueryClient } from '@tanstack/react-query';
+import { PROGRAM_IDS } from '@/lib/solana/anchor';
+
+const POOL_VAULT_SEED = 'pool_vault';
+
+/**
+ * Accounts that authorize a pool admin and allowlist the pool vaults as
+ * venues of the mint's transfer hook. The admin registry is only passed
+ * when it exists (platform admins); the issuer admin does not need it.
+ */
+async function venueAdminAccounts(programs: any, tokenMint: PublicKey) {
+  const factoryProgramId = new PublicKey(PROGRAM_IDS.srwaFactory);
+  const complianceProgramId = new PublicKey(PROGRAM_IDS.complianceModules);
+
+  const [srwaConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from('srwa_config'), tokenMint.toBuffer()],
+    factoryProgramId
+  );
+  const [adminRegistryPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('admin_registry')],
+    factoryProgramId
+  );
+  const [programAllowlist] = PublicKey.findProgramAddressSync(
+    [Buffer.from('program_allowlist'), tokenMint.toBuffer()],
+    complianceProgramId
+  );
+  const [accountAllowlist] = PublicKey.findProgramAddressSync(
+    [Buffer.from('account_allowlist'), tokenMint.toBuffer()],
+    complianceProgramId
+  );
+
+  const registryInfo = await programs.yieldAdapter.provider.connection.getAccountInfo(adminRegistryPda);
+
+  return {
+    srwaConfig,
+    adminRegistry: registryInfo ? adminRegistryPda : null,
+    programAllowlist,
+    accountAllowlist,
+    complianceProgram: complianceProgramId,
+    systemProgram: SystemProgram.programId,
+  };
+}
 
 export interface RaydiumPoolAccount {
   publicKey: PublicKey;
@@ -95,20 +136,41 @@ export function useRaydiumPools() {
           programs.yieldAdapter.programId
         );
 
+        // The pool state is owned by the Raydium program that created it,
+        // which also owns the pool vaults
+        const poolState = await programs.yieldAdapter.provider.connection.getAccountInfo(poolId);
+        if (!poolState) {
+          throw new Error(`Pool ${poolId.toBase58()} not found`);
+        }
+        const ammProgram = poolState.owner;
+        const [tokenVault] = PublicKey.findProgramAddressSync(
+          [Buffer.from(POOL_VAULT_SEED), poolId.toBuffer(), tokenMint.toBuffer()],
+          ammProgram
+        );
+        const [baseVault] = PublicKey.findProgramAddressSync(
+          [Buffer.from(POOL_VAULT_SEED), poolId.toBuffer(), baseMint.toBuffer()],
+          ammProgram
+        );
+
         console.log('[useRaydiumPools] Registering pool:', {
           poolId: poolId.toBase58(),
           tokenMint: tokenMint.toBase58(),
           baseMint: baseMint.toBase58(),
           poolAccountPda: poolAccountPda.toBase58(),
+          ammProgram: ammProgram.toBase58(),
         });
 
         const tx = await programs.yieldAdapter.methods
           .registerRaydiumPool(poolId, baseMint)
-          .accounts({
+          .accountsPartial({
             poolAccount: poolAccountPda,
             tokenMint: tokenMint,
             admin: publicKey,
-            systemProgram: SystemProgram.programId,
+            ammProgram,
+            poolState: poolId,
+            tokenVault,
+            baseVault,
+            ...(await venueAdminAccounts(programs, tokenMint)),
           })
           .rpc();
 
@@ -141,9 +203,11 @@ export function useRaydiumPools() {
 
         const tx = await programs.yieldAdapter.methods
           .updatePoolStatus(isActive)
-          .accounts({
+          .accountsPartial({
             poolAccount: poolAccountPda,
+            tokenMint,
             admin: publicKey,
+            ...(await venueAdminAccounts(programs, tokenMint)),
           })
           .rpc();
 
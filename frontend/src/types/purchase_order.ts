@@ -5,7 +5,7 @@
  * IDL can be found at `target/idl/purchase_order.json`.
  */
 export type PurchaseOrder = {
-  "address": "EdyLMn3iUrF16Z4VPyTfv9hC9G7eqxsHQVxnsNcsAT3Z",
+  "address": "43iQGS4Xyg6aGcpL52n7KurMm5eVNvQKEtgdPSxZwKPU",
   "metadata": {
     "name": "purchaseOrder",
     "version": "0.1.0",
@@ -16,7 +16,8 @@ export type PurchaseOrder = {
     {
       "name": "approvePurchaseOrder",
       "docs": [
-        "Admin aprova a purchase order e transfere os tokens"
+        "Admin aprova a purchase order: entrega `fill_quantity` tokens do inventário, libera o",
+        "pagamento para o issuer treasury e reembolsa o restante na mesma instrução"
       ],
       "discriminator": [
         169,
@@ -80,138 +81,224 @@ export type PurchaseOrder = {
           }
         },
         {
-          "name": "adminTokenAccount",
+          "name": "srwaConfig",
           "docs": [
-            "Token account do admin (origem dos tokens)"
+            "Configuração do SRWA, define os papéis autorizados"
           ],
-          "writable": true
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  114,
+                  119,
+                  97,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchase_order.mint",
+                "account": "purchaseOrder"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
         },
         {
-          "name": "investorTokenAccount",
+          "name": "adminRegistry",
           "docs": [
-            "Token account do investor (destino dos tokens)"
+            "Obrigatório quando o admin é um admin da plataforma"
           ],
-          "writable": true
-        },
-        {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "cancelPurchaseOrder",
-      "docs": [
-        "Investor cancela purchase order pendente (reembolso automático)"
-      ],
-      "discriminator": [
-        5,
-        234,
-        20,
-        31,
-        5,
-        88,
-        90,
-        219
-      ],
-      "accounts": [
-        {
-          "name": "investor",
-          "writable": true,
-          "signer": true
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
         },
         {
-          "name": "purchaseOrder",
+          "name": "investorKycClaim",
           "docs": [
-            "Purchase order sendo cancelada"
+            "verificado no handler antes da entrega"
           ],
-          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  117,
-                  114,
                   99,
-                  104,
+                  108,
                   97,
-                  115,
-                  101,
-                  95,
-                  111,
-                  114,
-                  100,
-                  101,
-                  114
+                  105,
+                  109
                 ]
               },
-              {
-                "kind": "account",
-                "path": "purchase_order.mint",
-                "account": "purchaseOrder"
-              },
               {
                 "kind": "account",
                 "path": "purchase_order.investor",
                 "account": "purchaseOrder"
               },
               {
-                "kind": "account",
-                "path": "purchase_order.created_at",
-                "account": "purchaseOrder"
+                "kind": "const",
+                "value": [
+                  1,
+                  0,
+                  0,
+                  0
+                ]
               }
-            ]
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                147,
+                43,
+                10,
+                29,
+                76,
+                157,
+                222,
+                26,
+                15,
+                243,
+                228,
+                196,
+                110,
+                69,
+                231,
+                64,
+                228,
+                194,
+                246,
+                127,
+                145,
+                224,
+                165,
+                104,
+                57,
+                249,
+                99,
+                229,
+                216,
+                46,
+                107,
+                229
+              ]
+            }
           }
         },
-        {
-          "name": "adminVault",
-          "docs": [
-            "Vault do admin que retornará o SOL"
-          ],
-          "writable": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "createPurchaseOrder",
-      "docs": [
-        "Cria uma nova purchase order (investor envia SOL, espera aprovação do admin)"
-      ],
-      "discriminator": [
-        149,
-        194,
-        73,
-        182,
-        117,
-        192,
-        79,
-        74
-      ],
-      "accounts": [
-        {
-          "name": "investor",
-          "writable": true,
-          "signer": true
-        },
         {
           "name": "mint",
           "docs": [
-            "Mint do token SRWA sendo comprado"
+            "Mint do token"
+          ],
+          "relations": [
+            "inventory"
           ]
         },
         {
-          "name": "purchaseOrder",
+          "name": "inventory",
           "docs": [
-            "PDA da purchase order",
-            "Derivado de: [b\"purchase_order\", mint, investor, timestamp]"
+            "Inventário do issuer, authority do vault de entrega"
           ],
           "writable": true,
           "pda": {
@@ -219,89 +306,43 @@ export type PurchaseOrder = {
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  117,
-                  114,
-                  99,
-                  104,
-                  97,
-                  115,
+                  105,
+                  110,
+                  118,
                   101,
-                  95,
+                  110,
+                  116,
                   111,
                   114,
-                  100,
-                  101,
-                  114
+                  121
                 ]
               },
               {
                 "kind": "account",
-                "path": "mint"
-              },
-              {
-                "kind": "account",
-                "path": "investor"
-              },
-              {
-                "kind": "arg",
-                "path": "timestamp"
+                "path": "purchase_order.mint",
+                "account": "purchaseOrder"
               }
             ]
           }
         },
         {
-          "name": "adminVault",
+          "name": "inventoryVault",
           "docs": [
-            "Vault do admin que receberá o SOL"
+            "Vault do inventário (origem dos tokens)"
           ],
           "writable": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "quantity",
-          "type": "u64"
-        },
-        {
-          "name": "pricePerTokenLamports",
-          "type": "u64"
-        },
-        {
-          "name": "timestamp",
-          "type": "i64"
-        }
-      ]
-    },
-    {
-      "name": "rejectPurchaseOrder",
-      "docs": [
-        "Admin rejeita a purchase order e reembolsa o SOL"
-      ],
-      "discriminator": [
-        153,
-        105,
-        254,
-        247,
-        117,
-        186,
-        95,
-        24
-      ],
-      "accounts": [
-        {
-          "name": "admin",
-          "writable": true,
-          "signer": true
+          "name": "investorTokenAccount",
+          "docs": [
+            "Token account do investor (destino dos tokens)"
+          ],
+          "writable": true
         },
         {
-          "name": "purchaseOrder",
+          "name": "escrow",
           "docs": [
-            "Purchase order sendo rejeitada"
+            "Escrow da order, fechado para o investor após liberar o pagamento"
           ],
           "writable": true,
           "pda": {
@@ -309,68 +350,3088 @@ export type PurchaseOrder = {
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  117,
-                  114,
-                  99,
-                  104,
-                  97,
-                  115,
-                  101,
-                  95,
                   111,
                   114,
                   100,
                   101,
-                  114
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
                 ]
               },
               {
                 "kind": "account",
-                "path": "purchase_order.mint",
-                "account": "purchaseOrder"
-              },
+                "path": "purchaseOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offering",
+          "docs": [
+            "o issuer treasury, senão o pagamento vai para o issuer admin"
+          ],
+          "pda": {
+            "seeds": [
               {
-                "kind": "account",
-                "path": "purchase_order.investor",
-                "account": "purchaseOrder"
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  105,
+                  110,
+                  103
+                ]
               },
               {
                 "kind": "account",
-                "path": "purchase_order.created_at",
+                "path": "purchase_order.mint",
                 "account": "purchaseOrder"
               }
-            ]
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
           }
         },
         {
-          "name": "adminVault",
+          "name": "issuerTreasury",
           "docs": [
-            "Vault do admin que retornará o SOL"
+            "Issuer treasury que recebe o pagamento do escrow, verificado no handler"
           ],
           "writable": true
         },
         {
           "name": "investor",
           "docs": [
-            "Investor que receberá o reembolso"
+            "Investor que recebe o rent do escrow"
           ],
           "writable": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
+          "name": "quoteMint",
+          "docs": [
+            "Quote mint da order (apenas orders pagas em SPL)"
+          ],
+          "optional": true
+        },
         {
-          "name": "reason",
-          "type": "string"
-        }
+          "name": "escrowTokenAccount",
+          "docs": [
+            "Escrow SPL da order"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  116,
+                  111,
+                  107,
+                  101,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchaseOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasuryQuoteAccount",
+          "docs": [
+            "Conta do issuer treasury no quote mint"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "investorQuoteAccount",
+          "docs": [
+            "Conta do investor no quote mint, recebe o reembolso do que não foi preenchido"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "valuationData",
+          "docs": [
+            "NAV publicado, obrigatório para orders precificadas pelo NAV"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  97,
+                  108,
+                  117,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchase_order.mint",
+                "account": "purchaseOrder"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                164,
+                110,
+                42,
+                93,
+                89,
+                186,
+                222,
+                103,
+                249,
+                247,
+                15,
+                254,
+                82,
+                154,
+                210,
+                152,
+                206,
+                24,
+                133,
+                160,
+                136,
+                123,
+                130,
+                24,
+                115,
+                18,
+                77,
+                59,
+                47,
+                218,
+                176,
+                83
+              ]
+            }
+          }
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "quoteTokenProgram",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "fillQuantity",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "approveSellOrder",
+      "docs": [
+        "Admin aprova a sell order: tokens para o inventário e pagamento do liquidity vault"
+      ],
+      "discriminator": [
+        65,
+        209,
+        180,
+        219,
+        82,
+        177,
+        44,
+        11
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "sellOrder",
+          "docs": [
+            "Sell order sendo aprovada"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  108,
+                  108,
+                  95,
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sell_order.mint",
+                "account": "sellOrder"
+              },
+              {
+                "kind": "account",
+                "path": "sell_order.holder",
+                "account": "sellOrder"
+              },
+              {
+                "kind": "account",
+                "path": "sell_order.created_at",
+                "account": "sellOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "srwaConfig",
+          "docs": [
+            "Configuração do SRWA, define os papéis autorizados"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  114,
+                  119,
+                  97,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sell_order.mint",
+                "account": "sellOrder"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
+        },
+        {
+          "name": "adminRegistry",
+          "docs": [
+            "Obrigatório quando o admin é um admin da plataforma"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Mint do token"
+          ],
+          "relations": [
+            "inventory"
+          ]
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow dos tokens SRWA"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  108,
+                  108,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sellOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "inventory",
+          "docs": [
+            "Inventário do issuer, recebe os tokens recomprados"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  110,
+                  116,
+                  111,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sell_order.mint",
+                "account": "sellOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "inventoryVault",
+          "writable": true
+        },
+        {
+          "name": "liquidityVault",
+          "docs": [
+            "Liquidity vault que paga a order"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sell_order.mint",
+                "account": "sellOrder"
+              },
+              {
+                "kind": "account",
+                "path": "sell_order.quote_mint",
+                "account": "sellOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "quoteMint"
+        },
+        {
+          "name": "holderQuoteAccount",
+          "docs": [
+            "Conta do holder no quote mint (destino do pagamento)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "holder",
+          "docs": [
+            "Holder que recebe o rent do escrow"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "quoteTokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelPurchaseOrder",
+      "docs": [
+        "Investor cancela purchase order pendente (reembolso automático)"
+      ],
+      "discriminator": [
+        5,
+        234,
+        20,
+        31,
+        5,
+        88,
+        90,
+        219
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "purchaseOrder",
+          "docs": [
+            "Purchase order sendo cancelada"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  117,
+                  114,
+                  99,
+                  104,
+                  97,
+                  115,
+                  101,
+                  95,
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchase_order.mint",
+                "account": "purchaseOrder"
+              },
+              {
+                "kind": "account",
+                "path": "purchase_order.investor",
+                "account": "purchaseOrder"
+              },
+              {
+                "kind": "account",
+                "path": "purchase_order.created_at",
+                "account": "purchaseOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow da order, fechado para o investor (reembolso + rent)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchaseOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "quoteMint",
+          "docs": [
+            "Quote mint da order (apenas orders pagas em SPL)"
+          ],
+          "optional": true
+        },
+        {
+          "name": "escrowTokenAccount",
+          "docs": [
+            "Escrow SPL da order"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  116,
+                  111,
+                  107,
+                  101,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchaseOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorQuoteAccount",
+          "docs": [
+            "Conta do investor no quote mint que recebe o reembolso"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "quoteTokenProgram",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelSellOrder",
+      "docs": [
+        "Holder cancela sell order pendente (tokens devolvidos)"
+      ],
+      "discriminator": [
+        35,
+        49,
+        106,
+        38,
+        91,
+        127,
+        157,
+        20
+      ],
+      "accounts": [
+        {
+          "name": "holder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "sellOrder",
+          "docs": [
+            "Sell order sendo cancelada"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  108,
+                  108,
+                  95,
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sell_order.mint",
+                "account": "sellOrder"
+              },
+              {
+                "kind": "account",
+                "path": "sell_order.holder",
+                "account": "sellOrder"
+              },
+              {
+                "kind": "account",
+                "path": "sell_order.created_at",
+                "account": "sellOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Mint do token"
+          ]
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow dos tokens SRWA"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  108,
+                  108,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sellOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "holderTokenAccount",
+          "docs": [
+            "Conta do holder que recebe os tokens de volta"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createPurchaseOrder",
+      "docs": [
+        "Cria uma nova purchase order (investor deposita SOL no escrow, espera aprovação do admin)"
+      ],
+      "discriminator": [
+        149,
+        194,
+        73,
+        182,
+        117,
+        192,
+        79,
+        74
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Mint do token SRWA sendo comprado"
+          ]
+        },
+        {
+          "name": "offering",
+          "docs": [
+            "Oferta do SRWA, se existir"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
+        },
+        {
+          "name": "purchaseOrder",
+          "docs": [
+            "PDA da purchase order",
+            "Derivado de: [b\"purchase_order\", mint, investor, timestamp]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  117,
+                  114,
+                  99,
+                  104,
+                  97,
+                  115,
+                  101,
+                  95,
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "arg",
+                "path": "timestamp"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow da order, guarda o SOL até aprovação ou reembolso"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchaseOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "quantity",
+          "type": "u64"
+        },
+        {
+          "name": "pricePerTokenLamports",
+          "type": "u64"
+        },
+        {
+          "name": "timestamp",
+          "type": "i64"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createSellOrder",
+      "docs": [
+        "Holder cria uma sell order (tokens SRWA em escrow, espera aprovação do admin)"
+      ],
+      "discriminator": [
+        53,
+        52,
+        255,
+        44,
+        191,
+        74,
+        171,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "holder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Mint do token SRWA sendo vendido"
+          ]
+        },
+        {
+          "name": "sellOrder",
+          "docs": [
+            "PDA da sell order",
+            "Derivado de: [b\"sell_order\", mint, holder, timestamp]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  108,
+                  108,
+                  95,
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              },
+              {
+                "kind": "account",
+                "path": "holder"
+              },
+              {
+                "kind": "arg",
+                "path": "timestamp"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow dos tokens SRWA, authority = sell order"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  108,
+                  108,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sellOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "holderTokenAccount",
+          "docs": [
+            "Conta do holder (origem dos tokens)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "liquidityVault",
+          "docs": [
+            "Liquidity vault que pagará a order"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              },
+              {
+                "kind": "account",
+                "path": "liquidity_vault.quote_mint",
+                "account": "liquidityVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programAllowlist",
+          "docs": [
+            "ProgramAllowlist do mint; o escrow é um venue do transfer hook porque",
+            "a authority é a sell order, um PDA deste programa"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  95,
+                  97,
+                  108,
+                  108,
+                  111,
+                  119,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                3,
+                229,
+                82,
+                168,
+                74,
+                20,
+                187,
+                75,
+                165,
+                42,
+                179,
+                2,
+                25,
+                103,
+                16,
+                94,
+                231,
+                171,
+                243,
+                56,
+                214,
+                108,
+                87,
+                177,
+                54,
+                129,
+                101,
+                42,
+                127,
+                75,
+                251,
+                4
+              ]
+            }
+          }
+        },
+        {
+          "name": "escrowCheckpoint",
+          "writable": true
+        },
+        {
+          "name": "controllerProgram",
+          "address": "A6JtsR3Zw1GB1gTJuqdpFiBijarm9pQRTgqVkZaEdBs3"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "quantity",
+          "type": "u64"
+        },
+        {
+          "name": "pricePerToken",
+          "type": "u64"
+        },
+        {
+          "name": "timestamp",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "createTokenPurchaseOrder",
+      "docs": [
+        "Cria uma purchase order paga em SPL/Token-2022 (USDC, PYUSD), com escrow no quote mint.",
+        "Em ofertas precificadas pelo NAV, `price_per_token` é o preço máximo aceito."
+      ],
+      "discriminator": [
+        99,
+        81,
+        254,
+        118,
+        130,
+        201,
+        71,
+        69
+      ],
+      "accounts": [
+        {
+          "name": "investor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Mint do token SRWA sendo comprado"
+          ]
+        },
+        {
+          "name": "offering",
+          "docs": [
+            "Oferta do SRWA, se existir"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114,
+                  105,
+                  110,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
+        },
+        {
+          "name": "purchaseOrder",
+          "docs": [
+            "PDA da purchase order",
+            "Derivado de: [b\"purchase_order\", mint, investor, timestamp]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  117,
+                  114,
+                  99,
+                  104,
+                  97,
+                  115,
+                  101,
+                  95,
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              },
+              {
+                "kind": "account",
+                "path": "investor"
+              },
+              {
+                "kind": "arg",
+                "path": "timestamp"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow da order, authority da conta SPL abaixo"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchaseOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "quoteMint",
+          "docs": [
+            "Mint de pagamento (USDC, PYUSD, ...)"
+          ]
+        },
+        {
+          "name": "investorQuoteAccount",
+          "docs": [
+            "Conta do investor no quote mint (origem do pagamento)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "escrowTokenAccount",
+          "docs": [
+            "Escrow SPL que guarda o pagamento até aprovação ou reembolso"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  116,
+                  111,
+                  107,
+                  101,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchaseOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "quoteTokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "quantity",
+          "type": "u64"
+        },
+        {
+          "name": "pricePerToken",
+          "type": "u64"
+        },
+        {
+          "name": "timestamp",
+          "type": "i64"
+        },
+        {
+          "name": "expiresAt",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "expirePurchaseOrder",
+      "docs": [
+        "Qualquer um expira uma purchase order vencida, reembolsando o investor"
+      ],
+      "discriminator": [
+        110,
+        64,
+        69,
+        52,
+        143,
+        52,
+        207,
+        104
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Crank permissionless após `expires_at`"
+          ],
+          "signer": true
+        },
+        {
+          "name": "purchaseOrder",
+          "docs": [
+            "Purchase order sendo expirada"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  117,
+                  114,
+                  99,
+                  104,
+                  97,
+                  115,
+                  101,
+                  95,
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchase_order.mint",
+                "account": "purchaseOrder"
+              },
+              {
+                "kind": "account",
+                "path": "purchase_order.investor",
+                "account": "purchaseOrder"
+              },
+              {
+                "kind": "account",
+                "path": "purchase_order.created_at",
+                "account": "purchaseOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow da order, fechado para o investor (reembolso + rent)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchaseOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investor",
+          "docs": [
+            "Investor que recebe o reembolso"
+          ],
+          "writable": true
+        },
+        {
+          "name": "quoteMint",
+          "docs": [
+            "Quote mint da order (apenas orders pagas em SPL)"
+          ],
+          "optional": true
+        },
+        {
+          "name": "escrowTokenAccount",
+          "docs": [
+            "Escrow SPL da order"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  116,
+                  111,
+                  107,
+                  101,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchaseOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorQuoteAccount",
+          "docs": [
+            "Conta do investor no quote mint que recebe o reembolso"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "quoteTokenProgram",
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeInventory",
+      "docs": [
+        "Cria o inventário do issuer de onde as orders aprovadas são entregues"
+      ],
+      "discriminator": [
+        75,
+        221,
+        38,
+        238,
+        9,
+        187,
+        237,
+        157
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Mint do token SRWA"
+          ]
+        },
+        {
+          "name": "srwaConfig",
+          "docs": [
+            "Configuração do SRWA, define os papéis autorizados"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  114,
+                  119,
+                  97,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
+        },
+        {
+          "name": "adminRegistry",
+          "docs": [
+            "Obrigatório quando o admin é um admin da plataforma"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
+        },
+        {
+          "name": "inventory",
+          "docs": [
+            "PDA do inventário, authority do vault abaixo"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  110,
+                  116,
+                  111,
+                  114,
+                  121
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "inventoryVault",
+          "docs": [
+            "Conta de tokens que o issuer abastece para entregar as orders"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  105,
+                  110,
+                  118,
+                  101,
+                  110,
+                  116,
+                  111,
+                  114,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "inventory"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programAllowlist",
+          "writable": true
+        },
+        {
+          "name": "accountAllowlist",
+          "writable": true
+        },
+        {
+          "name": "inventoryCheckpoint",
+          "writable": true
+        },
+        {
+          "name": "complianceProgram",
+          "address": "GD3ArP1GPKN9sWYPxiPia2i3iAKKsnbXxpcoB1gQK5D"
+        },
+        {
+          "name": "controllerProgram",
+          "address": "A6JtsR3Zw1GB1gTJuqdpFiBijarm9pQRTgqVkZaEdBs3"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeLiquidityVault",
+      "docs": [
+        "Cria o liquidity vault (SRWA, quote mint) que paga as sell orders"
+      ],
+      "discriminator": [
+        100,
+        241,
+        242,
+        43,
+        252,
+        228,
+        234,
+        8
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Mint do token SRWA"
+          ]
+        },
+        {
+          "name": "srwaConfig",
+          "docs": [
+            "Configuração do SRWA, define os papéis autorizados"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  114,
+                  119,
+                  97,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
+        },
+        {
+          "name": "adminRegistry",
+          "docs": [
+            "Obrigatório quando o admin é um admin da plataforma"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
+        },
+        {
+          "name": "quoteMint",
+          "docs": [
+            "Mint de pagamento das sell orders"
+          ]
+        },
+        {
+          "name": "liquidityVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              },
+              {
+                "kind": "account",
+                "path": "quoteMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "docs": [
+            "Conta que o issuer/market maker abastece para pagar as sell orders"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116,
+                  95,
+                  116,
+                  111,
+                  107,
+                  101,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "liquidityVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "programAllowlist",
+          "writable": true
+        },
+        {
+          "name": "accountAllowlist",
+          "writable": true
+        },
+        {
+          "name": "complianceProgram",
+          "address": "GD3ArP1GPKN9sWYPxiPia2i3iAKKsnbXxpcoB1gQK5D"
+        },
+        {
+          "name": "quoteTokenProgram"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rejectPurchaseOrder",
+      "docs": [
+        "Admin rejeita a purchase order e reembolsa o SOL do escrow"
+      ],
+      "discriminator": [
+        153,
+        105,
+        254,
+        247,
+        117,
+        186,
+        95,
+        24
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "purchaseOrder",
+          "docs": [
+            "Purchase order sendo rejeitada"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  117,
+                  114,
+                  99,
+                  104,
+                  97,
+                  115,
+                  101,
+                  95,
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchase_order.mint",
+                "account": "purchaseOrder"
+              },
+              {
+                "kind": "account",
+                "path": "purchase_order.investor",
+                "account": "purchaseOrder"
+              },
+              {
+                "kind": "account",
+                "path": "purchase_order.created_at",
+                "account": "purchaseOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "srwaConfig",
+          "docs": [
+            "Configuração do SRWA, define os papéis autorizados"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  114,
+                  119,
+                  97,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchase_order.mint",
+                "account": "purchaseOrder"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
+        },
+        {
+          "name": "adminRegistry",
+          "docs": [
+            "Obrigatório quando o admin é um admin da plataforma"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow da order, fechado para o investor (reembolso + rent)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchaseOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investor",
+          "docs": [
+            "Investor que receberá o reembolso"
+          ],
+          "writable": true
+        },
+        {
+          "name": "quoteMint",
+          "docs": [
+            "Quote mint da order (apenas orders pagas em SPL)"
+          ],
+          "optional": true
+        },
+        {
+          "name": "escrowTokenAccount",
+          "docs": [
+            "Escrow SPL da order"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119,
+                  95,
+                  116,
+                  111,
+                  107,
+                  101,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "purchaseOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "investorQuoteAccount",
+          "docs": [
+            "Conta do investor no quote mint que recebe o reembolso"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "quoteTokenProgram",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "rejectSellOrder",
+      "docs": [
+        "Admin rejeita a sell order e devolve os tokens ao holder"
+      ],
+      "discriminator": [
+        41,
+        96,
+        49,
+        40,
+        57,
+        243,
+        26,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "sellOrder",
+          "docs": [
+            "Sell order sendo rejeitada"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  108,
+                  108,
+                  95,
+                  111,
+                  114,
+                  100,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sell_order.mint",
+                "account": "sellOrder"
+              },
+              {
+                "kind": "account",
+                "path": "sell_order.holder",
+                "account": "sellOrder"
+              },
+              {
+                "kind": "account",
+                "path": "sell_order.created_at",
+                "account": "sellOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "srwaConfig",
+          "docs": [
+            "Configuração do SRWA, define os papéis autorizados"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  114,
+                  119,
+                  97,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sell_order.mint",
+                "account": "sellOrder"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
+        },
+        {
+          "name": "adminRegistry",
+          "docs": [
+            "Obrigatório quando o admin é um admin da plataforma"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Mint do token"
+          ]
+        },
+        {
+          "name": "escrow",
+          "docs": [
+            "Escrow dos tokens SRWA"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  101,
+                  108,
+                  108,
+                  95,
+                  101,
+                  115,
+                  99,
+                  114,
+                  111,
+                  119
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "sellOrder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "holderTokenAccount",
+          "docs": [
+            "Conta do holder que recebe os tokens de volta"
+          ],
+          "writable": true
+        },
+        {
+          "name": "holder",
+          "docs": [
+            "Holder que recebe o rent do escrow"
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "withdrawLiquidity",
+      "docs": [
+        "Admin retira fundos do liquidity vault"
+      ],
+      "discriminator": [
+        149,
+        158,
+        33,
+        185,
+        47,
+        243,
+        253,
+        31
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "srwaConfig",
+          "docs": [
+            "Configuração do SRWA, define os papéis autorizados"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  114,
+                  119,
+                  97,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "liquidity_vault.mint",
+                "account": "liquidityVault"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
+        },
+        {
+          "name": "adminRegistry",
+          "docs": [
+            "Obrigatório quando o admin é um admin da plataforma"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  101,
+                  103,
+                  105,
+                  115,
+                  116,
+                  114,
+                  121
+                ]
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                72,
+                121,
+                42,
+                120,
+                243,
+                52,
+                67,
+                234,
+                192,
+                172,
+                203,
+                7,
+                220,
+                157,
+                162,
+                93,
+                222,
+                226,
+                152,
+                36,
+                197,
+                76,
+                194,
+                125,
+                113,
+                21,
+                177,
+                234,
+                31,
+                124,
+                246,
+                117
+              ]
+            }
+          }
+        },
+        {
+          "name": "liquidityVault",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  113,
+                  117,
+                  105,
+                  100,
+                  105,
+                  116,
+                  121,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "liquidity_vault.mint",
+                "account": "liquidityVault"
+              },
+              {
+                "kind": "account",
+                "path": "liquidity_vault.quote_mint",
+                "account": "liquidityVault"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "quoteMint",
+          "relations": [
+            "liquidityVault"
+          ]
+        },
+        {
+          "name": "destination",
+          "docs": [
+            "Destino dos fundos retirados"
+          ],
+          "writable": true
+        },
+        {
+          "name": "quoteTokenProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "issuerInventory",
+      "discriminator": [
+        158,
+        49,
+        82,
+        213,
+        251,
+        150,
+        174,
+        85
+      ]
+    },
+    {
+      "name": "liquidityVault",
+      "discriminator": [
+        221,
+        166,
+        52,
+        46,
+        13,
+        174,
+        181,
+        99
+      ]
+    },
+    {
+      "name": "orderEscrow",
+      "discriminator": [
+        197,
+        239,
+        178,
+        120,
+        174,
+        113,
+        67,
+        180
+      ]
+    },
+    {
+      "name": "platformAdminRegistry",
+      "discriminator": [
+        173,
+        8,
+        43,
+        230,
+        46,
+        59,
+        39,
+        31
       ]
-    }
-  ],
-  "accounts": [
+    },
+    {
+      "name": "programAllowlist",
+      "discriminator": [
+        247,
+        115,
+        209,
+        223,
+        107,
+        77,
+        0,
+        65
+      ]
+    },
     {
       "name": "purchaseOrder",
       "discriminator": [
@@ -383,6 +3444,45 @@ export type PurchaseOrder = {
         171,
         23
       ]
+    },
+    {
+      "name": "srwaConfig",
+      "discriminator": [
+        194,
+        199,
+        237,
+        20,
+        148,
+        94,
+        25,
+        11
+      ]
+    },
+    {
+      "name": "sellOrder",
+      "discriminator": [
+        125,
+        28,
+        219,
+        150,
+        25,
+        64,
+        250,
+        236
+      ]
+    },
+    {
+      "name": "valuationData",
+      "discriminator": [
+        112,
+        28,
+        13,
+        74,
+        123,
+        207,
+        215,
+        222
+      ]
     }
   ],
   "errors": [
@@ -418,21 +3518,356 @@ export type PurchaseOrder = {
     },
     {
       "code": 6006,
-      "name": "insufficientAdminTokens",
-      "msg": "Saldo insuficiente de tokens do admin"
+      "name": "insufficientInventory",
+      "msg": "Saldo insuficiente no inventário do issuer"
     },
     {
       "code": 6007,
       "name": "rejectReasonTooLong",
       "msg": "Motivo de rejeição muito longo (max 200 chars)"
     },
-    {
-      "code": 6008,
-      "name": "mathOverflow",
-      "msg": "Overflow no cálculo do total"
-    }
-  ],
-  "types": [
+    {
+      "code": 6008,
+      "name": "mathOverflow",
+      "msg": "Overflow no cálculo do total"
+    },
+    {
+      "code": 6009,
+      "name": "treasuryMismatch",
+      "msg": "Treasury não corresponde ao issuer treasury da oferta"
+    },
+    {
+      "code": 6010,
+      "name": "insufficientEscrow",
+      "msg": "Saldo do escrow insuficiente"
+    },
+    {
+      "code": 6011,
+      "name": "quoteMintMismatch",
+      "msg": "Quote mint não corresponde à oferta ou à order"
+    },
+    {
+      "code": 6012,
+      "name": "priceMismatch",
+      "msg": "Preço não corresponde ao pricing da oferta"
+    },
+    {
+      "code": 6013,
+      "name": "missingQuoteAccounts",
+      "msg": "Contas do quote mint ausentes para order em SPL"
+    },
+    {
+      "code": 6014,
+      "name": "investorNotVerified",
+      "msg": "Investor sem claim KYC válido de um trusted issuer"
+    },
+    {
+      "code": 6015,
+      "name": "orderExpired",
+      "msg": "Purchase order expirada"
+    },
+    {
+      "code": 6016,
+      "name": "orderNotExpired",
+      "msg": "Purchase order ainda não expirou"
+    },
+    {
+      "code": 6017,
+      "name": "invalidExpiry",
+      "msg": "Expiração inválida"
+    },
+    {
+      "code": 6018,
+      "name": "invalidFillQuantity",
+      "msg": "Quantidade de preenchimento inválida"
+    },
+    {
+      "code": 6019,
+      "name": "priceAboveLimit",
+      "msg": "NAV acima do preço máximo da order"
+    },
+    {
+      "code": 6020,
+      "name": "missingPriceSource",
+      "msg": "Fonte de preço (NAV) ausente"
+    },
+    {
+      "code": 6021,
+      "name": "staleNav",
+      "msg": "NAV desatualizado"
+    },
+    {
+      "code": 6022,
+      "name": "insufficientLiquidity",
+      "msg": "Saldo insuficiente no liquidity vault"
+    },
+    {
+      "code": 6023,
+      "name": "venueNotAllowlisted",
+      "msg": "Programa de ordens fora da allowlist de venues do mint"
+    }
+  ],
+  "types": [
+    {
+      "name": "currency",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "usd"
+          },
+          {
+            "name": "brl"
+          },
+          {
+            "name": "eur"
+          }
+        ]
+      }
+    },
+    {
+      "name": "issuerInventory",
+      "docs": [
+        "Inventário do issuer: authority da conta de tokens usada para entregar as orders aprovadas"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "Mint do token SRWA"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "vault",
+            "docs": [
+              "Conta de tokens do inventário"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "delivered",
+            "docs": [
+              "Total entregue a investors"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed para o PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "liquidityVault",
+      "docs": [
+        "Liquidity vault do par (SRWA, quote mint) que paga as sell orders"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "Mint do token SRWA"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "quoteMint",
+            "docs": [
+              "Mint de pagamento"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "vault",
+            "docs": [
+              "Conta de tokens do vault no quote mint"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "paidOut",
+            "docs": [
+              "Total pago a holders"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed para o PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "moduleId",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "jurisdiction"
+          },
+          {
+            "name": "sanctions"
+          },
+          {
+            "name": "accredited"
+          },
+          {
+            "name": "lockup"
+          },
+          {
+            "name": "maxHolders"
+          },
+          {
+            "name": "volumeCaps"
+          },
+          {
+            "name": "transferWindow"
+          },
+          {
+            "name": "programAllowlist"
+          },
+          {
+            "name": "accountAllowlist"
+          },
+          {
+            "name": "offeringRules"
+          },
+          {
+            "name": "investorLimits"
+          }
+        ]
+      }
+    },
+    {
+      "name": "oracleConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pythFeeds",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "heartbeat",
+            "type": "u32"
+          },
+          {
+            "name": "maxDevBps",
+            "type": "u32"
+          },
+          {
+            "name": "navFeeder",
+            "type": "pubkey"
+          },
+          {
+            "name": "baseCcy",
+            "type": {
+              "defined": {
+                "name": "currency"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "orderEscrow",
+      "docs": [
+        "Escrow PDA que guarda o SOL do investor até a aprovação"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "purchaseOrder",
+            "docs": [
+              "Purchase order dona deste escrow"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed para o PDA"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "platformAdminRegistry",
+      "docs": [
+        "Platform Admin Registry - Global PDA que controla quem pode aprovar tokens/pools"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "superAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "authorizedAdmins",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "programAllowlist",
+      "docs": [
+        "Program allowlist (DEX/lending programs)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "programs",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
     {
       "name": "purchaseOrder",
       "type": {
@@ -469,17 +3904,53 @@ export type PurchaseOrder = {
           {
             "name": "pricePerTokenLamports",
             "docs": [
-              "Preço por token em lamports"
+              "Preço por token: lamports por unidade base (SOL) ou",
+              "unidades do quote mint por token inteiro (SPL, mesmo formato do Pricing)"
             ],
             "type": "u64"
           },
           {
             "name": "totalLamports",
             "docs": [
-              "Total em SOL (lamports) que o investor pagou"
+              "Total que o investor pagou (lamports ou unidades do quote mint)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "quoteMint",
+            "docs": [
+              "Mint de pagamento (None = SOL)"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "maxPricePerToken",
+            "docs": [
+              "Preço máximo aceito quando o preço vem do NAV na aprovação",
+              "(None = preço fixo em `price_per_token_lamports`)"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "filledQuantity",
+            "docs": [
+              "Quantidade entregue na aprovação (o restante é reembolsado)"
             ],
             "type": "u64"
           },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "Após este timestamp qualquer um pode expirar a order e reembolsar o investor"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
           {
             "name": "status",
             "docs": [
@@ -556,6 +4027,285 @@ export type PurchaseOrder = {
           },
           {
             "name": "cancelled"
+          },
+          {
+            "name": "expired"
+          }
+        ]
+      }
+    },
+    {
+      "name": "roles",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "issuerAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "complianceOfficer",
+            "type": "pubkey"
+          },
+          {
+            "name": "transferAgent",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "srwaConfig",
+      "docs": [
+        "Main configuration for an SRWA token"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "roles",
+            "type": {
+              "defined": {
+                "name": "roles"
+              }
+            }
+          },
+          {
+            "name": "requiredTopics",
+            "type": {
+              "vec": "u32"
+            }
+          },
+          {
+            "name": "trustedIssuersData",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "trustedIssuerEntry"
+                }
+              }
+            }
+          },
+          {
+            "name": "modulesEnabled",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "moduleId"
+                }
+              }
+            }
+          },
+          {
+            "name": "paramsByModule",
+            "docs": [
+              "Legacy untyped params; module params now live in `ModuleConfig` accounts"
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "tokenControls",
+            "type": {
+              "defined": {
+                "name": "tokenControls"
+              }
+            }
+          },
+          {
+            "name": "oracleCfg",
+            "type": {
+              "defined": {
+                "name": "oracleConfig"
+              }
+            }
+          },
+          {
+            "name": "complianceVersion",
+            "type": "u16"
+          },
+          {
+            "name": "metadataUri",
+            "type": "string"
+          },
+          {
+            "name": "paused",
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sellOrder",
+      "docs": [
+        "Ordem de venda (sell-back): o holder deposita os tokens SRWA em escrow e o",
+        "issuer/market maker paga a partir do liquidity vault"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "docs": [
+              "Bump seed para o PDA"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "holder",
+            "docs": [
+              "Holder que está vendendo"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Mint do token SRWA sendo vendido"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "quantity",
+            "docs": [
+              "Quantidade de tokens em escrow (em unidades base)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pricePerToken",
+            "docs": [
+              "Preço acordado em unidades do quote mint por token inteiro"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "quoteMint",
+            "docs": [
+              "Mint de pagamento"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "totalPayout",
+            "docs": [
+              "Total a receber do liquidity vault"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Status da sell order"
+            ],
+            "type": {
+              "defined": {
+                "name": "purchaseOrderStatus"
+              }
+            }
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Timestamp de criação"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "updatedAt",
+            "docs": [
+              "Timestamp de atualização"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "processedBy",
+            "docs": [
+              "Admin que aprovou/rejeitou (se aplicável)"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "rejectReason",
+            "docs": [
+              "Motivo da rejeição (se rejeitado)"
+            ],
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "tokenControls",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "defaultFrozen",
+            "type": "bool"
+          },
+          {
+            "name": "permanentDelegate",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "trustedIssuerEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "topic",
+            "type": "u32"
+          },
+          {
+            "name": "issuer",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "valuationData",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalNav",
+            "type": "u128"
+          },
+          {
+            "name": "navPerToken",
+            "type": "u64"
+          },
+          {
+            "name": "currency",
+            "type": "u8"
+          },
+          {
+            "name": "lastUpdate",
+            "type": "i64"
+          },
+          {
+            "name": "signer",
+            "type": "pubkey"
           }
         ]
       }
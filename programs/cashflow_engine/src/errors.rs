@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum CashflowError {
+    #[msg("Unauthorized")]
+    Unauthorized,
+
+    #[msg("Invalid redemption window")]
+    InvalidWindow,
+
+    #[msg("Redemption gate must be between 1 and 10000 bps")]
+    InvalidGate,
+
+    #[msg("Redemption window is not open")]
+    WindowNotOpen,
+
+    #[msg("Redemption window is still open")]
+    WindowStillOpen,
+
+    #[msg("Redemption request is not pending")]
+    RequestNotPending,
+
+    #[msg("Invalid amount")]
+    InvalidAmount,
+
+    #[msg("Instrument has not reached maturity")]
+    NotMatured,
+
+    #[msg("Missing price source for this redemption kind")]
+    MissingPriceSource,
+
+    #[msg("NAV is stale")]
+    StaleNav,
+
+    #[msg("Invalid redemption price")]
+    InvalidPrice,
+
+    #[msg("Redemption vault is not fully funded")]
+    InsufficientFunding,
+
+    #[msg("Payment mint does not match the redemption window")]
+    PaymentMintMismatch,
+
+    #[msg("Math overflow")]
+    MathOverflow,
+
+    #[msg("Redemption window still has pending requests")]
+    PendingRequests,
+
+    #[msg("NAV is published in a different currency than the window expects")]
+    CurrencyMismatch,
+
+    #[msg("NAV decimals must be at most 18")]
+    InvalidNavDecimals,
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::RedemptionKind;
+
+#[event]
+pub struct RedemptionWindowOpened {
+    pub mint: Pubkey,
+    pub window_id: u64,
+    pub kind: RedemptionKind,
+    pub payment_mint: Pubkey,
+    pub opens_at: i64,
+    pub closes_at: i64,
+    pub max_redemption_bps: u16,
+    pub supply_at_open: u64,
+    pub nav_currency: u8,
+    pub nav_decimals: u8,
+}
+
+#[event]
+pub struct RedemptionRequested {
+    pub mint: Pubkey,
+    pub window_id: u64,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionCancelled {
+    pub mint: Pubkey,
+    pub window_id: u64,
+    pub holder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RedemptionWindowClosed {
+    pub mint: Pubkey,
+    pub window_id: u64,
+    pub requested_amount: u64,
+    pub accepted_amount: u64,
+    pub price_per_token: u64,
+    pub required_funding: u64,
+}
+
+#[event]
+pub struct RedemptionFunded {
+    pub mint: Pubkey,
+    pub window_id: u64,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub funded_amount: u64,
+}
+
+#[event]
+pub struct RedemptionSettled {
+    pub mint: Pubkey,
+    pub window_id: u64,
+    pub holder: Pubkey,
+    pub burned: u64,
+    pub returned: u64,
+    pub payout: u64,
+}
+
+#[event]
+pub struct RedemptionWindowSwept {
+    pub mint: Pubkey,
+    pub window_id: u64,
+    pub surplus: u64,
+    pub burned: u64,
+    pub authority: Pubkey,
+}
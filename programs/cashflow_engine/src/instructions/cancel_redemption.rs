@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::onchain::invoke_transfer_checked,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+pub struct CancelRedemption<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [RedemptionWindow::SEED_PREFIX, mint.key().as_ref(), &window.window_id.to_le_bytes()],
+        bump = window.bump,
+        has_one = mint,
+        constraint = window.status == RedemptionStatus::Open @ CashflowError::WindowNotOpen
+    )]
+    pub window: Account<'info, RedemptionWindow>,
+
+    #[account(
+        mut,
+        close = holder,
+        seeds = [RedemptionRequest::SEED_PREFIX, window.key().as_ref(), holder.key().as_ref()],
+        bump = request.bump,
+        has_one = holder,
+        has_one = window,
+        constraint = request.status == RedemptionRequestStatus::Pending @ CashflowError::RequestNotPending
+    )]
+    pub request: Account<'info, RedemptionRequest>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder,
+        token::token_program = token_program,
+    )]
+    pub holder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [RedemptionWindow::ESCROW_SEED_PREFIX, window.key().as_ref()],
+        bump,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    // remaining_accounts: transfer hook extra accounts for the SRWA mint
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CancelRedemption<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp < ctx.accounts.window.closes_at,
+        CashflowError::WindowNotOpen
+    );
+
+    let amount = ctx.accounts.request.amount;
+    let mint_key = ctx.accounts.mint.key();
+    let window_id = ctx.accounts.window.window_id.to_le_bytes();
+    let window_seeds: &[&[u8]] = &[
+        RedemptionWindow::SEED_PREFIX,
+        mint_key.as_ref(),
+        &window_id,
+        &[ctx.accounts.window.bump],
+    ];
+
+    invoke_transfer_checked(
+        ctx.accounts.token_program.key,
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.holder_token_account.to_account_info(),
+        ctx.accounts.window.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+        &[window_seeds],
+    )?;
+
+    let window = &mut ctx.accounts.window;
+    window.requested_amount -= amount;
+    window.pending_requests -= 1;
+
+    emit!(RedemptionCancelled {
+        mint: window.mint,
+        window_id: window.window_id,
+        holder: ctx.accounts.holder.key(),
+        amount,
+    });
+
+    msg!("Redemption cancelled: {} tokens returned", amount);
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use srwa_factory::state::SRWAConfig;
+use valuation_oracle::ValuationData;
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+pub struct CloseRedemptionWindow<'info> {
+    /// Permissionless crank once the window has ended
+    pub payer: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        mut,
+        seeds = [RedemptionWindow::SEED_PREFIX, mint.key().as_ref(), &window.window_id.to_le_bytes()],
+        bump = window.bump,
+        has_one = mint,
+        constraint = window.status == RedemptionStatus::Open @ CashflowError::WindowNotOpen
+    )]
+    pub window: Account<'info, RedemptionWindow>,
+
+    /// Required for `RedemptionKind::Nav`
+    #[account(
+        seeds = [b"valuation", mint.key().as_ref()],
+        bump,
+        seeds::program = valuation_oracle::ID,
+    )]
+    pub valuation_data: Option<Account<'info, ValuationData>>,
+
+    /// Required for `RedemptionKind::Maturity`
+    #[account(
+        seeds = [b"coupon_schedule", mint.key().as_ref()],
+        bump,
+    )]
+    pub coupon_schedule: Option<Account<'info, CouponSchedule>>,
+}
+
+pub fn handler(ctx: Context<CloseRedemptionWindow>) -> Result<()> {
+    let clock = Clock::get()?;
+    let window = &ctx.accounts.window;
+    require!(
+        clock.unix_timestamp >= window.closes_at,
+        CashflowError::WindowStillOpen
+    );
+
+    // Lock the settlement price for every request in the window
+    let price_per_token = match window.kind {
+        RedemptionKind::Nav => {
+            let valuation = ctx
+                .accounts
+                .valuation_data
+                .as_ref()
+                .ok_or(CashflowError::MissingPriceSource)?;
+            let heartbeat = ctx.accounts.srwa_config.oracle_cfg.heartbeat as i64;
+            require!(
+                clock.unix_timestamp - valuation.last_update <= heartbeat,
+                CashflowError::StaleNav
+            );
+            require!(
+                valuation.currency == window.nav_currency,
+                CashflowError::CurrencyMismatch
+            );
+            window.price_from_nav(valuation.nav_per_token)?
+        }
+        RedemptionKind::Maturity => {
+            let schedule = ctx
+                .accounts
+                .coupon_schedule
+                .as_ref()
+                .ok_or(CashflowError::MissingPriceSource)?;
+            require!(
+                clock.unix_timestamp >= schedule.maturity_ts(),
+                CashflowError::NotMatured
+            );
+            schedule.par_value
+        }
+    };
+    require!(price_per_token > 0, CashflowError::InvalidPrice);
+
+    let decimals = ctx.accounts.mint.decimals;
+    let window = &mut ctx.accounts.window;
+    window.accepted_amount = window.requested_amount.min(window.gate_amount()?);
+    window.price_per_token = price_per_token;
+    window.required_funding = window.payout_for(window.accepted_amount, decimals)?;
+    window.status = RedemptionStatus::Closed;
+
+    emit!(RedemptionWindowClosed {
+        mint: window.mint,
+        window_id: window.window_id,
+        requested_amount: window.requested_amount,
+        accepted_amount: window.accepted_amount,
+        price_per_token,
+        required_funding: window.required_funding,
+    });
+
+    msg!(
+        "Redemption window {} closed: {}/{} accepted at {}",
+        window.window_id,
+        window.accepted_amount,
+        window.requested_amount,
+        price_per_token
+    );
+
+    Ok(())
+}
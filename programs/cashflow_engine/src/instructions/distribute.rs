@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct Distribute<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<Distribute>) -> Result<()> {
+    // Waterfall distribution logic
+    // 1. Fees
+    // 2. Senior tranche
+    // 3. Mezz tranche
+    // 4. Equity/Junior
+
+    msg!("Cashflow distributed via waterfall");
+    Ok(())
+}
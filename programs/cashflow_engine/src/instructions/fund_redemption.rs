@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+pub struct FundRedemption<'info> {
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RedemptionWindow::SEED_PREFIX, window.mint.as_ref(), &window.window_id.to_le_bytes()],
+        bump = window.bump,
+        has_one = payment_mint @ CashflowError::PaymentMintMismatch
+    )]
+    pub window: Account<'info, RedemptionWindow>,
+
+    #[account(mint::token_program = payment_token_program)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = funder,
+        token::token_program = payment_token_program,
+    )]
+    pub funder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [RedemptionWindow::VAULT_SEED_PREFIX, window.key().as_ref()],
+        bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub payment_token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<FundRedemption>, amount: u64) -> Result<()> {
+    require!(amount > 0, CashflowError::InvalidAmount);
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.payment_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.funder_token_account.to_account_info(),
+                mint: ctx.accounts.payment_mint.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.funder.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.payment_mint.decimals,
+    )?;
+
+    let window = &mut ctx.accounts.window;
+    window.funded_amount = window
+        .funded_amount
+        .checked_add(amount)
+        .ok_or(CashflowError::MathOverflow)?;
+
+    emit!(RedemptionFunded {
+        mint: window.mint,
+        window_id: window.window_id,
+        funder: ctx.accounts.funder.key(),
+        amount,
+        funded_amount: window.funded_amount,
+    });
+
+    msg!("Redemption vault funded: {} (total {})", amount, window.funded_amount);
+
+    Ok(())
+}
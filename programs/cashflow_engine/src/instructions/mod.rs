@@ -0,0 +1,21 @@
+pub mod schedule_coupon;
+pub mod record_payment;
+pub mod distribute;
+pub mod open_redemption_window;
+pub mod request_redemption;
+pub mod cancel_redemption;
+pub mod close_redemption_window;
+pub mod fund_redemption;
+pub mod settle_redemption;
+pub mod sweep_redemption_window;
+
+pub use schedule_coupon::*;
+pub use record_payment::*;
+pub use distribute::*;
+pub use open_redemption_window::*;
+pub use request_redemption::*;
+pub use cancel_redemption::*;
+pub use close_redemption_window::*;
+pub use fund_redemption::*;
+pub use settle_redemption::*;
+pub use sweep_redemption_window::*;
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use compliance_modules::program::ComplianceModules;
+use srwa_controller::program::SrwaController;
+use srwa_factory::state::SRWAConfig;
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+#[instruction(window_id: u64)]
+pub struct OpenRedemptionWindow<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin @ CashflowError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RedemptionWindow::INIT_SPACE,
+        seeds = [RedemptionWindow::SEED_PREFIX, mint.key().as_ref(), &window_id.to_le_bytes()],
+        bump
+    )]
+    pub window: Account<'info, RedemptionWindow>,
+
+    /// Escrow holding the SRWA tokens submitted for redemption
+    #[account(
+        init,
+        payer = authority,
+        seeds = [RedemptionWindow::ESCROW_SEED_PREFIX, window.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = window,
+        token::token_program = token_program,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mint::token_program = payment_token_program)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    /// Vault the issuer funds with the payment currency
+    #[account(
+        init,
+        payer = authority,
+        seeds = [RedemptionWindow::VAULT_SEED_PREFIX, window.key().as_ref()],
+        bump,
+        token::mint = payment_mint,
+        token::authority = window,
+        token::token_program = payment_token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Mint's ProgramAllowlist, created by compliance_modules
+    #[account(mut)]
+    pub program_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: Mint's AccountAllowlist, created by compliance_modules
+    #[account(mut)]
+    pub account_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: Holder checkpoint of the escrow, created by the controller
+    #[account(mut)]
+    pub escrow_checkpoint: UncheckedAccount<'info>,
+
+    pub compliance_program: Program<'info, ComplianceModules>,
+    pub controller_program: Program<'info, SrwaController>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub payment_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<OpenRedemptionWindow>,
+    window_id: u64,
+    kind: RedemptionKind,
+    opens_at: i64,
+    closes_at: i64,
+    max_redemption_bps: u16,
+    nav_currency: u8,
+    nav_decimals: u8,
+) -> Result<()> {
+    require!(closes_at > opens_at, CashflowError::InvalidWindow);
+    require!(
+        max_redemption_bps > 0 && max_redemption_bps as u64 <= BPS_DENOMINATOR,
+        CashflowError::InvalidGate
+    );
+    require!(nav_decimals <= 18, CashflowError::InvalidNavDecimals);
+
+    // The escrow leg of every redemption transfer goes through the transfer
+    // hook, so the escrow is allowlisted as a venue account and gets its own
+    // checkpoint.
+    compliance_modules::cpi::allowlist_venue(
+        CpiContext::new(
+            ctx.accounts.compliance_program.to_account_info(),
+            compliance_modules::cpi::accounts::AllowlistVenue {
+                authority: ctx.accounts.authority.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                srwa_config: ctx.accounts.srwa_config.to_account_info(),
+                admin_registry: None,
+                program_allowlist: ctx.accounts.program_allowlist.to_account_info(),
+                account_allowlist: ctx.accounts.account_allowlist.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+        ),
+        crate::ID,
+        vec![ctx.accounts.escrow.key()],
+    )?;
+    srwa_controller::cpi::open_checkpoint(CpiContext::new(
+        ctx.accounts.controller_program.to_account_info(),
+        srwa_controller::cpi::accounts::OpenCheckpoint {
+            payer: ctx.accounts.authority.to_account_info(),
+            token_account: ctx.accounts.escrow.to_account_info(),
+            checkpoint: ctx.accounts.escrow_checkpoint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+    ))?;
+
+    let window = &mut ctx.accounts.window;
+    window.mint = ctx.accounts.mint.key();
+    window.window_id = window_id;
+    window.kind = kind;
+    window.payment_mint = ctx.accounts.payment_mint.key();
+    window.opens_at = opens_at;
+    window.closes_at = closes_at;
+    window.max_redemption_bps = max_redemption_bps;
+    window.supply_at_open = ctx.accounts.mint.supply;
+    window.requested_amount = 0;
+    window.accepted_amount = 0;
+    window.price_per_token = 0;
+    window.required_funding = 0;
+    window.funded_amount = 0;
+    window.paid_amount = 0;
+    window.pending_requests = 0;
+    window.status = RedemptionStatus::Open;
+    window.authority = ctx.accounts.authority.key();
+    window.bump = ctx.bumps.window;
+    window.nav_currency = nav_currency;
+    window.nav_decimals = nav_decimals;
+    window.payment_decimals = ctx.accounts.payment_mint.decimals;
+
+    emit!(RedemptionWindowOpened {
+        mint: window.mint,
+        window_id,
+        kind,
+        payment_mint: window.payment_mint,
+        opens_at,
+        closes_at,
+        max_redemption_bps,
+        supply_at_open: window.supply_at_open,
+        nav_currency,
+        nav_decimals,
+    });
+
+    msg!("Redemption window {} opened for {}", window_id, window.mint);
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RecordPayment<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<RecordPayment>,
+    amount: u64,
+    currency: u8,
+) -> Result<()> {
+    msg!("Payment recorded: amount={}, currency={}", amount, currency);
+    Ok(())
+}
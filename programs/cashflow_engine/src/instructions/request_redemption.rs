@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::onchain::invoke_transfer_checked,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+pub struct RequestRedemption<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [RedemptionWindow::SEED_PREFIX, mint.key().as_ref(), &window.window_id.to_le_bytes()],
+        bump = window.bump,
+        has_one = mint,
+        constraint = window.status == RedemptionStatus::Open @ CashflowError::WindowNotOpen
+    )]
+    pub window: Account<'info, RedemptionWindow>,
+
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + RedemptionRequest::INIT_SPACE,
+        seeds = [RedemptionRequest::SEED_PREFIX, window.key().as_ref(), holder.key().as_ref()],
+        bump
+    )]
+    pub request: Account<'info, RedemptionRequest>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder,
+        token::token_program = token_program,
+    )]
+    pub holder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [RedemptionWindow::ESCROW_SEED_PREFIX, window.key().as_ref()],
+        bump,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    // remaining_accounts: transfer hook extra accounts for the SRWA mint
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RequestRedemption<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, CashflowError::InvalidAmount);
+
+    let clock = Clock::get()?;
+    let window = &ctx.accounts.window;
+    require!(
+        clock.unix_timestamp >= window.opens_at && clock.unix_timestamp < window.closes_at,
+        CashflowError::WindowNotOpen
+    );
+
+    // Escrow the tokens; the transfer hook checks the holder's compliance
+    invoke_transfer_checked(
+        ctx.accounts.token_program.key,
+        ctx.accounts.holder_token_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.holder.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.mint.decimals,
+        &[],
+    )?;
+
+    let request = &mut ctx.accounts.request;
+    request.window = ctx.accounts.window.key();
+    request.holder = ctx.accounts.holder.key();
+    request.amount = amount;
+    request.accepted_amount = 0;
+    request.payout = 0;
+    request.status = RedemptionRequestStatus::Pending;
+    request.created_at = clock.unix_timestamp;
+    request.bump = ctx.bumps.request;
+
+    let window = &mut ctx.accounts.window;
+    window.requested_amount = window
+        .requested_amount
+        .checked_add(amount)
+        .ok_or(CashflowError::MathOverflow)?;
+    window.pending_requests += 1;
+
+    emit!(RedemptionRequested {
+        mint: window.mint,
+        window_id: window.window_id,
+        holder: request.holder,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Redemption requested: {} tokens by {}", amount, request.holder);
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use srwa_factory::state::SRWAConfig;
+use crate::{state::*, errors::*};
+
+#[derive(Accounts)]
+pub struct ScheduleCoupon<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+
+    /// Par value settles redemptions at maturity, so only the issuer may set it
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin @ CashflowError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + 200,
+        seeds = [b"coupon_schedule", mint.key().as_ref()],
+        bump
+    )]
+    pub schedule: Account<'info, CouponSchedule>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ScheduleCoupon>,
+    rate_bps: u16,
+    frequency: u8,
+    tenor_years: u8,
+    par_value: u64,
+    issue_ts: i64,
+) -> Result<()> {
+    let schedule = &mut ctx.accounts.schedule;
+    schedule.mint = ctx.accounts.mint.key();
+    schedule.rate_bps = rate_bps;
+    schedule.frequency = frequency;
+    schedule.tenor_years = tenor_years;
+    schedule.par_value = par_value;
+    schedule.issue_ts = issue_ts;
+
+    msg!("Coupon scheduled: rate={}bps, freq={}, tenor={}", rate_bps, frequency, tenor_years);
+    msg!("Par {} at maturity {}", par_value, schedule.maturity_ts());
+    Ok(())
+}
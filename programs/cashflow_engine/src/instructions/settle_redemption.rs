@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::onchain::invoke_transfer_checked,
+    token_interface::{self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+pub struct SettleRedemption<'info> {
+    /// Permissionless crank once the window is closed and funded
+    pub payer: Signer<'info>,
+
+    /// CHECK: Request holder, receives the rent of the closed request
+    #[account(mut)]
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [RedemptionWindow::SEED_PREFIX, mint.key().as_ref(), &window.window_id.to_le_bytes()],
+        bump = window.bump,
+        has_one = mint,
+        has_one = payment_mint @ CashflowError::PaymentMintMismatch,
+        constraint = window.status == RedemptionStatus::Closed @ CashflowError::WindowStillOpen
+    )]
+    pub window: Account<'info, RedemptionWindow>,
+
+    #[account(
+        mut,
+        close = holder,
+        seeds = [RedemptionRequest::SEED_PREFIX, window.key().as_ref(), holder.key().as_ref()],
+        bump = request.bump,
+        has_one = holder,
+        has_one = window,
+        constraint = request.status == RedemptionRequestStatus::Pending @ CashflowError::RequestNotPending
+    )]
+    pub request: Account<'info, RedemptionRequest>,
+
+    #[account(
+        mut,
+        seeds = [RedemptionWindow::ESCROW_SEED_PREFIX, window.key().as_ref()],
+        bump,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    /// Receives the part of the request rejected by the gate
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder,
+        token::token_program = token_program,
+    )]
+    pub holder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mint::token_program = payment_token_program)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [RedemptionWindow::VAULT_SEED_PREFIX, window.key().as_ref()],
+        bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = holder,
+        token::token_program = payment_token_program,
+    )]
+    pub holder_payment_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub payment_token_program: Interface<'info, TokenInterface>,
+    // remaining_accounts: transfer hook extra accounts for the SRWA mint
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, SettleRedemption<'info>>) -> Result<()> {
+    let window = &ctx.accounts.window;
+    require!(
+        window.funded_amount >= window.required_funding,
+        CashflowError::InsufficientFunding
+    );
+
+    let amount = ctx.accounts.request.amount;
+    let accepted = window.accepted_for(amount)?;
+    let returned = amount - accepted;
+    let payout = window.payout_for(accepted, ctx.accounts.mint.decimals)?;
+
+    let mint_key = ctx.accounts.mint.key();
+    let window_id = window.window_id.to_le_bytes();
+    let window_seeds: &[&[u8]] = &[
+        RedemptionWindow::SEED_PREFIX,
+        mint_key.as_ref(),
+        &window_id,
+        &[window.bump],
+    ];
+    let signer_seeds = &[window_seeds];
+
+    // Burns bypass the transfer hook
+    if accepted > 0 {
+        token_interface::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.escrow.to_account_info(),
+                    authority: ctx.accounts.window.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            accepted,
+        )?;
+    }
+
+    if returned > 0 {
+        invoke_transfer_checked(
+            ctx.accounts.token_program.key,
+            ctx.accounts.escrow.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.holder_token_account.to_account_info(),
+            ctx.accounts.window.to_account_info(),
+            ctx.remaining_accounts,
+            returned,
+            ctx.accounts.mint.decimals,
+            signer_seeds,
+        )?;
+    }
+
+    if payout > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.payment_token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault.to_account_info(),
+                    mint: ctx.accounts.payment_mint.to_account_info(),
+                    to: ctx.accounts.holder_payment_account.to_account_info(),
+                    authority: ctx.accounts.window.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            payout,
+            ctx.accounts.payment_mint.decimals,
+        )?;
+    }
+
+    let request = &mut ctx.accounts.request;
+    request.accepted_amount = accepted;
+    request.payout = payout;
+    request.status = RedemptionRequestStatus::Settled;
+
+    let window = &mut ctx.accounts.window;
+    window.paid_amount = window
+        .paid_amount
+        .checked_add(payout)
+        .ok_or(CashflowError::MathOverflow)?;
+    window.pending_requests -= 1;
+
+    emit!(RedemptionSettled {
+        mint: window.mint,
+        window_id: window.window_id,
+        holder: request.holder,
+        burned: accepted,
+        returned,
+        payout,
+    });
+
+    msg!("Redemption settled: burned {}, returned {}, paid {}", accepted, returned, payout);
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, Burn, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use srwa_factory::state::SRWAConfig;
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+pub struct SweepRedemptionWindow<'info> {
+    /// Issuer admin, receives the surplus funding and the rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin @ CashflowError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [RedemptionWindow::SEED_PREFIX, mint.key().as_ref(), &window.window_id.to_le_bytes()],
+        bump = window.bump,
+        has_one = mint,
+        has_one = payment_mint @ CashflowError::PaymentMintMismatch,
+        constraint = window.status == RedemptionStatus::Closed @ CashflowError::WindowStillOpen,
+        constraint = window.pending_requests == 0 @ CashflowError::PendingRequests
+    )]
+    pub window: Account<'info, RedemptionWindow>,
+
+    #[account(
+        mut,
+        seeds = [RedemptionWindow::ESCROW_SEED_PREFIX, window.key().as_ref()],
+        bump,
+    )]
+    pub escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mint::token_program = payment_token_program)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [RedemptionWindow::VAULT_SEED_PREFIX, window.key().as_ref()],
+        bump,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Receives whatever is left in the vault
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = authority,
+        token::token_program = payment_token_program,
+    )]
+    pub authority_payment_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub payment_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Once every request is settled or cancelled, return the surplus funding to
+/// the issuer and close the window, its escrow and its vault.
+pub fn handler(ctx: Context<SweepRedemptionWindow>) -> Result<()> {
+    let window = &ctx.accounts.window;
+    let mint_key = ctx.accounts.mint.key();
+    let window_id = window.window_id.to_le_bytes();
+    let window_seeds: &[&[u8]] = &[
+        RedemptionWindow::SEED_PREFIX,
+        mint_key.as_ref(),
+        &window_id,
+        &[window.bump],
+    ];
+    let signer_seeds = &[window_seeds];
+
+    // Every request has been burned or returned, so anything still in the
+    // escrow was sent outside a request; burning bypasses the transfer hook
+    let stray = ctx.accounts.escrow.amount;
+    if stray > 0 {
+        token_interface::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.escrow.to_account_info(),
+                    authority: ctx.accounts.window.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            stray,
+        )?;
+    }
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: ctx.accounts.window.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    let surplus = ctx.accounts.vault.amount;
+    if surplus > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.payment_token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault.to_account_info(),
+                    mint: ctx.accounts.payment_mint.to_account_info(),
+                    to: ctx.accounts.authority_payment_account.to_account_info(),
+                    authority: ctx.accounts.window.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            surplus,
+            ctx.accounts.payment_mint.decimals,
+        )?;
+    }
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.payment_token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: ctx.accounts.window.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    emit!(RedemptionWindowSwept {
+        mint: window.mint,
+        window_id: window.window_id,
+        surplus,
+        burned: stray,
+        authority: ctx.accounts.authority.key(),
+    });
+
+    msg!(
+        "Redemption window {} swept: {} surplus returned, {} stray tokens burned",
+        window.window_id,
+        surplus,
+        stray
+    );
+
+    Ok(())
+}
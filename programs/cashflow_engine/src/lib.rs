@@ -2,6 +2,14 @@ use anchor_lang::prelude::*;
 
 declare_id!("4ySjU9NzSwg457oxWCVgaH3fqqrhh7iDQco7Db1Zq4Di");
 
+pub mod state;
+pub mod instructions;
+pub mod errors;
+pub mod events;
+
+use instructions::*;
+use state::RedemptionKind;
+
 #[program]
 pub mod cashflow_engine {
     use super::*;
@@ -11,15 +19,10 @@ pub mod cashflow_engine {
         rate_bps: u16,
         frequency: u8,
         tenor_years: u8,
+        par_value: u64,
+        issue_ts: i64,
     ) -> Result<()> {
-        let schedule = &mut ctx.accounts.schedule;
-        schedule.mint = ctx.accounts.mint.key();
-        schedule.rate_bps = rate_bps;
-        schedule.frequency = frequency;
-        schedule.tenor_years = tenor_years;
-
-        msg!("Coupon scheduled: rate={}bps, freq={}, tenor={}", rate_bps, frequency, tenor_years);
-        Ok(())
+        instructions::schedule_coupon::handler(ctx, rate_bps, frequency, tenor_years, par_value, issue_ts)
     }
 
     pub fn record_payment(
@@ -27,58 +30,70 @@ pub mod cashflow_engine {
         amount: u64,
         currency: u8,
     ) -> Result<()> {
-        msg!("Payment recorded: amount={}, currency={}", amount, currency);
-        Ok(())
+        instructions::record_payment::handler(ctx, amount, currency)
     }
 
     pub fn distribute(ctx: Context<Distribute>) -> Result<()> {
-        // Waterfall distribution logic
-        // 1. Fees
-        // 2. Senior tranche
-        // 3. Mezz tranche
-        // 4. Equity/Junior
-
-        msg!("Cashflow distributed via waterfall");
-        Ok(())
+        instructions::distribute::handler(ctx)
     }
-}
 
-#[derive(Accounts)]
-pub struct ScheduleCoupon<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    /// Open a redemption window (issuer admin)
+    pub fn open_redemption_window(
+        ctx: Context<OpenRedemptionWindow>,
+        window_id: u64,
+        kind: RedemptionKind,
+        opens_at: i64,
+        closes_at: i64,
+        max_redemption_bps: u16,
+        nav_currency: u8,
+        nav_decimals: u8,
+    ) -> Result<()> {
+        instructions::open_redemption_window::handler(
+            ctx,
+            window_id,
+            kind,
+            opens_at,
+            closes_at,
+            max_redemption_bps,
+            nav_currency,
+            nav_decimals,
+        )
+    }
 
-    /// CHECK: Mint account
-    pub mint: UncheckedAccount<'info>,
+    /// Escrow tokens for redemption in an open window
+    pub fn request_redemption<'info>(
+        ctx: Context<'_, '_, '_, 'info, RequestRedemption<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::request_redemption::handler(ctx, amount)
+    }
 
-    #[account(
-        init_if_needed,
-        payer = authority,
-        space = 8 + 200,
-        seeds = [b"coupon_schedule", mint.key().as_ref()],
-        bump
-    )]
-    pub schedule: Account<'info, CouponSchedule>,
+    /// Withdraw a pending request before the window closes
+    pub fn cancel_redemption<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelRedemption<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_redemption::handler(ctx)
+    }
 
-    pub system_program: Program<'info, System>,
-}
+    /// Lock the price and apply the gate once the window has ended
+    pub fn close_redemption_window(ctx: Context<CloseRedemptionWindow>) -> Result<()> {
+        instructions::close_redemption_window::handler(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct RecordPayment<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
-}
+    /// Deposit payment tokens into the redemption vault
+    pub fn fund_redemption(ctx: Context<FundRedemption>, amount: u64) -> Result<()> {
+        instructions::fund_redemption::handler(ctx, amount)
+    }
 
-#[derive(Accounts)]
-pub struct Distribute<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
-}
+    /// Burn the accepted tokens, return the rest and pay the holder
+    pub fn settle_redemption<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleRedemption<'info>>,
+    ) -> Result<()> {
+        instructions::settle_redemption::handler(ctx)
+    }
 
-#[account]
-pub struct CouponSchedule {
-    pub mint: Pubkey,
-    pub rate_bps: u16,
-    pub frequency: u8,
-    pub tenor_years: u8,
+    /// Return the surplus funding and close a fully settled window (issuer admin)
+    pub fn sweep_redemption_window(ctx: Context<SweepRedemptionWindow>) -> Result<()> {
+        instructions::sweep_redemption_window::handler(ctx)
+    }
 }
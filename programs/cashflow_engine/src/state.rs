@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+
+pub const SECONDS_PER_YEAR: i64 = 365 * 24 * 60 * 60;
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+#[account]
+pub struct CouponSchedule {
+    pub mint: Pubkey,
+    pub rate_bps: u16,
+    pub frequency: u8,
+    pub tenor_years: u8,
+    pub par_value: u64, // payment units per whole token, paid at maturity
+    pub issue_ts: i64,
+}
+
+impl CouponSchedule {
+    pub fn maturity_ts(&self) -> i64 {
+        self.issue_ts + self.tenor_years as i64 * SECONDS_PER_YEAR
+    }
+}
+
+/// Redemption window opened by the issuer
+#[account]
+#[derive(InitSpace)]
+pub struct RedemptionWindow {
+    pub mint: Pubkey,
+    pub window_id: u64,
+    pub kind: RedemptionKind,
+    pub payment_mint: Pubkey,
+    pub opens_at: i64,
+    pub closes_at: i64,
+    pub max_redemption_bps: u16, // gate: share of supply redeemable in this window
+    pub supply_at_open: u64,
+    pub requested_amount: u64,
+    pub accepted_amount: u64,
+    pub price_per_token: u64, // locked at close, payment units per whole token
+    pub required_funding: u64,
+    pub funded_amount: u64,
+    pub paid_amount: u64,
+    pub pending_requests: u32,
+    pub status: RedemptionStatus,
+    pub authority: Pubkey,
+    pub bump: u8,
+    pub nav_currency: u8,     // currency code the NAV must be published in
+    pub nav_decimals: u8,     // fixed-point decimals of the published NAV
+    pub payment_decimals: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum RedemptionKind {
+    /// Open-ended fund: settle at the published NAV
+    Nav,
+    /// Bond-like: settle at par once the coupon schedule has matured
+    Maturity,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum RedemptionStatus {
+    Open,
+    Closed,
+}
+
+/// Holder redemption request, tokens are escrowed until settlement
+#[account]
+#[derive(InitSpace)]
+pub struct RedemptionRequest {
+    pub window: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub accepted_amount: u64,
+    pub payout: u64,
+    pub status: RedemptionRequestStatus,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum RedemptionRequestStatus {
+    Pending,
+    Settled,
+}
+
+impl RedemptionWindow {
+    pub const SEED_PREFIX: &'static [u8] = b"redemption_window";
+    pub const ESCROW_SEED_PREFIX: &'static [u8] = b"redemption_escrow";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"redemption_vault";
+
+    /// Maximum number of tokens this window may redeem
+    pub fn gate_amount(&self) -> Result<u64> {
+        let cap = (self.supply_at_open as u128)
+            .checked_mul(self.max_redemption_bps as u128)
+            .ok_or(crate::errors::CashflowError::MathOverflow)?
+            / BPS_DENOMINATOR as u128;
+        Ok(cap as u64)
+    }
+
+    /// Part of a request accepted after the gate is applied pro-rata
+    pub fn accepted_for(&self, amount: u64) -> Result<u64> {
+        if self.accepted_amount >= self.requested_amount {
+            return Ok(amount);
+        }
+        let accepted = (amount as u128)
+            .checked_mul(self.accepted_amount as u128)
+            .ok_or(crate::errors::CashflowError::MathOverflow)?
+            / self.requested_amount as u128;
+        Ok(accepted as u64)
+    }
+
+    /// Whole-token NAV converted to payment base units per whole token
+    pub fn price_from_nav(&self, nav_per_token: u64) -> Result<u64> {
+        let price = (nav_per_token as u128)
+            .checked_mul(10u128.pow(self.payment_decimals as u32))
+            .ok_or(crate::errors::CashflowError::MathOverflow)?
+            / 10u128.pow(self.nav_decimals as u32);
+        u64::try_from(price).map_err(|_| error!(crate::errors::CashflowError::MathOverflow))
+    }
+
+    /// Payment owed for `amount` base units at the locked price
+    pub fn payout_for(&self, amount: u64, decimals: u8) -> Result<u64> {
+        let payout = (amount as u128)
+            .checked_mul(self.price_per_token as u128)
+            .ok_or(crate::errors::CashflowError::MathOverflow)?
+            / 10u128.pow(decimals as u32);
+        u64::try_from(payout).map_err(|_| error!(crate::errors::CashflowError::MathOverflow))
+    }
+}
+
+impl RedemptionRequest {
+    pub const SEED_PREFIX: &'static [u8] = b"redemption_request";
+}
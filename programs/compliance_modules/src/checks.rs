@@ -0,0 +1,369 @@
+//! Rule evaluation shared by the check instructions, the controller's
+//! transfer hook and the offering programs. Module accounts are taken as raw
+//! `AccountInfo`s so callers can pass PDAs that were never created.
+use anchor_lang::prelude::*;
+use identity_claims::state::{topics, ClaimAccount};
+use srwa_factory::state::{SRWAConfig, TrustedIssuerEntry};
+use crate::{state::*, errors::*};
+
+/// Read the mint's JurisdictionConfig, if one has been created
+pub fn load_jurisdiction_config(
+    config_info: &AccountInfo,
+    mint: &Pubkey,
+) -> Result<Option<JurisdictionConfig>> {
+    if config_info.owner != &crate::ID || config_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let config = JurisdictionConfig::try_deserialize(&mut &config_info.try_borrow_data()?[..])?;
+    require_keys_eq!(config.mint, *mint, ComplianceError::JurisdictionDenied);
+
+    Ok(Some(config))
+}
+
+/// Country of the user's RESIDENCY claim, if it exists and is still valid
+pub fn residency_country(claim_info: &AccountInfo, user: &Pubkey, now: i64) -> Result<Option<u16>> {
+    if claim_info.owner != &identity_claims::ID || claim_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let claim = ClaimAccount::try_deserialize(&mut &claim_info.try_borrow_data()?[..])?;
+    if claim.user != *user || claim.topic != topics::RESIDENCY || !claim.is_valid(now) {
+        return Ok(None);
+    }
+
+    Ok(Some(claim.country_code()))
+}
+
+/// Apply the mint's jurisdiction rules to `user`. Mints without a config
+/// accept everyone; users without a claim pass unless the config sets
+/// `FLAG_REQUIRE_CLAIM`.
+pub fn check_jurisdiction(
+    config_info: &AccountInfo,
+    claim_info: &AccountInfo,
+    mint: &Pubkey,
+    user: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    let Some(config) = load_jurisdiction_config(config_info, mint)? else {
+        return Ok(());
+    };
+    check_jurisdiction_with(&config, claim_info, user, now)
+}
+
+/// Same as `check_jurisdiction` with an already loaded config
+pub fn check_jurisdiction_with(
+    config: &JurisdictionConfig,
+    claim_info: &AccountInfo,
+    user: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    match residency_country(claim_info, user, now)? {
+        Some(country) => {
+            require!(config.is_allowed(country), ComplianceError::JurisdictionDenied);
+        }
+        None => {
+            require!(
+                !config.has_flag(JurisdictionConfig::FLAG_REQUIRE_CLAIM),
+                ComplianceError::ResidencyClaimRequired
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Whether `marker_info` is the sanctions marker of `address`
+pub fn is_sanctioned(marker_info: &AccountInfo, address: &Pubkey) -> Result<bool> {
+    if marker_info.owner != &crate::ID || marker_info.data_is_empty() {
+        return Ok(false);
+    }
+
+    let marker = SanctionedAddress::try_deserialize(&mut &marker_info.try_borrow_data()?[..])?;
+    Ok(marker.address == *address)
+}
+
+/// Fail if `address` is on the mint's list or the platform-wide list
+pub fn check_sanctions(
+    mint_marker: &AccountInfo,
+    platform_marker: &AccountInfo,
+    address: &Pubkey,
+) -> Result<()> {
+    require!(
+        !is_sanctioned(mint_marker, address)? && !is_sanctioned(platform_marker, address)?,
+        ComplianceError::Sanctioned
+    );
+    Ok(())
+}
+
+/// Read the user's LockupAccount for `mint`, if one has been set
+pub fn load_lockup(lockup_info: &AccountInfo, mint: &Pubkey, user: &Pubkey) -> Result<Option<LockupAccount>> {
+    if lockup_info.owner != &crate::ID || lockup_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let lockup = LockupAccount::try_deserialize(&mut &lockup_info.try_borrow_data()?[..])?;
+    if lockup.mint != *mint || lockup.user != *user {
+        return Ok(None);
+    }
+
+    Ok(Some(lockup))
+}
+
+/// Fail if `balance_after` (the sender's balance once the transfer settles)
+/// is below the amount still locked at `now`
+pub fn check_lockup(
+    lockup_info: &AccountInfo,
+    mint: &Pubkey,
+    user: &Pubkey,
+    balance_after: u64,
+    now: i64,
+) -> Result<()> {
+    if let Some(lockup) = load_lockup(lockup_info, mint, user)? {
+        require!(balance_after >= lockup.locked_at(now), ComplianceError::LockupActive);
+    }
+    Ok(())
+}
+
+/// Read the mint's VolumeCapsConfig, if one has been created
+pub fn load_volume_caps(caps_info: &AccountInfo, mint: &Pubkey) -> Result<Option<VolumeCapsConfig>> {
+    if caps_info.owner != &crate::ID || caps_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let caps = VolumeCapsConfig::try_deserialize(&mut &caps_info.try_borrow_data()?[..])?;
+    if caps.mint != *mint {
+        return Ok(None);
+    }
+
+    Ok(Some(caps))
+}
+
+/// Check a transfer of `amount` against the caps, given the volumes that
+/// include it. A cap of 0 is disabled.
+pub fn check_volume_caps(
+    caps: &VolumeCapsConfig,
+    amount: u64,
+    daily_volume: u64,
+    monthly_volume: u64,
+) -> Result<()> {
+    require!(caps.max_tx == 0 || amount <= caps.max_tx, ComplianceError::MaxTxExceeded);
+    require!(
+        caps.daily_cap == 0 || daily_volume <= caps.daily_cap,
+        ComplianceError::DailyCapExceeded
+    );
+    require!(
+        caps.monthly_cap == 0 || monthly_volume <= caps.monthly_cap,
+        ComplianceError::MonthlyCapExceeded
+    );
+    Ok(())
+}
+
+/// Check a sender's volumes, including this transfer, against the per-holder
+/// caps. A cap of 0 is disabled.
+pub fn check_holder_volume_caps(
+    caps: &VolumeCapsConfig,
+    daily_volume: u64,
+    monthly_volume: u64,
+) -> Result<()> {
+    require!(
+        caps.holder_daily_cap == 0 || daily_volume <= caps.holder_daily_cap,
+        ComplianceError::HolderDailyCapExceeded
+    );
+    require!(
+        caps.holder_monthly_cap == 0 || monthly_volume <= caps.holder_monthly_cap,
+        ComplianceError::HolderMonthlyCapExceeded
+    );
+    Ok(())
+}
+
+/// Fail if the mint has a transfer window and it is closed at `now`
+pub fn check_transfer_window(window_info: &AccountInfo, mint: &Pubkey, now: i64) -> Result<()> {
+    if window_info.owner != &crate::ID || window_info.data_is_empty() {
+        return Ok(());
+    }
+
+    let window = TransferWindowConfig::try_deserialize(&mut &window_info.try_borrow_data()?[..])?;
+    if window.mint == *mint {
+        require!(window.is_open(now), ComplianceError::WindowClosed);
+    }
+    Ok(())
+}
+
+/// Read the mint's MaxHoldersConfig, if one has been created
+pub fn load_max_holders(config_info: &AccountInfo, mint: &Pubkey) -> Result<Option<MaxHoldersConfig>> {
+    if config_info.owner != &crate::ID || config_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let config = MaxHoldersConfig::try_deserialize(&mut &config_info.try_borrow_data()?[..])?;
+    if config.mint != *mint {
+        return Ok(None);
+    }
+
+    Ok(Some(config))
+}
+
+/// Read the mint's SRWAConfig from the factory, if it exists
+pub fn load_srwa_config(config_info: &AccountInfo, mint: &Pubkey) -> Result<Option<SRWAConfig>> {
+    if config_info.owner != &srwa_factory::ID || config_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let config = SRWAConfig::try_deserialize(&mut &config_info.try_borrow_data()?[..])?;
+    if config.mint != *mint {
+        return Ok(None);
+    }
+
+    Ok(Some(config))
+}
+
+/// Whether the user holds a valid claim on `topic` from one of the mint's
+/// trusted issuers for that topic
+pub fn has_trusted_claim(
+    claim_info: &AccountInfo,
+    user: &Pubkey,
+    topic: u32,
+    trusted_issuers: &[TrustedIssuerEntry],
+    now: i64,
+) -> Result<bool> {
+    if claim_info.owner != &identity_claims::ID || claim_info.data_is_empty() {
+        return Ok(false);
+    }
+
+    let claim = ClaimAccount::try_deserialize(&mut &claim_info.try_borrow_data()?[..])?;
+    Ok(claim.user == *user
+        && claim.topic == topic
+        && claim.is_valid(now)
+        && trusted_issuers
+            .iter()
+            .any(|entry| entry.topic == topic && entry.issuer == claim.issuer))
+}
+
+/// Whether the user holds a valid ACCREDITED claim from a trusted issuer
+pub fn is_accredited(
+    claim_info: &AccountInfo,
+    user: &Pubkey,
+    trusted_issuers: &[TrustedIssuerEntry],
+    now: i64,
+) -> Result<bool> {
+    has_trusted_claim(claim_info, user, topics::ACCREDITED, trusted_issuers, now)
+}
+
+/// Fail if admitting one more holder would exceed the caps, given the
+/// current counts
+pub fn check_new_holder(
+    config: &MaxHoldersConfig,
+    holders: u32,
+    non_accredited: u32,
+    accredited: bool,
+) -> Result<()> {
+    require!(
+        config.max_holders == 0 || holders < config.max_holders,
+        ComplianceError::MaxHoldersReached
+    );
+    require!(
+        accredited || config.max_non_accredited == 0 || non_accredited < config.max_non_accredited,
+        ComplianceError::MaxNonAccreditedReached
+    );
+    Ok(())
+}
+
+/// Read the user's InvestorProfile for `mint`, if one has been set
+pub fn load_investor_profile(
+    profile_info: &AccountInfo,
+    mint: &Pubkey,
+    user: &Pubkey,
+) -> Result<Option<InvestorProfile>> {
+    if profile_info.owner != &crate::ID || profile_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let profile = InvestorProfile::try_deserialize(&mut &profile_info.try_borrow_data()?[..])?;
+    if profile.mint != *mint || profile.user != *user {
+        return Ok(None);
+    }
+
+    Ok(Some(profile))
+}
+
+/// Check the receiver's balance after the transfer against its position cap
+/// and its maximum share of `supply`
+pub fn check_position(limits: &InvestorLimits, balance: u64, supply: u64) -> Result<()> {
+    require!(
+        limits.position_cap == 0 || balance <= limits.position_cap,
+        ComplianceError::PositionCapExceeded
+    );
+    require!(
+        limits.concentration_bps == 0
+            || (balance as u128) * 10_000 <= (supply as u128) * limits.concentration_bps as u128,
+        ComplianceError::ConcentrationExceeded
+    );
+    Ok(())
+}
+
+/// Check what the sender moved today, including this transfer
+pub fn check_investor_daily_volume(limits: &InvestorLimits, day_volume: u64) -> Result<()> {
+    require!(
+        limits.daily_volume == 0 || day_volume <= limits.daily_volume,
+        ComplianceError::InvestorLimitExceeded
+    );
+    Ok(())
+}
+
+/// Read the mint's AccreditedConfig, if one has been created
+pub fn load_accredited_config(config_info: &AccountInfo, mint: &Pubkey) -> Result<Option<AccreditedConfig>> {
+    if config_info.owner != &crate::ID || config_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let config = AccreditedConfig::try_deserialize(&mut &config_info.try_borrow_data()?[..])?;
+    if config.mint != *mint {
+        return Ok(None);
+    }
+
+    Ok(Some(config))
+}
+
+/// When the mint requires accreditation, the recipient needs a trusted
+/// ACCREDITED claim unless one of the configured exemptions applies
+pub fn check_accreditation_with(
+    config: &AccreditedConfig,
+    trusted_issuers: &[TrustedIssuerEntry],
+    accredited_claim: &AccountInfo,
+    kyb_claim: &AccountInfo,
+    user: &Pubkey,
+    allowlisted: bool,
+    now: i64,
+) -> Result<()> {
+    if !config.required || (config.exempt_allowlisted && allowlisted) {
+        return Ok(());
+    }
+    if is_accredited(accredited_claim, user, trusted_issuers, now)? {
+        return Ok(());
+    }
+    require!(
+        config.exempt_kyb && has_trusted_claim(kyb_claim, user, topics::KYB, trusted_issuers, now)?,
+        ComplianceError::AccreditationRequired
+    );
+    Ok(())
+}
+
+/// Accreditation check from raw accounts, for subscriptions and other
+/// programs. Mints without a config accept everyone.
+pub fn check_accreditation(
+    config_info: &AccountInfo,
+    srwa_config_info: &AccountInfo,
+    accredited_claim: &AccountInfo,
+    kyb_claim: &AccountInfo,
+    mint: &Pubkey,
+    user: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    let Some(config) = load_accredited_config(config_info, mint)? else {
+        return Ok(());
+    };
+    let trusted_issuers = load_srwa_config(srwa_config_info, mint)?
+        .map(|srwa_config| srwa_config.trusted_issuers_data)
+        .unwrap_or_default();
+    check_accreditation_with(&config, &trusted_issuers, accredited_claim, kyb_claim, user, false, now)
+}
@@ -37,4 +37,46 @@ pub enum ComplianceError {
 
     #[msg("Investor limit exceeded")]
     InvestorLimitExceeded,
+
+    #[msg("Allowlist is full")]
+    AllowlistFull,
+
+    #[msg("Valid residency claim required")]
+    ResidencyClaimRequired,
+
+    #[msg("Invalid lockup schedule")]
+    InvalidLockupSchedule,
+
+    #[msg("Invalid transfer window")]
+    InvalidTransferWindow,
+
+    #[msg("Maximum number of holders reached")]
+    MaxHoldersReached,
+
+    #[msg("Maximum number of non-accredited holders reached")]
+    MaxNonAccreditedReached,
+
+    #[msg("Position cap exceeded")]
+    PositionCapExceeded,
+
+    #[msg("Concentration limit exceeded")]
+    ConcentrationExceeded,
+
+    #[msg("Invalid investor limits")]
+    InvalidInvestorLimits,
+
+    #[msg("Holder daily volume cap exceeded")]
+    HolderDailyCapExceeded,
+
+    #[msg("Holder monthly volume cap exceeded")]
+    HolderMonthlyCapExceeded,
+
+    #[msg("Module has no compliance config account")]
+    ModuleHasNoConfig,
+
+    #[msg("Invalid module config account")]
+    InvalidModuleConfig,
+
+    #[msg("Module is still enabled")]
+    ModuleStillEnabled,
 }
@@ -1,53 +1,162 @@
 use anchor_lang::prelude::*;
+use crate::state::{BlackoutPeriod, LockupSchedule};
 
 #[event]
 pub struct JurisdictionConfigured {
     pub mint: Pubkey,
-    pub num_allowed: u16,
-    pub num_denied: u16,
+    pub old_allow_list: Vec<u16>,
+    pub old_deny_list: Vec<u16>,
+    pub old_flags: u8,
+    pub allow_list: Vec<u16>,
+    pub deny_list: Vec<u16>,
+    pub flags: u8,
+    pub updated_by: Pubkey,
 }
 
 #[event]
 pub struct SanctionsUpdated {
     pub mint: Pubkey,
+    pub old_num_sanctioned: u16, // the lists are too large to log
     pub num_sanctioned: u16,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct AddressSanctioned {
+    pub scope: Pubkey,
+    pub address: Pubkey,
+    pub added_by: Pubkey,
+}
+
+#[event]
+pub struct AddressUnsanctioned {
+    pub scope: Pubkey,
+    pub address: Pubkey,
+    pub removed_by: Pubkey,
+}
+
+#[event]
+pub struct MaxHoldersConfigured {
+    pub mint: Pubkey,
+    pub old_max_holders: u32,
+    pub old_max_non_accredited: u32,
+    pub max_holders: u32,
+    pub max_non_accredited: u32,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct InvestorProfileSet {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub daily_volume: u64,
+    pub position_cap: u64,
+    pub concentration_bps: u16,
+    pub set_by: Pubkey,
+}
+
+#[event]
+pub struct InvestorProfileClosed {
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub closed_by: Pubkey,
 }
 
 #[event]
 pub struct AccreditedConfigured {
     pub mint: Pubkey,
+    pub old_required: bool,
+    pub old_exempt_allowlisted: bool,
+    pub old_exempt_kyb: bool,
     pub required: bool,
+    pub exempt_allowlisted: bool,
+    pub exempt_kyb: bool,
+    pub updated_by: Pubkey,
 }
 
 #[event]
 pub struct LockupSet {
     pub mint: Pubkey,
     pub user: Pubkey,
-    pub end_ts: i64,
+    pub old_schedule: LockupSchedule,
+    pub old_locked_amount: u64,
+    pub schedule: LockupSchedule,
+    pub locked_amount: u64,
+    pub updated_by: Pubkey,
 }
 
 #[event]
 pub struct VolumeCapsSet {
     pub mint: Pubkey,
+    pub old_daily: u64,
+    pub old_monthly: u64,
+    pub old_max_tx: u64,
+    pub old_holder_daily: u64,
+    pub old_holder_monthly: u64,
     pub daily: u64,
     pub monthly: u64,
+    pub max_tx: u64,
+    pub holder_daily: u64,
+    pub holder_monthly: u64,
+    pub updated_by: Pubkey,
 }
 
 #[event]
 pub struct TransferWindowSet {
     pub mint: Pubkey,
-    pub num_hours: u8,
-    pub num_blocked_days: u8,
+    pub old_allowed_hours: Vec<u8>,
+    pub old_blocked_days: Vec<u8>,
+    pub old_utc_offset_minutes: i16,
+    pub old_blackouts: Vec<BlackoutPeriod>,
+    pub allowed_hours: Vec<u8>,
+    pub blocked_days: Vec<u8>,
+    pub utc_offset_minutes: i16,
+    pub blackouts: Vec<BlackoutPeriod>,
+    pub updated_by: Pubkey,
 }
 
 #[event]
 pub struct ProgramAllowlistSet {
     pub mint: Pubkey,
-    pub num_programs: u8,
+    pub old_programs: Vec<Pubkey>,
+    pub programs: Vec<Pubkey>,
+    pub updated_by: Pubkey,
 }
 
 #[event]
 pub struct AccountAllowlistSet {
     pub mint: Pubkey,
-    pub num_accounts: u16,
+    pub old_accounts: Vec<Pubkey>,
+    pub accounts: Vec<Pubkey>,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct VenueAllowlisted {
+    pub mint: Pubkey,
+    pub program: Pubkey,
+    pub accounts: Vec<Pubkey>,
+    pub added_by: Pubkey,
+}
+
+#[event]
+pub struct VenueDelisted {
+    pub mint: Pubkey,
+    pub accounts: Vec<Pubkey>,
+    pub removed_by: Pubkey,
+}
+
+#[event]
+pub struct ModuleParamsApplied {
+    pub mint: Pubkey,
+    pub module_id: u8,
+    pub params: srwa_factory::state::ModuleParams,
+    pub applied_by: Pubkey,
+}
+
+#[event]
+pub struct ModuleConfigClosed {
+    pub mint: Pubkey,
+    pub module_id: u8,
+    pub closed_by: Pubkey,
 }
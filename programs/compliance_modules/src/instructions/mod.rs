@@ -1,6 +1,19 @@
 use anchor_lang::prelude::*;
+use identity_claims::state::topics;
+use srwa_factory::state::{ModuleConfig, ModuleId, ModuleParams, PlatformAdminRegistry, SRWAConfig};
 use crate::{state::*, events::*, errors::*};
 
+/// Mint roles allowed to change its compliance configuration
+fn is_compliance_authority(srwa_config: &SRWAConfig, authority: &Pubkey) -> bool {
+    *authority == srwa_config.roles.compliance_officer || *authority == srwa_config.roles.issuer_admin
+}
+
+fn is_platform_admin(registry: Option<&PlatformAdminRegistry>, authority: &Pubkey) -> bool {
+    registry.is_some_and(|registry| {
+        registry.super_admin == *authority || registry.authorized_admins.contains(authority)
+    })
+}
+
 // Configure Jurisdiction
 #[derive(Accounts)]
 pub struct ConfigureJurisdiction<'info> {
@@ -8,6 +21,14 @@ pub struct ConfigureJurisdiction<'info> {
     pub authority: Signer<'info>,
     /// CHECK: Mint account
     pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_compliance_authority(&srwa_config, &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
     #[account(
         init_if_needed,
         payer = authority,
@@ -23,20 +44,58 @@ pub mod configure_jurisdiction {
     use super::*;
     pub fn handler(ctx: Context<ConfigureJurisdiction>, allow: Vec<u16>, deny: Vec<u16>, flags: u8) -> Result<()> {
         let config = &mut ctx.accounts.jurisdiction_config;
+        let old_allow_list = std::mem::replace(&mut config.allow_list, allow.clone());
+        let old_deny_list = std::mem::replace(&mut config.deny_list, deny.clone());
+        let old_flags = config.flags;
         config.mint = ctx.accounts.mint.key();
-        config.allow_list = allow.clone();
-        config.deny_list = deny.clone();
         config.flags = flags;
         config.bump = ctx.bumps.jurisdiction_config;
         emit!(JurisdictionConfigured {
             mint: ctx.accounts.mint.key(),
-            num_allowed: allow.len() as u16,
-            num_denied: deny.len() as u16,
+            old_allow_list,
+            old_deny_list,
+            old_flags,
+            allow_list: allow,
+            deny_list: deny,
+            flags,
+            updated_by: ctx.accounts.authority.key(),
         });
         Ok(())
     }
 }
 
+// Check Jurisdiction
+#[derive(Accounts)]
+pub struct CheckJurisdiction<'info> {
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+    /// CHECK: User being checked
+    pub user: UncheckedAccount<'info>,
+    /// CHECK: May not exist, evaluated by `checks::check_jurisdiction`
+    #[account(seeds = [b"jurisdiction", mint.key().as_ref()], bump)]
+    pub jurisdiction_config: UncheckedAccount<'info>,
+    /// CHECK: May not exist, evaluated by `checks::check_jurisdiction`
+    #[account(
+        seeds = [b"claim", user.key().as_ref(), &topics::RESIDENCY.to_le_bytes()],
+        bump,
+        seeds::program = identity_claims::ID
+    )]
+    pub residency_claim: UncheckedAccount<'info>,
+}
+
+pub mod check_jurisdiction {
+    use super::*;
+    pub fn handler(ctx: Context<CheckJurisdiction>) -> Result<()> {
+        crate::checks::check_jurisdiction(
+            &ctx.accounts.jurisdiction_config,
+            &ctx.accounts.residency_claim,
+            &ctx.accounts.mint.key(),
+            &ctx.accounts.user.key(),
+            Clock::get()?.unix_timestamp,
+        )
+    }
+}
+
 // Set Sanctions
 #[derive(Accounts)]
 pub struct SetSanctions<'info> {
@@ -44,6 +103,14 @@ pub struct SetSanctions<'info> {
     pub authority: Signer<'info>,
     /// CHECK: Mint account
     pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_compliance_authority(&srwa_config, &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
     #[account(
         init_if_needed,
         payer = authority,
@@ -59,12 +126,246 @@ pub mod set_sanctions {
     use super::*;
     pub fn handler(ctx: Context<SetSanctions>, sanctioned_addresses: Vec<Pubkey>) -> Result<()> {
         let sanctions = &mut ctx.accounts.sanctions;
+        let old_num_sanctioned = sanctions.sanctioned_addresses.len() as u16;
         sanctions.mint = ctx.accounts.mint.key();
         sanctions.sanctioned_addresses = sanctioned_addresses.clone();
         sanctions.bump = ctx.bumps.sanctions;
         emit!(SanctionsUpdated {
             mint: ctx.accounts.mint.key(),
+            old_num_sanctioned,
             num_sanctioned: sanctioned_addresses.len() as u16,
+            updated_by: ctx.accounts.authority.key(),
+        });
+        Ok(())
+    }
+}
+
+// Add Sanctioned (per-mint marker)
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct AddSanctioned<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+    /// Required when the authority is a platform admin rather than an issuer role
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub admin_registry: Option<Account<'info, PlatformAdminRegistry>>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SanctionedAddress::INIT_SPACE,
+        seeds = [SanctionedAddress::SEED_PREFIX, mint.key().as_ref(), address.as_ref()],
+        bump
+    )]
+    pub marker: Account<'info, SanctionedAddress>,
+    pub system_program: Program<'info, System>,
+}
+
+pub mod add_sanctioned {
+    use super::*;
+    pub fn handler(ctx: Context<AddSanctioned>, address: Pubkey) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        require!(
+            is_compliance_authority(&ctx.accounts.srwa_config, &authority)
+                || is_platform_admin(ctx.accounts.admin_registry.as_deref(), &authority),
+            ComplianceError::Unauthorized
+        );
+
+        let marker = &mut ctx.accounts.marker;
+        marker.scope = ctx.accounts.mint.key();
+        marker.address = address;
+        marker.added_by = authority;
+        marker.added_at = Clock::get()?.unix_timestamp;
+        marker.bump = ctx.bumps.marker;
+        emit!(AddressSanctioned {
+            scope: marker.scope,
+            address,
+            added_by: authority,
+        });
+        Ok(())
+    }
+}
+
+// Remove Sanctioned (per-mint marker)
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct RemoveSanctioned<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+    /// Required when the authority is a platform admin rather than an issuer role
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub admin_registry: Option<Account<'info, PlatformAdminRegistry>>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [SanctionedAddress::SEED_PREFIX, mint.key().as_ref(), address.as_ref()],
+        bump = marker.bump
+    )]
+    pub marker: Account<'info, SanctionedAddress>,
+}
+
+pub mod remove_sanctioned {
+    use super::*;
+    pub fn handler(ctx: Context<RemoveSanctioned>, address: Pubkey) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        require!(
+            is_compliance_authority(&ctx.accounts.srwa_config, &authority)
+                || is_platform_admin(ctx.accounts.admin_registry.as_deref(), &authority),
+            ComplianceError::Unauthorized
+        );
+
+        emit!(AddressUnsanctioned {
+            scope: ctx.accounts.mint.key(),
+            address,
+            removed_by: authority,
+        });
+        Ok(())
+    }
+}
+
+// Add Platform Sanctioned (applies to every mint)
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct AddPlatformSanctioned<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+        constraint = is_platform_admin(Some(&*admin_registry), &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub admin_registry: Account<'info, PlatformAdminRegistry>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SanctionedAddress::INIT_SPACE,
+        seeds = [SanctionedAddress::SEED_PREFIX, SanctionedAddress::PLATFORM_SCOPE.as_ref(), address.as_ref()],
+        bump
+    )]
+    pub marker: Account<'info, SanctionedAddress>,
+    pub system_program: Program<'info, System>,
+}
+
+pub mod add_platform_sanctioned {
+    use super::*;
+    pub fn handler(ctx: Context<AddPlatformSanctioned>, address: Pubkey) -> Result<()> {
+        let marker = &mut ctx.accounts.marker;
+        marker.scope = SanctionedAddress::PLATFORM_SCOPE;
+        marker.address = address;
+        marker.added_by = ctx.accounts.authority.key();
+        marker.added_at = Clock::get()?.unix_timestamp;
+        marker.bump = ctx.bumps.marker;
+        emit!(AddressSanctioned {
+            scope: marker.scope,
+            address,
+            added_by: marker.added_by,
+        });
+        Ok(())
+    }
+}
+
+// Remove Platform Sanctioned
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct RemovePlatformSanctioned<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+        constraint = is_platform_admin(Some(&*admin_registry), &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub admin_registry: Account<'info, PlatformAdminRegistry>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [SanctionedAddress::SEED_PREFIX, SanctionedAddress::PLATFORM_SCOPE.as_ref(), address.as_ref()],
+        bump = marker.bump
+    )]
+    pub marker: Account<'info, SanctionedAddress>,
+}
+
+pub mod remove_platform_sanctioned {
+    use super::*;
+    pub fn handler(ctx: Context<RemovePlatformSanctioned>, address: Pubkey) -> Result<()> {
+        emit!(AddressUnsanctioned {
+            scope: SanctionedAddress::PLATFORM_SCOPE,
+            address,
+            removed_by: ctx.accounts.authority.key(),
+        });
+        Ok(())
+    }
+}
+
+// Configure Max Holders
+#[derive(Accounts)]
+pub struct ConfigureMaxHolders<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_compliance_authority(&srwa_config, &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MaxHoldersConfig::INIT_SPACE,
+        seeds = [b"max_holders", mint.key().as_ref()],
+        bump
+    )]
+    pub max_holders_config: Account<'info, MaxHoldersConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+pub mod configure_max_holders {
+    use super::*;
+    pub fn handler(ctx: Context<ConfigureMaxHolders>, max_holders: u32, max_non_accredited: u32) -> Result<()> {
+        let config = &mut ctx.accounts.max_holders_config;
+        let (old_max_holders, old_max_non_accredited) = (config.max_holders, config.max_non_accredited);
+        config.mint = ctx.accounts.mint.key();
+        config.max_holders = max_holders;
+        config.max_non_accredited = max_non_accredited;
+        config.bump = ctx.bumps.max_holders_config;
+        emit!(MaxHoldersConfigured {
+            mint: ctx.accounts.mint.key(),
+            old_max_holders,
+            old_max_non_accredited,
+            max_holders,
+            max_non_accredited,
+            updated_by: ctx.accounts.authority.key(),
         });
         Ok(())
     }
@@ -77,6 +378,14 @@ pub struct ConfigureAccredited<'info> {
     pub authority: Signer<'info>,
     /// CHECK: Mint account
     pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_compliance_authority(&srwa_config, &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
     #[account(
         init_if_needed,
         payer = authority,
@@ -90,14 +399,30 @@ pub struct ConfigureAccredited<'info> {
 
 pub mod configure_accredited {
     use super::*;
-    pub fn handler(ctx: Context<ConfigureAccredited>, required: bool) -> Result<()> {
+    pub fn handler(
+        ctx: Context<ConfigureAccredited>,
+        required: bool,
+        exempt_allowlisted: bool,
+        exempt_kyb: bool,
+    ) -> Result<()> {
         let config = &mut ctx.accounts.accredited_config;
+        let old_required = config.required;
+        let old_exempt_allowlisted = config.exempt_allowlisted;
+        let old_exempt_kyb = config.exempt_kyb;
         config.mint = ctx.accounts.mint.key();
         config.required = required;
+        config.exempt_allowlisted = exempt_allowlisted;
+        config.exempt_kyb = exempt_kyb;
         config.bump = ctx.bumps.accredited_config;
         emit!(AccreditedConfigured {
             mint: ctx.accounts.mint.key(),
+            old_required,
+            old_exempt_allowlisted,
+            old_exempt_kyb,
             required,
+            exempt_allowlisted,
+            exempt_kyb,
+            updated_by: ctx.accounts.authority.key(),
         });
         Ok(())
     }
@@ -110,6 +435,14 @@ pub struct SetLockup<'info> {
     pub authority: Signer<'info>,
     /// CHECK: Mint account
     pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_compliance_authority(&srwa_config, &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
     /// CHECK: User account
     pub user: UncheckedAccount<'info>,
     #[account(
@@ -125,16 +458,23 @@ pub struct SetLockup<'info> {
 
 pub mod set_lockup {
     use super::*;
-    pub fn handler(ctx: Context<SetLockup>, schedule: LockupSchedule) -> Result<()> {
+    pub fn handler(ctx: Context<SetLockup>, schedule: LockupSchedule, locked_amount: u64) -> Result<()> {
+        require!(schedule.is_valid(), ComplianceError::InvalidLockupSchedule);
         let lockup = &mut ctx.accounts.lockup;
+        let old_schedule = std::mem::replace(&mut lockup.schedule, schedule.clone());
+        let old_locked_amount = lockup.locked_amount;
         lockup.mint = ctx.accounts.mint.key();
         lockup.user = ctx.accounts.user.key();
-        lockup.schedule = schedule.clone();
+        lockup.locked_amount = locked_amount;
         lockup.bump = ctx.bumps.lockup;
         emit!(LockupSet {
             mint: ctx.accounts.mint.key(),
             user: ctx.accounts.user.key(),
-            end_ts: schedule.end_ts,
+            old_schedule,
+            old_locked_amount,
+            schedule,
+            locked_amount,
+            updated_by: ctx.accounts.authority.key(),
         });
         Ok(())
     }
@@ -147,6 +487,14 @@ pub struct SetVolumeCaps<'info> {
     pub authority: Signer<'info>,
     /// CHECK: Mint account
     pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_compliance_authority(&srwa_config, &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
     #[account(
         init_if_needed,
         payer = authority,
@@ -160,17 +508,37 @@ pub struct SetVolumeCaps<'info> {
 
 pub mod set_volume_caps {
     use super::*;
-    pub fn handler(ctx: Context<SetVolumeCaps>, daily: u64, monthly: u64, max_tx: u64) -> Result<()> {
+    pub fn handler(
+        ctx: Context<SetVolumeCaps>,
+        daily: u64,
+        monthly: u64,
+        max_tx: u64,
+        holder_daily: u64,
+        holder_monthly: u64,
+    ) -> Result<()> {
         let caps = &mut ctx.accounts.volume_caps;
+        let (old_daily, old_monthly, old_max_tx) = (caps.daily_cap, caps.monthly_cap, caps.max_tx);
+        let (old_holder_daily, old_holder_monthly) = (caps.holder_daily_cap, caps.holder_monthly_cap);
         caps.mint = ctx.accounts.mint.key();
         caps.daily_cap = daily;
         caps.monthly_cap = monthly;
         caps.max_tx = max_tx;
+        caps.holder_daily_cap = holder_daily;
+        caps.holder_monthly_cap = holder_monthly;
         caps.bump = ctx.bumps.volume_caps;
         emit!(VolumeCapsSet {
             mint: ctx.accounts.mint.key(),
+            old_daily,
+            old_monthly,
+            old_max_tx,
+            old_holder_daily,
+            old_holder_monthly,
             daily,
             monthly,
+            max_tx,
+            holder_daily,
+            holder_monthly,
+            updated_by: ctx.accounts.authority.key(),
         });
         Ok(())
     }
@@ -183,6 +551,14 @@ pub struct SetTransferWindow<'info> {
     pub authority: Signer<'info>,
     /// CHECK: Mint account
     pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_compliance_authority(&srwa_config, &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
     #[account(
         init_if_needed,
         payer = authority,
@@ -196,16 +572,40 @@ pub struct SetTransferWindow<'info> {
 
 pub mod set_transfer_window {
     use super::*;
-    pub fn handler(ctx: Context<SetTransferWindow>, allowed_hours: Vec<u8>, blocked_days: Vec<u8>) -> Result<()> {
+    pub fn handler(
+        ctx: Context<SetTransferWindow>,
+        allowed_hours: Vec<u8>,
+        blocked_days: Vec<u8>,
+        utc_offset_minutes: i16,
+        blackouts: Vec<BlackoutPeriod>,
+    ) -> Result<()> {
+        require!(
+            allowed_hours.iter().all(|hour| *hour < 24)
+                && blocked_days.iter().all(|day| *day < 7)
+                && utc_offset_minutes.abs() <= TransferWindowConfig::MAX_UTC_OFFSET_MINUTES
+                && blackouts.len() <= TransferWindowConfig::MAX_BLACKOUTS
+                && blackouts.iter().all(|period| period.start_ts < period.end_ts),
+            ComplianceError::InvalidTransferWindow
+        );
         let window = &mut ctx.accounts.transfer_window;
+        let old_allowed_hours = std::mem::replace(&mut window.allowed_hours, allowed_hours.clone());
+        let old_blocked_days = std::mem::replace(&mut window.blocked_days, blocked_days.clone());
+        let old_blackouts = std::mem::replace(&mut window.blackouts, blackouts.clone());
+        let old_utc_offset_minutes = window.utc_offset_minutes;
         window.mint = ctx.accounts.mint.key();
-        window.allowed_hours = allowed_hours.clone();
-        window.blocked_days = blocked_days.clone();
+        window.utc_offset_minutes = utc_offset_minutes;
         window.bump = ctx.bumps.transfer_window;
         emit!(TransferWindowSet {
             mint: ctx.accounts.mint.key(),
-            num_hours: allowed_hours.len() as u8,
-            num_blocked_days: blocked_days.len() as u8,
+            old_allowed_hours,
+            old_blocked_days,
+            old_utc_offset_minutes,
+            old_blackouts,
+            allowed_hours,
+            blocked_days,
+            utc_offset_minutes,
+            blackouts,
+            updated_by: ctx.accounts.authority.key(),
         });
         Ok(())
     }
@@ -218,6 +618,14 @@ pub struct SetProgramAllowlist<'info> {
     pub authority: Signer<'info>,
     /// CHECK: Mint account
     pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_compliance_authority(&srwa_config, &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
     #[account(
         init_if_needed,
         payer = authority,
@@ -233,12 +641,14 @@ pub mod set_program_allowlist {
     use super::*;
     pub fn handler(ctx: Context<SetProgramAllowlist>, programs: Vec<Pubkey>) -> Result<()> {
         let allowlist = &mut ctx.accounts.program_allowlist;
+        let old_programs = std::mem::replace(&mut allowlist.programs, programs.clone());
         allowlist.mint = ctx.accounts.mint.key();
-        allowlist.programs = programs.clone();
         allowlist.bump = ctx.bumps.program_allowlist;
         emit!(ProgramAllowlistSet {
             mint: ctx.accounts.mint.key(),
-            num_programs: programs.len() as u8,
+            old_programs,
+            programs,
+            updated_by: ctx.accounts.authority.key(),
         });
         Ok(())
     }
@@ -251,6 +661,14 @@ pub struct SetAccountAllowlist<'info> {
     pub authority: Signer<'info>,
     /// CHECK: Mint account
     pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_compliance_authority(&srwa_config, &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
     #[account(
         init_if_needed,
         payer = authority,
@@ -266,12 +684,396 @@ pub mod set_account_allowlist {
     use super::*;
     pub fn handler(ctx: Context<SetAccountAllowlist>, accounts: Vec<Pubkey>) -> Result<()> {
         let allowlist = &mut ctx.accounts.account_allowlist;
+        let old_accounts = std::mem::replace(&mut allowlist.accounts, accounts.clone());
         allowlist.mint = ctx.accounts.mint.key();
-        allowlist.accounts = accounts.clone();
         allowlist.bump = ctx.bumps.account_allowlist;
         emit!(AccountAllowlistSet {
             mint: ctx.accounts.mint.key(),
-            num_accounts: accounts.len() as u16,
+            old_accounts,
+            accounts,
+            updated_by: ctx.accounts.authority.key(),
+        });
+        Ok(())
+    }
+}
+
+// Allowlist Venue (AMM pool vaults and program)
+#[derive(Accounts)]
+pub struct AllowlistVenue<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+    /// Required when the authority is a platform admin rather than the issuer admin
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub admin_registry: Option<Account<'info, PlatformAdminRegistry>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProgramAllowlist::INIT_SPACE,
+        seeds = [b"program_allowlist", mint.key().as_ref()],
+        bump
+    )]
+    pub program_allowlist: Account<'info, ProgramAllowlist>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AccountAllowlist::INIT_SPACE,
+        seeds = [b"account_allowlist", mint.key().as_ref()],
+        bump
+    )]
+    pub account_allowlist: Account<'info, AccountAllowlist>,
+    pub system_program: Program<'info, System>,
+}
+
+pub mod allowlist_venue {
+    use super::*;
+    /// Append a venue program and its vaults to the mint's allowlists
+    pub fn handler(ctx: Context<AllowlistVenue>, program: Pubkey, accounts: Vec<Pubkey>) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        require!(
+            is_compliance_authority(&ctx.accounts.srwa_config, &authority)
+                || is_platform_admin(ctx.accounts.admin_registry.as_deref(), &authority),
+            ComplianceError::Unauthorized
+        );
+
+        let mint = ctx.accounts.mint.key();
+
+        let program_allowlist = &mut ctx.accounts.program_allowlist;
+        program_allowlist.mint = mint;
+        program_allowlist.bump = ctx.bumps.program_allowlist;
+        if !program_allowlist.programs.contains(&program) {
+            require!(
+                program_allowlist.programs.len() < ProgramAllowlist::MAX_PROGRAMS,
+                ComplianceError::AllowlistFull
+            );
+            program_allowlist.programs.push(program);
+        }
+
+        let account_allowlist = &mut ctx.accounts.account_allowlist;
+        account_allowlist.mint = mint;
+        account_allowlist.bump = ctx.bumps.account_allowlist;
+        for account in accounts.iter() {
+            if !account_allowlist.accounts.contains(account) {
+                require!(
+                    account_allowlist.accounts.len() < AccountAllowlist::MAX_ACCOUNTS,
+                    ComplianceError::AllowlistFull
+                );
+                account_allowlist.accounts.push(*account);
+            }
+        }
+
+        emit!(VenueAllowlisted {
+            mint,
+            program,
+            accounts,
+            added_by: authority,
+        });
+        Ok(())
+    }
+}
+
+// Delist Venue Accounts (deactivated AMM pool vaults)
+#[derive(Accounts)]
+pub struct DelistVenueAccounts<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+    /// Required when the authority is a platform admin rather than the issuer admin
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub admin_registry: Option<Account<'info, PlatformAdminRegistry>>,
+    #[account(
+        mut,
+        seeds = [b"account_allowlist", mint.key().as_ref()],
+        bump = account_allowlist.bump
+    )]
+    pub account_allowlist: Account<'info, AccountAllowlist>,
+}
+
+pub mod delist_venue_accounts {
+    use super::*;
+    /// Remove venue vaults from the mint's AccountAllowlist. The venue program
+    /// stays in the ProgramAllowlist, since other pools may share it.
+    pub fn handler(ctx: Context<DelistVenueAccounts>, accounts: Vec<Pubkey>) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        require!(
+            is_compliance_authority(&ctx.accounts.srwa_config, &authority)
+                || is_platform_admin(ctx.accounts.admin_registry.as_deref(), &authority),
+            ComplianceError::Unauthorized
+        );
+
+        ctx.accounts
+            .account_allowlist
+            .accounts
+            .retain(|account| !accounts.contains(account));
+
+        emit!(VenueDelisted {
+            mint: ctx.accounts.mint.key(),
+            accounts,
+            removed_by: authority,
+        });
+        Ok(())
+    }
+}
+
+// Set Investor Profile
+#[derive(Accounts)]
+pub struct SetInvestorProfile<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+    /// CHECK: Investor wallet
+    pub user: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_compliance_authority(&srwa_config, &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + InvestorProfile::INIT_SPACE,
+        seeds = [InvestorProfile::SEED_PREFIX, mint.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub investor_profile: Account<'info, InvestorProfile>,
+    pub system_program: Program<'info, System>,
+}
+
+pub mod set_investor_profile {
+    use super::*;
+    pub fn handler(ctx: Context<SetInvestorProfile>, investor_class: InvestorClass, limits: InvestorLimits) -> Result<()> {
+        require!(limits.concentration_bps <= 10_000, ComplianceError::InvalidInvestorLimits);
+        let profile = &mut ctx.accounts.investor_profile;
+        profile.mint = ctx.accounts.mint.key();
+        profile.user = ctx.accounts.user.key();
+        profile.investor_class = investor_class;
+        profile.limits = limits.clone();
+        profile.bump = ctx.bumps.investor_profile;
+        emit!(InvestorProfileSet {
+            mint: profile.mint,
+            user: profile.user,
+            daily_volume: limits.daily_volume,
+            position_cap: limits.position_cap,
+            concentration_bps: limits.concentration_bps,
+            set_by: ctx.accounts.authority.key(),
+        });
+        Ok(())
+    }
+}
+
+// Close Investor Profile
+#[derive(Accounts)]
+pub struct CloseInvestorProfile<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+    /// CHECK: Investor wallet
+    pub user: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_compliance_authority(&srwa_config, &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [InvestorProfile::SEED_PREFIX, mint.key().as_ref(), user.key().as_ref()],
+        bump = investor_profile.bump
+    )]
+    pub investor_profile: Account<'info, InvestorProfile>,
+}
+
+pub mod close_investor_profile {
+    use super::*;
+    pub fn handler(ctx: Context<CloseInvestorProfile>) -> Result<()> {
+        emit!(InvestorProfileClosed {
+            mint: ctx.accounts.mint.key(),
+            user: ctx.accounts.user.key(),
+            closed_by: ctx.accounts.authority.key(),
+        });
+        Ok(())
+    }
+}
+
+// Apply Module Params
+/// Copies a module's typed params from srwa_factory's ModuleConfig into the
+/// config account the transfer hook reads. Called by enable_module and
+/// update_module_params; `config` must already exist (create it with the
+/// module's configure instruction), other fields are left untouched.
+#[derive(Accounts)]
+pub struct ApplyModuleParams<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_compliance_authority(&srwa_config, &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+    #[account(
+        seeds = [ModuleConfig::SEED_PREFIX, mint.key().as_ref(), &[module_config.module as u8]],
+        bump = module_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint
+    )]
+    pub module_config: Account<'info, ModuleConfig>,
+    /// CHECK: Config PDA of the module, checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub config: UncheckedAccount<'info>,
+}
+
+pub mod apply_module_params {
+    use super::*;
+
+    fn update<T: AccountSerialize + AccountDeserialize>(
+        info: &AccountInfo,
+        update: impl FnOnce(&mut T),
+    ) -> Result<()> {
+        let mut config = T::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        update(&mut config);
+        config.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])
+    }
+
+    pub fn handler(ctx: Context<ApplyModuleParams>) -> Result<()> {
+        let mint = ctx.accounts.mint.key();
+        let params = ctx.accounts.module_config.params.clone();
+        let seed = params.compliance_config_seed().ok_or(ComplianceError::ModuleHasNoConfig)?;
+        let (address, _) = Pubkey::find_program_address(&[seed, mint.as_ref()], &crate::ID);
+        let info = ctx.accounts.config.to_account_info();
+        require_keys_eq!(info.key(), address, ComplianceError::InvalidModuleConfig);
+
+        match params {
+            ModuleParams::Jurisdiction { require_claim, recipient_only } => {
+                update(&info, |config: &mut JurisdictionConfig| {
+                    let flags = JurisdictionConfig::FLAG_REQUIRE_CLAIM | JurisdictionConfig::FLAG_RECIPIENT_ONLY;
+                    config.flags &= !flags;
+                    if require_claim {
+                        config.flags |= JurisdictionConfig::FLAG_REQUIRE_CLAIM;
+                    }
+                    if recipient_only {
+                        config.flags |= JurisdictionConfig::FLAG_RECIPIENT_ONLY;
+                    }
+                })?
+            }
+            ModuleParams::Accredited { exempt_allowlisted, exempt_kyb } => {
+                update(&info, |config: &mut AccreditedConfig| {
+                    config.exempt_allowlisted = exempt_allowlisted;
+                    config.exempt_kyb = exempt_kyb;
+                })?
+            }
+            ModuleParams::MaxHolders { max_holders, max_non_accredited } => {
+                update(&info, |config: &mut MaxHoldersConfig| {
+                    config.max_holders = max_holders;
+                    config.max_non_accredited = max_non_accredited;
+                })?
+            }
+            ModuleParams::VolumeCaps { daily_cap, monthly_cap, max_tx } => {
+                update(&info, |config: &mut VolumeCapsConfig| {
+                    config.daily_cap = daily_cap;
+                    config.monthly_cap = monthly_cap;
+                    config.max_tx = max_tx;
+                })?
+            }
+            ModuleParams::TransferWindow { utc_offset_minutes } => {
+                update(&info, |config: &mut TransferWindowConfig| {
+                    config.utc_offset_minutes = utc_offset_minutes;
+                })?
+            }
+            _ => return err!(ComplianceError::ModuleHasNoConfig),
+        }
+
+        emit!(ModuleParamsApplied {
+            mint,
+            module_id: ctx.accounts.module_config.module as u8,
+            params,
+            applied_by: ctx.accounts.authority.key(),
+        });
+        Ok(())
+    }
+}
+
+// Close Module Config
+/// Closes the config account the transfer hook enforces for a module, once
+/// srwa_factory has disabled it. Called by disable_module; the hook treats a
+/// missing config as unconfigured.
+#[derive(Accounts)]
+pub struct CloseModuleConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_compliance_authority(&srwa_config, &authority.key()) @ ComplianceError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+    /// CHECK: Config PDA of the module, checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub config: UncheckedAccount<'info>,
+}
+
+pub mod close_module_config {
+    use super::*;
+    pub fn handler(ctx: Context<CloseModuleConfig>, module_id: u8) -> Result<()> {
+        let mint = ctx.accounts.mint.key();
+        let module = ModuleId::try_from(module_id)?;
+        require!(
+            !ctx.accounts.srwa_config.modules_enabled.contains(&module),
+            ComplianceError::ModuleStillEnabled
+        );
+        let seed = module.compliance_config_seed().ok_or(ComplianceError::ModuleHasNoConfig)?;
+        let (address, _) = Pubkey::find_program_address(&[seed, mint.as_ref()], &crate::ID);
+        let config = ctx.accounts.config.to_account_info();
+        require_keys_eq!(config.key(), address, ComplianceError::InvalidModuleConfig);
+
+        let authority = ctx.accounts.authority.to_account_info();
+        let lamports = authority.lamports().checked_add(config.lamports()).ok_or(ProgramError::ArithmeticOverflow)?;
+        **authority.try_borrow_mut_lamports()? = lamports;
+        **config.try_borrow_mut_lamports()? = 0;
+        config.assign(&System::id());
+        config.resize(0)?;
+
+        emit!(ModuleConfigClosed {
+            mint,
+            module_id,
+            closed_by: ctx.accounts.authority.key(),
         });
         Ok(())
     }
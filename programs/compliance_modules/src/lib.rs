@@ -6,6 +6,7 @@ pub mod state;
 pub mod instructions;
 pub mod errors;
 pub mod events;
+pub mod checks;
 
 use state::*;
 use instructions::*;
@@ -26,7 +27,12 @@ pub mod compliance_modules {
         instructions::configure_jurisdiction::handler(ctx, allow, deny, flags)
     }
 
-    /// Set sanctions list
+    /// Fail unless `user` may hold or receive `mint` under its jurisdiction rules
+    pub fn check_jurisdiction(ctx: Context<CheckJurisdiction>) -> Result<()> {
+        instructions::check_jurisdiction::handler(ctx)
+    }
+
+    /// Set sanctions list (legacy bulk list, not read by the transfer hook)
     pub fn set_sanctions(
         ctx: Context<SetSanctions>,
         sanctioned_addresses: Vec<Pubkey>,
@@ -34,39 +40,75 @@ pub mod compliance_modules {
         instructions::set_sanctions::handler(ctx, sanctioned_addresses)
     }
 
-    /// Configure accreditation requirement
+    /// Flag a wallet as sanctioned for one mint
+    pub fn add_sanctioned(ctx: Context<AddSanctioned>, address: Pubkey) -> Result<()> {
+        instructions::add_sanctioned::handler(ctx, address)
+    }
+
+    /// Clear a wallet from a mint's sanctions list
+    pub fn remove_sanctioned(ctx: Context<RemoveSanctioned>, address: Pubkey) -> Result<()> {
+        instructions::remove_sanctioned::handler(ctx, address)
+    }
+
+    /// Flag a wallet as sanctioned for every mint (platform admins)
+    pub fn add_platform_sanctioned(ctx: Context<AddPlatformSanctioned>, address: Pubkey) -> Result<()> {
+        instructions::add_platform_sanctioned::handler(ctx, address)
+    }
+
+    /// Clear a wallet from the platform-wide sanctions list
+    pub fn remove_platform_sanctioned(ctx: Context<RemovePlatformSanctioned>, address: Pubkey) -> Result<()> {
+        instructions::remove_platform_sanctioned::handler(ctx, address)
+    }
+
+    /// Cap the number of holders, and of non-accredited holders (0 = uncapped)
+    pub fn configure_max_holders(
+        ctx: Context<ConfigureMaxHolders>,
+        max_holders: u32,
+        max_non_accredited: u32,
+    ) -> Result<()> {
+        instructions::configure_max_holders::handler(ctx, max_holders, max_non_accredited)
+    }
+
+    /// Configure accreditation requirement and its exemptions
     pub fn configure_accredited(
         ctx: Context<ConfigureAccredited>,
         required: bool,
+        exempt_allowlisted: bool,
+        exempt_kyb: bool,
     ) -> Result<()> {
-        instructions::configure_accredited::handler(ctx, required)
+        instructions::configure_accredited::handler(ctx, required, exempt_allowlisted, exempt_kyb)
     }
 
-    /// Set lockup for user
+    /// Lock `locked_amount` of the user's balance under `schedule`
     pub fn set_lockup(
         ctx: Context<SetLockup>,
         schedule: LockupSchedule,
+        locked_amount: u64,
     ) -> Result<()> {
-        instructions::set_lockup::handler(ctx, schedule)
+        instructions::set_lockup::handler(ctx, schedule, locked_amount)
     }
 
-    /// Set global volume caps
+    /// Set the mint's total and per-holder volume caps
     pub fn set_volume_caps(
         ctx: Context<SetVolumeCaps>,
         daily: u64,
         monthly: u64,
         max_tx: u64,
+        holder_daily: u64,
+        holder_monthly: u64,
     ) -> Result<()> {
-        instructions::set_volume_caps::handler(ctx, daily, monthly, max_tx)
+        instructions::set_volume_caps::handler(ctx, daily, monthly, max_tx, holder_daily, holder_monthly)
     }
 
-    /// Configure transfer window
+    /// Configure transfer window (local hours and weekdays, plus blackout periods)
     pub fn set_transfer_window(
         ctx: Context<SetTransferWindow>,
         allowed_hours: Vec<u8>,
         blocked_days: Vec<u8>,
+        utc_offset_minutes: i16,
+        blackouts: Vec<BlackoutPeriod>,
     ) -> Result<()> {
-        instructions::set_transfer_window::handler(ctx, allowed_hours, blocked_days)
+        instructions::set_transfer_window::handler(ctx, allowed_hours, blocked_days, utc_offset_minutes, blackouts)
     }
 
     /// Set program allowlist
@@ -84,4 +126,45 @@ pub mod compliance_modules {
     ) -> Result<()> {
         instructions::set_account_allowlist::handler(ctx, accounts)
     }
+
+    /// Allowlist an approved venue program and its vault accounts
+    pub fn allowlist_venue(
+        ctx: Context<AllowlistVenue>,
+        program: Pubkey,
+        accounts: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::allowlist_venue::handler(ctx, program, accounts)
+    }
+
+    /// Remove a venue's vault accounts from the mint's allowlist
+    pub fn delist_venue_accounts(
+        ctx: Context<DelistVenueAccounts>,
+        accounts: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::delist_venue_accounts::handler(ctx, accounts)
+    }
+
+    /// Create or update an investor's class and limits for a mint
+    pub fn set_investor_profile(
+        ctx: Context<SetInvestorProfile>,
+        investor_class: InvestorClass,
+        limits: InvestorLimits,
+    ) -> Result<()> {
+        instructions::set_investor_profile::handler(ctx, investor_class, limits)
+    }
+
+    /// Remove an investor's profile and its limits
+    pub fn close_investor_profile(ctx: Context<CloseInvestorProfile>) -> Result<()> {
+        instructions::close_investor_profile::handler(ctx)
+    }
+
+    /// Copy a module's ModuleConfig params into the config read by the hook
+    pub fn apply_module_params(ctx: Context<ApplyModuleParams>) -> Result<()> {
+        instructions::apply_module_params::handler(ctx)
+    }
+
+    /// Close the config the hook enforces for a module disabled in srwa_factory
+    pub fn close_module_config(ctx: Context<CloseModuleConfig>, module_id: u8) -> Result<()> {
+        instructions::close_module_config::handler(ctx, module_id)
+    }
 }
@@ -13,6 +13,24 @@ pub struct JurisdictionConfig {
     pub bump: u8,
 }
 
+impl JurisdictionConfig {
+    /// Reject users without a valid RESIDENCY claim
+    pub const FLAG_REQUIRE_CLAIM: u8 = 1 << 0;
+    /// Only check the receiving side, so holders can always exit
+    pub const FLAG_RECIPIENT_ONLY: u8 = 1 << 1;
+
+    pub fn has_flag(&self, flag: u8) -> bool {
+        self.flags & flag != 0
+    }
+
+    /// Deny list wins over allow list; an empty allow list admits every
+    /// country not denied
+    pub fn is_allowed(&self, country: u16) -> bool {
+        !self.deny_list.contains(&country)
+            && (self.allow_list.is_empty() || self.allow_list.contains(&country))
+    }
+}
+
 /// Sanctions list
 #[account]
 #[derive(InitSpace)]
@@ -23,12 +41,43 @@ pub struct SanctionsList {
     pub bump: u8,
 }
 
+/// Marker PDA flagging one sanctioned wallet, for a mint or for the whole
+/// platform. Existence is the check, so lookups from the hook are O(1).
+#[account]
+#[derive(InitSpace)]
+pub struct SanctionedAddress {
+    pub scope: Pubkey, // mint, or PLATFORM_SCOPE
+    pub address: Pubkey,
+    pub added_by: Pubkey,
+    pub added_at: i64,
+    pub bump: u8,
+}
+
+impl SanctionedAddress {
+    pub const SEED_PREFIX: &'static [u8] = b"sanctioned";
+    /// Scope of the platform-wide list, applied to every mint
+    pub const PLATFORM_SCOPE: Pubkey = Pubkey::new_from_array([0; 32]);
+}
+
+/// Holder caps of a mint (0 = uncapped); the live count is kept by the
+/// controller in `HolderCount`
+#[account]
+#[derive(InitSpace)]
+pub struct MaxHoldersConfig {
+    pub mint: Pubkey,
+    pub max_holders: u32,
+    pub max_non_accredited: u32,
+    pub bump: u8,
+}
+
 /// Accreditation requirement
 #[account]
 #[derive(InitSpace)]
 pub struct AccreditedConfig {
     pub mint: Pubkey,
     pub required: bool,
+    pub exempt_allowlisted: bool, // accounts in AccountAllowlist (custodial omnibus)
+    pub exempt_kyb: bool,         // institutions holding a trusted KYB claim
     pub bump: u8,
 }
 
@@ -39,9 +88,39 @@ pub struct LockupAccount {
     pub mint: Pubkey,
     pub user: Pubkey,
     pub schedule: LockupSchedule,
+    pub locked_amount: u64, // principal subject to the schedule
     pub bump: u8,
 }
 
+impl LockupAccount {
+    /// Part of the principal that has vested at `now`: nothing before the
+    /// cliff, then linear up to `end_ts` (or all at `end_ts` without linear
+    /// vesting)
+    pub fn unlocked_at(&self, now: i64) -> u64 {
+        let schedule = &self.schedule;
+        if now >= schedule.end_ts {
+            return self.locked_amount;
+        }
+        if !schedule.linear_vesting || now < schedule.cliff_ts {
+            return 0;
+        }
+
+        let elapsed = (now - schedule.cliff_ts) as u128;
+        let duration = (schedule.end_ts - schedule.cliff_ts) as u128;
+        (self.locked_amount as u128 * elapsed / duration) as u64
+    }
+
+    /// Part of the principal still locked at `now`
+    pub fn locked_at(&self, now: i64) -> u64 {
+        self.locked_amount - self.unlocked_at(now)
+    }
+
+    /// How much of `balance` may be transferred at `now`
+    pub fn transferable(&self, balance: u64, now: i64) -> u64 {
+        balance.saturating_sub(self.locked_at(now))
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct LockupSchedule {
     pub start_ts: i64,
@@ -50,6 +129,12 @@ pub struct LockupSchedule {
     pub linear_vesting: bool,
 }
 
+impl LockupSchedule {
+    pub fn is_valid(&self) -> bool {
+        self.start_ts <= self.cliff_ts && self.cliff_ts <= self.end_ts
+    }
+}
+
 /// Volume caps configuration
 #[account]
 #[derive(InitSpace)]
@@ -58,9 +143,17 @@ pub struct VolumeCapsConfig {
     pub daily_cap: u64,
     pub monthly_cap: u64,
     pub max_tx: u64,
+    pub holder_daily_cap: u64,
+    pub holder_monthly_cap: u64,
     pub bump: u8,
 }
 
+impl VolumeCapsConfig {
+    pub fn has_holder_caps(&self) -> bool {
+        self.holder_daily_cap > 0 || self.holder_monthly_cap > 0
+    }
+}
+
 /// Transfer window configuration
 #[account]
 #[derive(InitSpace)]
@@ -70,9 +163,44 @@ pub struct TransferWindowConfig {
     pub allowed_hours: Vec<u8>, // 0-23
     #[max_len(7)]
     pub blocked_days: Vec<u8>, // 0-6 (0=Sunday)
+    pub utc_offset_minutes: i16, // local time of the mint's market
+    #[max_len(32)]
+    pub blackouts: Vec<BlackoutPeriod>,
     pub bump: u8,
 }
 
+/// Holiday or corporate action period during which transfers are closed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct BlackoutPeriod {
+    pub start_ts: i64,
+    pub end_ts: i64, // exclusive
+}
+
+impl TransferWindowConfig {
+    pub const MAX_BLACKOUTS: usize = 32;
+    pub const MAX_UTC_OFFSET_MINUTES: i16 = 14 * 60;
+
+    /// Whether transfers are open at `now`. Hours and weekdays are taken in
+    /// the mint's local time; an empty `allowed_hours` allows every hour.
+    pub fn is_open(&self, now: i64) -> bool {
+        if self
+            .blackouts
+            .iter()
+            .any(|period| now >= period.start_ts && now < period.end_ts)
+        {
+            return false;
+        }
+
+        let local = now + self.utc_offset_minutes as i64 * 60;
+        let hour = (local.rem_euclid(86_400) / 3_600) as u8;
+        // 1970-01-01 was a Thursday
+        let weekday = (local.div_euclid(86_400) + 4).rem_euclid(7) as u8;
+
+        (self.allowed_hours.is_empty() || self.allowed_hours.contains(&hour))
+            && !self.blocked_days.contains(&weekday)
+    }
+}
+
 /// Program allowlist (DEX/lending programs)
 #[account]
 #[derive(InitSpace)]
@@ -83,6 +211,14 @@ pub struct ProgramAllowlist {
     pub bump: u8,
 }
 
+impl ProgramAllowlist {
+    pub const MAX_PROGRAMS: usize = 20;
+
+    pub fn contains(&self, program: &Pubkey) -> bool {
+        self.programs.contains(program)
+    }
+}
+
 /// Account allowlist (vaults/pools)
 #[account]
 #[derive(InitSpace)]
@@ -93,6 +229,14 @@ pub struct AccountAllowlist {
     pub bump: u8,
 }
 
+impl AccountAllowlist {
+    pub const MAX_ACCOUNTS: usize = 50;
+
+    pub fn contains(&self, account: &Pubkey) -> bool {
+        self.accounts.contains(account)
+    }
+}
+
 /// Investor profile with limits
 #[account]
 #[derive(InitSpace)]
@@ -105,6 +249,10 @@ pub struct InvestorProfile {
     pub bump: u8,
 }
 
+impl InvestorProfile {
+    pub const SEED_PREFIX: &'static [u8] = b"investor_profile";
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum InvestorClass {
     Senior,
@@ -116,7 +264,7 @@ pub enum InvestorClass {
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct InvestorLimits {
-    pub daily_volume: u64,
-    pub position_cap: u64,
-    pub concentration_bps: u16,
+    pub daily_volume: u64,      // 0 = no limit
+    pub position_cap: u64,      // 0 = no limit
+    pub concentration_bps: u16, // share of supply, 0 = no limit
 }
@@ -27,6 +27,19 @@ pub struct ClaimAccount {
     pub bump: u8,
 }
 
+impl ClaimAccount {
+    /// Not revoked and not past `valid_until`
+    pub fn is_valid(&self, now: i64) -> bool {
+        !self.revoked && now < self.valid_until
+    }
+
+    /// ISO 3166-1 numeric country code of a RESIDENCY claim, stored
+    /// little-endian in the first two bytes of `data_hash`
+    pub fn country_code(&self) -> u16 {
+        u16::from_le_bytes([self.data_hash[0], self.data_hash[1]])
+    }
+}
+
 /// Claim topics constants
 pub mod topics {
     pub const KYC: u32 = 1;
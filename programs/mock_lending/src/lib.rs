@@ -0,0 +1,257 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, Burn, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
+};
+
+declare_id!("9roAkrxuxsE8iXeHEy8xJ6cedGzKBttBkbYmAb4oKh9p");
+
+/// Generic lending interface reserve used by the test suite: depositors receive
+/// collateral tokens whose value grows as interest is paid into the liquidity
+/// vault.
+#[program]
+pub mod mock_lending {
+    use super::*;
+
+    pub fn init_reserve(ctx: Context<InitReserve>) -> Result<()> {
+        let reserve = &mut ctx.accounts.reserve;
+        reserve.liquidity_mint = ctx.accounts.liquidity_mint.key();
+        reserve.liquidity_vault = ctx.accounts.liquidity_vault.key();
+        reserve.collateral_mint = ctx.accounts.collateral_mint.key();
+        reserve.bump = ctx.bumps.reserve;
+
+        msg!("Reserve initialized for {}", reserve.liquidity_mint);
+        Ok(())
+    }
+
+    pub fn deposit_reserve_liquidity(ctx: Context<ReserveLiquidity>, amount: u64) -> Result<()> {
+        require!(amount > 0, LendingError::InvalidAmount);
+
+        let collateral_amount = liquidity_to_collateral(
+            amount,
+            ctx.accounts.liquidity_vault.amount,
+            ctx.accounts.collateral_mint.supply,
+        )?;
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.user_liquidity.to_account_info(),
+                    mint: ctx.accounts.liquidity_mint.to_account_info(),
+                    to: ctx.accounts.liquidity_vault.to_account_info(),
+                    authority: ctx.accounts.user_authority.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.liquidity_mint.decimals,
+        )?;
+
+        let liquidity_mint = ctx.accounts.liquidity_mint.key();
+        let seeds: &[&[u8]] = &[b"reserve", liquidity_mint.as_ref(), &[ctx.accounts.reserve.bump]];
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.collateral_mint.to_account_info(),
+                    to: ctx.accounts.user_collateral.to_account_info(),
+                    authority: ctx.accounts.reserve.to_account_info(),
+                },
+                &[seeds],
+            ),
+            collateral_amount,
+        )?;
+
+        msg!("Deposited {} liquidity for {} collateral", amount, collateral_amount);
+        Ok(())
+    }
+
+    pub fn redeem_reserve_collateral(
+        ctx: Context<ReserveLiquidity>,
+        collateral_amount: u64,
+    ) -> Result<()> {
+        require!(collateral_amount > 0, LendingError::InvalidAmount);
+
+        let amount = collateral_to_liquidity(
+            collateral_amount,
+            ctx.accounts.liquidity_vault.amount,
+            ctx.accounts.collateral_mint.supply,
+        )?;
+
+        token_interface::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.collateral_mint.to_account_info(),
+                    from: ctx.accounts.user_collateral.to_account_info(),
+                    authority: ctx.accounts.user_authority.to_account_info(),
+                },
+            ),
+            collateral_amount,
+        )?;
+
+        let liquidity_mint = ctx.accounts.liquidity_mint.key();
+        let seeds: &[&[u8]] = &[b"reserve", liquidity_mint.as_ref(), &[ctx.accounts.reserve.bump]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.liquidity_vault.to_account_info(),
+                    mint: ctx.accounts.liquidity_mint.to_account_info(),
+                    to: ctx.accounts.user_liquidity.to_account_info(),
+                    authority: ctx.accounts.reserve.to_account_info(),
+                },
+                &[seeds],
+            ),
+            amount,
+            ctx.accounts.liquidity_mint.decimals,
+        )?;
+
+        msg!("Redeemed {} collateral for {} liquidity", collateral_amount, amount);
+        Ok(())
+    }
+
+    /// Simulate interest by paying liquidity into the vault without minting collateral
+    pub fn accrue_interest(ctx: Context<AccrueInterest>, amount: u64) -> Result<()> {
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.payer_liquidity.to_account_info(),
+                    mint: ctx.accounts.liquidity_mint.to_account_info(),
+                    to: ctx.accounts.liquidity_vault.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.liquidity_mint.decimals,
+        )?;
+
+        msg!("Accrued {} interest", amount);
+        Ok(())
+    }
+}
+
+fn liquidity_to_collateral(amount: u64, total_liquidity: u64, collateral_supply: u64) -> Result<u64> {
+    if collateral_supply == 0 || total_liquidity == 0 {
+        return Ok(amount);
+    }
+    let collateral = (amount as u128) * (collateral_supply as u128) / (total_liquidity as u128);
+    u64::try_from(collateral).map_err(|_| error!(LendingError::MathOverflow))
+}
+
+fn collateral_to_liquidity(collateral: u64, total_liquidity: u64, collateral_supply: u64) -> Result<u64> {
+    require!(collateral_supply > 0, LendingError::InvalidAmount);
+    let amount = (collateral as u128) * (total_liquidity as u128) / (collateral_supply as u128);
+    u64::try_from(amount).map_err(|_| error!(LendingError::MathOverflow))
+}
+
+#[derive(Accounts)]
+pub struct InitReserve<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Reserve::INIT_SPACE,
+        seeds = [b"reserve", liquidity_mint.key().as_ref()],
+        bump
+    )]
+    pub reserve: Account<'info, Reserve>,
+
+    pub liquidity_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"liquidity_vault", reserve.key().as_ref()],
+        bump,
+        token::mint = liquidity_mint,
+        token::authority = reserve,
+        token::token_program = token_program,
+    )]
+    pub liquidity_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"collateral_mint", reserve.key().as_ref()],
+        bump,
+        mint::decimals = liquidity_mint.decimals,
+        mint::authority = reserve,
+        mint::token_program = token_program,
+    )]
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Account order shared by deposit and redeem (the generic lending interface)
+#[derive(Accounts)]
+pub struct ReserveLiquidity<'info> {
+    #[account(
+        seeds = [b"reserve", liquidity_mint.key().as_ref()],
+        bump = reserve.bump,
+        has_one = liquidity_vault,
+        has_one = collateral_mint,
+    )]
+    pub reserve: Account<'info, Reserve>,
+
+    #[account(mut)]
+    pub liquidity_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, token::mint = liquidity_mint)]
+    pub user_liquidity: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, token::mint = collateral_mint)]
+    pub user_collateral: InterfaceAccount<'info, TokenAccount>,
+
+    pub liquidity_mint: InterfaceAccount<'info, Mint>,
+
+    pub user_authority: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct AccrueInterest<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"reserve", liquidity_mint.key().as_ref()],
+        bump = reserve.bump,
+        has_one = liquidity_vault,
+    )]
+    pub reserve: Account<'info, Reserve>,
+
+    #[account(mut)]
+    pub liquidity_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, token::mint = liquidity_mint)]
+    pub payer_liquidity: InterfaceAccount<'info, TokenAccount>,
+
+    pub liquidity_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Reserve {
+    pub liquidity_mint: Pubkey,
+    pub liquidity_vault: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub bump: u8,
+}
+
+#[error_code]
+pub enum LendingError {
+    #[msg("Invalid amount")]
+    InvalidAmount,
+    #[msg("Math overflow")]
+    MathOverflow,
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use identity_claims::state::topics;
 
 declare_id!("4D54H4NBA9Q7WtsAy2yaFs9BjEdT8DcdmXekKsf7n6KP");
 
@@ -14,6 +15,23 @@ pub mod offering_pool {
     }
 
     pub fn subscribe(ctx: Context<Subscribe>, amount: u64) -> Result<()> {
+        compliance_modules::checks::check_jurisdiction(
+            &ctx.accounts.jurisdiction_config,
+            &ctx.accounts.residency_claim,
+            &ctx.accounts.mint.key(),
+            &ctx.accounts.user.key(),
+            Clock::get()?.unix_timestamp,
+        )?;
+        compliance_modules::checks::check_accreditation(
+            &ctx.accounts.accredited_config,
+            &ctx.accounts.srwa_config,
+            &ctx.accounts.accredited_claim,
+            &ctx.accounts.kyb_claim,
+            &ctx.accounts.mint.key(),
+            &ctx.accounts.user.key(),
+            Clock::get()?.unix_timestamp,
+        )?;
+
         let subscription = &mut ctx.accounts.subscription;
         subscription.user = ctx.accounts.user.key();
         subscription.mint = ctx.accounts.mint.key();
@@ -69,6 +87,48 @@ pub struct Subscribe<'info> {
         bump
     )]
     pub subscription: Account<'info, Subscription>,
+    /// CHECK: Jurisdiction config of the mint, may not exist
+    #[account(
+        seeds = [b"jurisdiction", mint.key().as_ref()],
+        bump,
+        seeds::program = compliance_modules::ID
+    )]
+    pub jurisdiction_config: UncheckedAccount<'info>,
+    /// CHECK: RESIDENCY claim of the user, may not exist
+    #[account(
+        seeds = [b"claim", user.key().as_ref(), &topics::RESIDENCY.to_le_bytes()],
+        bump,
+        seeds::program = identity_claims::ID
+    )]
+    pub residency_claim: UncheckedAccount<'info>,
+    /// CHECK: Accreditation requirement of the mint, may not exist
+    #[account(
+        seeds = [b"accredited", mint.key().as_ref()],
+        bump,
+        seeds::program = compliance_modules::ID
+    )]
+    pub accredited_config: UncheckedAccount<'info>,
+    /// CHECK: SRWA config of the mint (trusted issuers), may not exist
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump,
+        seeds::program = srwa_factory::ID
+    )]
+    pub srwa_config: UncheckedAccount<'info>,
+    /// CHECK: ACCREDITED claim of the user, may not exist
+    #[account(
+        seeds = [b"claim", user.key().as_ref(), &topics::ACCREDITED.to_le_bytes()],
+        bump,
+        seeds::program = identity_claims::ID
+    )]
+    pub accredited_claim: UncheckedAccount<'info>,
+    /// CHECK: KYB claim of the user, may not exist
+    #[account(
+        seeds = [b"claim", user.key().as_ref(), &topics::KYB.to_le_bytes()],
+        bump,
+        seeds::program = identity_claims::ID
+    )]
+    pub kyb_claim: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
@@ -20,8 +20,8 @@ pub enum PurchaseOrderError {
     #[msg("Apenas admin pode aprovar/rejeitar")]
     UnauthorizedAdmin,
 
-    #[msg("Saldo insuficiente de tokens do admin")]
-    InsufficientAdminTokens,
+    #[msg("Saldo insuficiente no inventário do issuer")]
+    InsufficientInventory,
 
     #[msg("Motivo de rejeição muito longo (max 200 chars)")]
     RejectReasonTooLong,
@@ -29,6 +29,48 @@ pub enum PurchaseOrderError {
     #[msg("Overflow no cálculo do total")]
     MathOverflow,
 
-    #[msg("Admin vault precisa ser a mesma conta que recebeu o SOL")]
-    AdminVaultMismatch,
+    #[msg("Treasury não corresponde ao issuer treasury da oferta")]
+    TreasuryMismatch,
+
+    #[msg("Saldo do escrow insuficiente")]
+    InsufficientEscrow,
+
+    #[msg("Quote mint não corresponde à oferta ou à order")]
+    QuoteMintMismatch,
+
+    #[msg("Preço não corresponde ao pricing da oferta")]
+    PriceMismatch,
+
+    #[msg("Contas do quote mint ausentes para order em SPL")]
+    MissingQuoteAccounts,
+
+    #[msg("Investor sem claim KYC válido de um trusted issuer")]
+    InvestorNotVerified,
+
+    #[msg("Purchase order expirada")]
+    OrderExpired,
+
+    #[msg("Purchase order ainda não expirou")]
+    OrderNotExpired,
+
+    #[msg("Expiração inválida")]
+    InvalidExpiry,
+
+    #[msg("Quantidade de preenchimento inválida")]
+    InvalidFillQuantity,
+
+    #[msg("NAV acima do preço máximo da order")]
+    PriceAboveLimit,
+
+    #[msg("Fonte de preço (NAV) ausente")]
+    MissingPriceSource,
+
+    #[msg("NAV desatualizado")]
+    StaleNav,
+
+    #[msg("Saldo insuficiente no liquidity vault")]
+    InsufficientLiquidity,
+
+    #[msg("Programa de ordens fora da allowlist de venues do mint")]
+    VenueNotAllowlisted,
 }
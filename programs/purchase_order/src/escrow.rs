@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::onchain::invoke_transfer_checked,
+    token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use crate::state::*;
+use crate::errors::*;
+
+/// Escrow SPL de uma order paga em quote mint
+pub struct QuoteEscrow<'a, 'info> {
+    pub escrow: &'a Account<'info, OrderEscrow>,
+    pub quote_mint: &'a InterfaceAccount<'info, Mint>,
+    pub escrow_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'a, 'info> QuoteEscrow<'a, 'info> {
+    /// Monta o escrow a partir das contas opcionais da instrução
+    pub fn load(
+        escrow: &'a Account<'info, OrderEscrow>,
+        quote_mint: Option<&'a InterfaceAccount<'info, Mint>>,
+        escrow_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+        token_program: Option<&'a Interface<'info, TokenInterface>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            escrow,
+            quote_mint: quote_mint.ok_or(PurchaseOrderError::MissingQuoteAccounts)?,
+            escrow_token_account: escrow_token_account.ok_or(PurchaseOrderError::MissingQuoteAccounts)?,
+            token_program: token_program.ok_or(PurchaseOrderError::MissingQuoteAccounts)?,
+        })
+    }
+
+    pub fn balance(&self) -> u64 {
+        self.escrow_token_account.amount
+    }
+
+    /// Transfere `amount` do escrow para `to`
+    pub fn transfer(&self, to: AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.escrow_token_account.to_account_info(),
+                    mint: self.quote_mint.to_account_info(),
+                    to,
+                    authority: self.escrow.to_account_info(),
+                },
+                &[&self.signer_seeds()],
+            ),
+            amount,
+            self.quote_mint.decimals,
+        )
+    }
+
+    /// Fecha a conta (já vazia), devolvendo o rent para `rent_receiver`
+    pub fn close(&self, rent_receiver: AccountInfo<'info>) -> Result<()> {
+        token_interface::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.escrow_token_account.to_account_info(),
+                destination: rent_receiver,
+                authority: self.escrow.to_account_info(),
+            },
+            &[&self.signer_seeds()],
+        ))
+    }
+
+    /// Devolve todo o saldo para o investor e fecha a conta; retorna o
+    /// valor reembolsado
+    pub fn refund_and_close(
+        &self,
+        investor_quote_account: AccountInfo<'info>,
+        rent_receiver: AccountInfo<'info>,
+    ) -> Result<u64> {
+        let refunded = self.balance();
+        self.transfer(investor_quote_account, refunded)?;
+        self.close(rent_receiver)?;
+        Ok(refunded)
+    }
+
+    fn signer_seeds(&self) -> [&[u8]; 3] {
+        [
+            OrderEscrow::SEED_PREFIX,
+            self.escrow.purchase_order.as_ref(),
+            std::slice::from_ref(&self.escrow.bump),
+        ]
+    }
+}
+
+/// Libera os tokens SRWA em escrow de uma sell order para `to` (passa pelo
+/// transfer hook, contas extras em `remaining_accounts`) e fecha o escrow
+pub fn release_sell_escrow<'info>(
+    sell_order: &Account<'info, SellOrder>,
+    mint: &InterfaceAccount<'info, Mint>,
+    escrow: &InterfaceAccount<'info, TokenAccount>,
+    to: AccountInfo<'info>,
+    rent_receiver: AccountInfo<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let created_at = sell_order.created_at.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SellOrder::SEED_PREFIX,
+        sell_order.mint.as_ref(),
+        sell_order.holder.as_ref(),
+        &created_at,
+        &[sell_order.bump],
+    ]];
+
+    invoke_transfer_checked(
+        token_program.key,
+        escrow.to_account_info(),
+        mint.to_account_info(),
+        to,
+        sell_order.to_account_info(),
+        remaining_accounts,
+        escrow.amount,
+        mint.decimals,
+        signer_seeds,
+    )?;
+
+    token_interface::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: escrow.to_account_info(),
+            destination: rent_receiver,
+            authority: sell_order.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
@@ -1,9 +1,18 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{self, TokenAccount, TokenInterface, TransferChecked};
+use anchor_spl::{
+    token_2022::spl_token_2022::onchain::invoke_transfer_checked,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use compliance_modules::checks::has_trusted_claim;
+use identity_claims::state::topics;
+use srwa_factory::state::{PlatformAdminRegistry, SRWAConfig};
+use valuation_oracle::ValuationData;
+use crate::escrow::QuoteEscrow;
 use crate::state::*;
 use crate::errors::*;
 
 #[derive(Accounts)]
+#[instruction(fill_quantity: u64)]
 pub struct ApprovePurchaseOrder<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
@@ -22,16 +31,55 @@ pub struct ApprovePurchaseOrder<'info> {
     )]
     pub purchase_order: Account<'info, PurchaseOrder>,
 
+    /// Configuração do SRWA, define os papéis autorizados
+    #[account(
+        seeds = [b"srwa_config", purchase_order.mint.as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        constraint = is_order_admin(&admin.key(), &srwa_config, admin_registry.as_deref()) @ PurchaseOrderError::UnauthorizedAdmin
+    )]
+    pub srwa_config: Box<Account<'info, SRWAConfig>>,
+
+    /// Obrigatório quando o admin é um admin da plataforma
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub admin_registry: Option<Account<'info, PlatformAdminRegistry>>,
+
+    /// CHECK: Claim KYC do investor, precisa ser de um trusted issuer do mint;
+    /// verificado no handler antes da entrega
+    #[account(
+        seeds = [b"claim", purchase_order.investor.as_ref(), &topics::KYC.to_le_bytes()],
+        bump,
+        seeds::program = identity_claims::ID
+    )]
+    pub investor_kyc_claim: UncheckedAccount<'info>,
+
     /// Mint do token
-    pub mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+    #[account(
+        address = purchase_order.mint,
+        mint::token_program = token_program
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
 
-    /// Token account do admin (origem dos tokens)
+    /// Inventário do issuer, authority do vault de entrega
     #[account(
         mut,
-        constraint = admin_token_account.mint == purchase_order.mint,
-        constraint = admin_token_account.amount >= purchase_order.quantity @ PurchaseOrderError::InsufficientAdminTokens
+        seeds = [IssuerInventory::SEED_PREFIX, purchase_order.mint.as_ref()],
+        bump = inventory.bump,
+        has_one = mint
     )]
-    pub admin_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub inventory: Account<'info, IssuerInventory>,
+
+    /// Vault do inventário (origem dos tokens)
+    #[account(
+        mut,
+        address = inventory.vault,
+        constraint = inventory_vault.amount >= fill_quantity @ PurchaseOrderError::InsufficientInventory
+    )]
+    pub inventory_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// Token account do investor (destino dos tokens)
     #[account(
@@ -39,42 +87,214 @@ pub struct ApprovePurchaseOrder<'info> {
         constraint = investor_token_account.mint == purchase_order.mint,
         constraint = investor_token_account.owner == purchase_order.investor
     )]
-    pub investor_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub investor_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Escrow da order, fechado para o investor após liberar o pagamento
+    #[account(
+        mut,
+        seeds = [OrderEscrow::SEED_PREFIX, purchase_order.key().as_ref()],
+        bump = escrow.bump,
+        close = investor
+    )]
+    pub escrow: Account<'info, OrderEscrow>,
+
+    /// CHECK: Oferta do SRWA (pode não existir); quando inicializada define
+    /// o issuer treasury, senão o pagamento vai para o issuer admin
+    #[account(
+        seeds = [b"offering", purchase_order.mint.as_ref()],
+        bump,
+        seeds::program = srwa_factory::ID
+    )]
+    pub offering: UncheckedAccount<'info>,
+
+    /// Issuer treasury que recebe o pagamento do escrow, verificado no handler
+    #[account(mut)]
+    pub issuer_treasury: SystemAccount<'info>,
+
+    /// Investor que recebe o rent do escrow
+    #[account(
+        mut,
+        constraint = investor.key() == purchase_order.investor
+    )]
+    pub investor: SystemAccount<'info>,
+
+    /// Quote mint da order (apenas orders pagas em SPL)
+    #[account(address = purchase_order.quote_mint.unwrap_or_default() @ PurchaseOrderError::QuoteMintMismatch)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Escrow SPL da order
+    #[account(
+        mut,
+        seeds = [OrderEscrow::TOKEN_SEED_PREFIX, purchase_order.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Conta do issuer treasury no quote mint
+    #[account(
+        mut,
+        constraint = treasury_quote_account.mint == purchase_order.quote_mint.unwrap_or_default() @ PurchaseOrderError::QuoteMintMismatch,
+        constraint = treasury_quote_account.owner == issuer_treasury.key() @ PurchaseOrderError::TreasuryMismatch
+    )]
+    pub treasury_quote_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Conta do investor no quote mint, recebe o reembolso do que não foi preenchido
+    #[account(
+        mut,
+        constraint = investor_quote_account.mint == purchase_order.quote_mint.unwrap_or_default() @ PurchaseOrderError::QuoteMintMismatch,
+        constraint = investor_quote_account.owner == purchase_order.investor
+    )]
+    pub investor_quote_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// NAV publicado, obrigatório para orders precificadas pelo NAV
+    #[account(
+        seeds = [b"valuation", purchase_order.mint.as_ref()],
+        bump,
+        seeds::program = valuation_oracle::ID,
+    )]
+    pub valuation_data: Option<Account<'info, ValuationData>>,
 
     pub token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
 }
 
-pub fn handler(ctx: Context<ApprovePurchaseOrder>) -> Result<()> {
-    let purchase_order = &mut ctx.accounts.purchase_order;
+/// Entrega e liberação do pagamento acontecem na mesma instrução; o que
+/// não for preenchido volta ao investor. As contas extras do transfer hook
+/// vão em remaining_accounts.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ApprovePurchaseOrder<'info>>,
+    fill_quantity: u64,
+) -> Result<()> {
     let clock = Clock::get()?;
+    let order = &ctx.accounts.purchase_order;
+    require!(
+        fill_quantity > 0 && fill_quantity <= order.quantity,
+        PurchaseOrderError::InvalidFillQuantity
+    );
+    require!(
+        !order.is_expired(clock.unix_timestamp),
+        PurchaseOrderError::OrderExpired
+    );
+    require!(
+        has_trusted_claim(
+            &ctx.accounts.investor_kyc_claim,
+            &order.investor,
+            topics::KYC,
+            &ctx.accounts.srwa_config.trusted_issuers_data,
+            clock.unix_timestamp,
+        )?,
+        PurchaseOrderError::InvestorNotVerified
+    );
+
+    // Preço fixo da order ou NAV atual, limitado pelo preço máximo do investor
+    let price_per_token = match order.max_price_per_token {
+        Some(max_price) => {
+            let valuation = ctx
+                .accounts
+                .valuation_data
+                .as_ref()
+                .ok_or(PurchaseOrderError::MissingPriceSource)?;
+            let heartbeat = ctx.accounts.srwa_config.oracle_cfg.heartbeat as i64;
+            require!(
+                clock.unix_timestamp - valuation.last_update <= heartbeat,
+                PurchaseOrderError::StaleNav
+            );
+            require!(valuation.nav_per_token > 0, PurchaseOrderError::InvalidPrice);
+            require!(
+                valuation.nav_per_token <= max_price,
+                PurchaseOrderError::PriceAboveLimit
+            );
+            valuation.nav_per_token
+        }
+        None => order.price_per_token_lamports,
+    };
+    let cost = order.cost_for(fill_quantity, price_per_token, ctx.accounts.mint.decimals)?;
+    require!(cost <= order.total_lamports, PurchaseOrderError::InsufficientEscrow);
 
-    // Transferir tokens do admin para o investor
-    let decimals = ctx.accounts.mint.decimals;
-
-    let transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        TransferChecked {
-            from: ctx.accounts.admin_token_account.to_account_info(),
-            to: ctx.accounts.investor_token_account.to_account_info(),
-            authority: ctx.accounts.admin.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-        },
+    // Treasury da oferta, ou o issuer admin para mints sem oferta
+    let treasury = match load_offering(&ctx.accounts.offering)? {
+        Some(offering) => offering.settlement.issuer_treasury,
+        None => ctx.accounts.srwa_config.roles.issuer_admin,
+    };
+    require_keys_eq!(
+        ctx.accounts.issuer_treasury.key(),
+        treasury,
+        PurchaseOrderError::TreasuryMismatch
     );
 
-    token_interface::transfer_checked(transfer_ctx, purchase_order.quantity, decimals)?;
+    // Entregar os tokens do inventário para o investor (passa pelo transfer hook)
+    let mint_key = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        IssuerInventory::SEED_PREFIX,
+        mint_key.as_ref(),
+        &[ctx.accounts.inventory.bump],
+    ]];
+    invoke_transfer_checked(
+        ctx.accounts.token_program.key,
+        ctx.accounts.inventory_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.investor_token_account.to_account_info(),
+        ctx.accounts.inventory.to_account_info(),
+        ctx.remaining_accounts,
+        fill_quantity,
+        ctx.accounts.mint.decimals,
+        signer_seeds,
+    )?;
+
+    if ctx.accounts.purchase_order.quote_mint.is_some() {
+        // Orders em SPL: pagamento para o treasury, restante de volta ao investor
+        let accounts = &ctx.accounts;
+        let quote = QuoteEscrow::load(
+            &accounts.escrow,
+            accounts.quote_mint.as_deref(),
+            accounts.escrow_token_account.as_deref(),
+            accounts.quote_token_program.as_ref(),
+        )?;
+        let balance = quote.balance();
+        require!(balance >= cost, PurchaseOrderError::InsufficientEscrow);
+        quote.transfer(
+            accounts.treasury_quote_account.as_deref().ok_or(PurchaseOrderError::MissingQuoteAccounts)?.to_account_info(),
+            cost,
+        )?;
+        if balance > cost {
+            quote.transfer(
+                accounts.investor_quote_account.as_deref().ok_or(PurchaseOrderError::MissingQuoteAccounts)?.to_account_info(),
+                balance - cost,
+            )?;
+        }
+        quote.close(accounts.investor.to_account_info())?;
+    } else {
+        // Orders em SOL: pagamento para o treasury, o restante volta ao
+        // investor quando o escrow é fechado
+        let escrow_info = ctx.accounts.escrow.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(escrow_info.data_len());
+        let available = escrow_info.lamports().saturating_sub(rent_exempt);
+        require!(available >= cost, PurchaseOrderError::InsufficientEscrow);
+        **escrow_info.try_borrow_mut_lamports()? -= cost;
+        **ctx.accounts.issuer_treasury.to_account_info().try_borrow_mut_lamports()? += cost;
+    }
+
+    let inventory = &mut ctx.accounts.inventory;
+    inventory.delivered = inventory
+        .delivered
+        .checked_add(fill_quantity)
+        .ok_or(PurchaseOrderError::MathOverflow)?;
 
     // Atualizar purchase order
+    let purchase_order = &mut ctx.accounts.purchase_order;
+    purchase_order.filled_quantity = fill_quantity;
     purchase_order.status = PurchaseOrderStatus::Approved;
     purchase_order.updated_at = clock.unix_timestamp;
     purchase_order.processed_by = Some(ctx.accounts.admin.key());
 
-    // Armazenar a signature da transação (será definido pelo cliente)
-    // purchase_order.approval_tx será preenchido no frontend após a confirmação
-
     msg!(
-        "Purchase order aprovada: {} tokens transferidos para {}",
+        "Purchase order aprovada: {}/{} tokens entregues para {} a {} por token, pagamento de {} liberado para {}",
+        fill_quantity,
         purchase_order.quantity,
-        purchase_order.investor
+        purchase_order.investor,
+        price_per_token,
+        cost,
+        ctx.accounts.issuer_treasury.key()
     );
 
     Ok(())
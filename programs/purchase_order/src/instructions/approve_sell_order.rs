@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use srwa_factory::state::{PlatformAdminRegistry, SRWAConfig};
+use crate::escrow::release_sell_escrow;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct ApproveSellOrder<'info> {
+    pub admin: Signer<'info>,
+
+    /// Sell order sendo aprovada
+    #[account(
+        mut,
+        seeds = [
+            SellOrder::SEED_PREFIX,
+            sell_order.mint.as_ref(),
+            sell_order.holder.as_ref(),
+            &sell_order.created_at.to_le_bytes()
+        ],
+        bump = sell_order.bump,
+        constraint = sell_order.status == PurchaseOrderStatus::Pending @ PurchaseOrderError::NotPending
+    )]
+    pub sell_order: Account<'info, SellOrder>,
+
+    /// Configuração do SRWA, define os papéis autorizados
+    #[account(
+        seeds = [b"srwa_config", sell_order.mint.as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        constraint = is_order_admin(&admin.key(), &srwa_config, admin_registry.as_deref()) @ PurchaseOrderError::UnauthorizedAdmin
+    )]
+    pub srwa_config: Box<Account<'info, SRWAConfig>>,
+
+    /// Obrigatório quando o admin é um admin da plataforma
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub admin_registry: Option<Account<'info, PlatformAdminRegistry>>,
+
+    /// Mint do token
+    #[account(
+        address = sell_order.mint,
+        mint::token_program = token_program
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Escrow dos tokens SRWA
+    #[account(
+        mut,
+        seeds = [SellOrder::ESCROW_SEED_PREFIX, sell_order.key().as_ref()],
+        bump
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Inventário do issuer, recebe os tokens recomprados
+    #[account(
+        seeds = [IssuerInventory::SEED_PREFIX, sell_order.mint.as_ref()],
+        bump = inventory.bump,
+        has_one = mint
+    )]
+    pub inventory: Account<'info, IssuerInventory>,
+
+    #[account(mut, address = inventory.vault)]
+    pub inventory_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Liquidity vault que paga a order
+    #[account(
+        mut,
+        seeds = [LiquidityVault::SEED_PREFIX, sell_order.mint.as_ref(), sell_order.quote_mint.as_ref()],
+        bump = liquidity_vault.bump
+    )]
+    pub liquidity_vault: Account<'info, LiquidityVault>,
+
+    #[account(
+        mut,
+        address = liquidity_vault.vault,
+        constraint = vault_token_account.amount >= sell_order.total_payout @ PurchaseOrderError::InsufficientLiquidity
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = sell_order.quote_mint @ PurchaseOrderError::QuoteMintMismatch)]
+    pub quote_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Conta do holder no quote mint (destino do pagamento)
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        constraint = holder_quote_account.owner == sell_order.holder
+    )]
+    pub holder_quote_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Holder que recebe o rent do escrow
+    #[account(
+        mut,
+        constraint = holder.key() == sell_order.holder
+    )]
+    pub holder: SystemAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Recompra e pagamento acontecem na mesma instrução.
+/// As contas extras do transfer hook vão em remaining_accounts.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ApproveSellOrder<'info>>) -> Result<()> {
+    // Tokens do escrow para o inventário do issuer
+    release_sell_escrow(
+        &ctx.accounts.sell_order,
+        &ctx.accounts.mint,
+        &ctx.accounts.escrow,
+        ctx.accounts.inventory_vault.to_account_info(),
+        ctx.accounts.holder.to_account_info(),
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+    )?;
+
+    // Pagamento do liquidity vault para o holder
+    let total_payout = ctx.accounts.sell_order.total_payout;
+    let liquidity_vault = &ctx.accounts.liquidity_vault;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        LiquidityVault::SEED_PREFIX,
+        liquidity_vault.mint.as_ref(),
+        liquidity_vault.quote_mint.as_ref(),
+        &[liquidity_vault.bump],
+    ]];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.quote_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                mint: ctx.accounts.quote_mint.to_account_info(),
+                to: ctx.accounts.holder_quote_account.to_account_info(),
+                authority: liquidity_vault.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        total_payout,
+        ctx.accounts.quote_mint.decimals,
+    )?;
+
+    let liquidity_vault = &mut ctx.accounts.liquidity_vault;
+    liquidity_vault.paid_out = liquidity_vault
+        .paid_out
+        .checked_add(total_payout)
+        .ok_or(PurchaseOrderError::MathOverflow)?;
+
+    // Atualizar sell order
+    let clock = Clock::get()?;
+    let sell_order = &mut ctx.accounts.sell_order;
+    sell_order.status = PurchaseOrderStatus::Approved;
+    sell_order.updated_at = clock.unix_timestamp;
+    sell_order.processed_by = Some(ctx.accounts.admin.key());
+
+    msg!(
+        "Sell order aprovada: {} tokens recomprados de {}, {} pagos",
+        sell_order.quantity,
+        sell_order.holder,
+        total_payout
+    );
+
+    Ok(())
+}
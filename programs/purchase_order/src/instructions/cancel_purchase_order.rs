@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::escrow::QuoteEscrow;
 use crate::state::*;
 use crate::errors::*;
 
@@ -22,29 +24,69 @@ pub struct CancelPurchaseOrder<'info> {
     )]
     pub purchase_order: Account<'info, PurchaseOrder>,
 
-    /// Vault do admin que retornará o SOL
-    /// CHECK: Deve ser a mesma conta que recebeu o SOL originalmente
-    #[account(mut)]
-    pub admin_vault: UncheckedAccount<'info>,
+    /// Escrow da order, fechado para o investor (reembolso + rent)
+    #[account(
+        mut,
+        seeds = [OrderEscrow::SEED_PREFIX, purchase_order.key().as_ref()],
+        bump = escrow.bump,
+        close = investor
+    )]
+    pub escrow: Account<'info, OrderEscrow>,
 
-    pub system_program: Program<'info, System>,
+    /// Quote mint da order (apenas orders pagas em SPL)
+    #[account(address = purchase_order.quote_mint.unwrap_or_default() @ PurchaseOrderError::QuoteMintMismatch)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Escrow SPL da order
+    #[account(
+        mut,
+        seeds = [OrderEscrow::TOKEN_SEED_PREFIX, purchase_order.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Conta do investor no quote mint que recebe o reembolso
+    #[account(
+        mut,
+        constraint = investor_quote_account.mint == purchase_order.quote_mint.unwrap_or_default() @ PurchaseOrderError::QuoteMintMismatch,
+        constraint = investor_quote_account.owner == purchase_order.investor
+    )]
+    pub investor_quote_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 pub fn handler(ctx: Context<CancelPurchaseOrder>) -> Result<()> {
+    // Orders em SPL: reembolso no mesmo quote mint
+    let refunded = if ctx.accounts.purchase_order.quote_mint.is_some() {
+        let accounts = &ctx.accounts;
+        QuoteEscrow::load(
+            &accounts.escrow,
+            accounts.quote_mint.as_deref(),
+            accounts.escrow_token_account.as_deref(),
+            accounts.quote_token_program.as_ref(),
+        )?
+        .refund_and_close(
+            accounts.investor_quote_account.as_deref().ok_or(PurchaseOrderError::MissingQuoteAccounts)?.to_account_info(),
+            accounts.investor.to_account_info(),
+        )?
+    } else {
+        // O SOL acima do rent volta ao investor ao fechar o escrow
+        let escrow_info = ctx.accounts.escrow.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(escrow_info.data_len());
+        escrow_info.lamports().saturating_sub(rent_exempt)
+    };
+
     let purchase_order = &mut ctx.accounts.purchase_order;
     let clock = Clock::get()?;
 
-    // Reembolsar SOL do admin vault para o investor
-    **ctx.accounts.admin_vault.try_borrow_mut_lamports()? -= purchase_order.total_lamports;
-    **ctx.accounts.investor.try_borrow_mut_lamports()? += purchase_order.total_lamports;
-
     // Atualizar purchase order
     purchase_order.status = PurchaseOrderStatus::Cancelled;
     purchase_order.updated_at = clock.unix_timestamp;
 
     msg!(
-        "Purchase order cancelada pelo investor: {} SOL reembolsados",
-        purchase_order.total_lamports
+        "Purchase order cancelada pelo investor: {} reembolsados",
+        refunded
     );
 
     Ok(())
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::escrow::release_sell_escrow;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct CancelSellOrder<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Sell order sendo cancelada
+    #[account(
+        mut,
+        seeds = [
+            SellOrder::SEED_PREFIX,
+            sell_order.mint.as_ref(),
+            sell_order.holder.as_ref(),
+            &sell_order.created_at.to_le_bytes()
+        ],
+        bump = sell_order.bump,
+        constraint = sell_order.status == PurchaseOrderStatus::Pending @ PurchaseOrderError::NotPending,
+        constraint = sell_order.holder == holder.key() @ PurchaseOrderError::UnauthorizedCancel
+    )]
+    pub sell_order: Account<'info, SellOrder>,
+
+    /// Mint do token
+    #[account(
+        address = sell_order.mint,
+        mint::token_program = token_program
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Escrow dos tokens SRWA
+    #[account(
+        mut,
+        seeds = [SellOrder::ESCROW_SEED_PREFIX, sell_order.key().as_ref()],
+        bump
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Conta do holder que recebe os tokens de volta
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder,
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// As contas extras do transfer hook vão em remaining_accounts.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CancelSellOrder<'info>>) -> Result<()> {
+    // Devolver os tokens do escrow para o holder
+    release_sell_escrow(
+        &ctx.accounts.sell_order,
+        &ctx.accounts.mint,
+        &ctx.accounts.escrow,
+        ctx.accounts.holder_token_account.to_account_info(),
+        ctx.accounts.holder.to_account_info(),
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+    )?;
+
+    let clock = Clock::get()?;
+    let sell_order = &mut ctx.accounts.sell_order;
+    sell_order.status = PurchaseOrderStatus::Cancelled;
+    sell_order.updated_at = clock.unix_timestamp;
+
+    msg!(
+        "Sell order cancelada pelo holder: {} tokens devolvidos",
+        sell_order.quantity
+    );
+
+    Ok(())
+}
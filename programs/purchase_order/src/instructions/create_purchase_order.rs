@@ -13,6 +13,15 @@ pub struct CreatePurchaseOrder<'info> {
     /// CHECK: Validated as token mint via InterfaceAccount (supports both Token and Token-2022)
     pub mint: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
 
+    /// Oferta do SRWA, se existir
+    /// CHECK: PDA da factory, lido apenas se inicializado
+    #[account(
+        seeds = [b"offering", mint.key().as_ref()],
+        bump,
+        seeds::program = srwa_factory::ID
+    )]
+    pub offering: UncheckedAccount<'info>,
+
     /// PDA da purchase order
     /// Derivado de: [b"purchase_order", mint, investor, timestamp]
     #[account(
@@ -29,10 +38,15 @@ pub struct CreatePurchaseOrder<'info> {
     )]
     pub purchase_order: Account<'info, PurchaseOrder>,
 
-    /// Vault do admin que receberá o SOL
-    /// CHECK: Validado manualmente (pode ser qualquer conta)
-    #[account(mut)]
-    pub admin_vault: UncheckedAccount<'info>,
+    /// Escrow da order, guarda o SOL até aprovação ou reembolso
+    #[account(
+        init,
+        payer = investor,
+        space = 8 + OrderEscrow::INIT_SPACE,
+        seeds = [OrderEscrow::SEED_PREFIX, purchase_order.key().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, OrderEscrow>,
 
     pub system_program: Program<'info, System>,
 }
@@ -42,10 +56,24 @@ pub fn handler(
     quantity: u64,
     price_per_token_lamports: u64,
     timestamp: i64,
+    expires_at: Option<i64>,
 ) -> Result<()> {
     require!(quantity > 0, PurchaseOrderError::InvalidQuantity);
     require!(price_per_token_lamports > 0, PurchaseOrderError::InvalidPrice);
 
+    let clock = Clock::get()?;
+    if let Some(expires_at) = expires_at {
+        require!(expires_at > clock.unix_timestamp, PurchaseOrderError::InvalidExpiry);
+    }
+
+    // Ofertas com quote mint próprio só aceitam orders em SPL
+    if let Some(offering) = load_offering(&ctx.accounts.offering)? {
+        require!(
+            offering.pricing.quote_mint == Pubkey::default(),
+            PurchaseOrderError::QuoteMintMismatch
+        );
+    }
+
     // Calcular total em lamports
     let total_lamports = (quantity as u128)
         .checked_mul(price_per_token_lamports as u128)
@@ -53,20 +81,22 @@ pub fn handler(
         .try_into()
         .map_err(|_| PurchaseOrderError::MathOverflow)?;
 
-    let clock = Clock::get()?;
-
-    // Transferir SOL do investor para o admin vault
+    // Transferir SOL do investor para o escrow da order
     system_program::transfer(
         CpiContext::new(
             ctx.accounts.system_program.to_account_info(),
             system_program::Transfer {
                 from: ctx.accounts.investor.to_account_info(),
-                to: ctx.accounts.admin_vault.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
             },
         ),
         total_lamports,
     )?;
 
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.purchase_order = ctx.accounts.purchase_order.key();
+    escrow.bump = ctx.bumps.escrow;
+
     // Inicializar a purchase order
     let purchase_order = &mut ctx.accounts.purchase_order;
     purchase_order.bump = ctx.bumps.purchase_order;
@@ -75,6 +105,10 @@ pub fn handler(
     purchase_order.quantity = quantity;
     purchase_order.price_per_token_lamports = price_per_token_lamports;
     purchase_order.total_lamports = total_lamports;
+    purchase_order.quote_mint = None;
+    purchase_order.max_price_per_token = None;
+    purchase_order.filled_quantity = 0;
+    purchase_order.expires_at = expires_at;
     purchase_order.status = PurchaseOrderStatus::Pending;
     purchase_order.created_at = timestamp;
     purchase_order.updated_at = clock.unix_timestamp;
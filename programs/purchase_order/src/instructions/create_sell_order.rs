@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::onchain::invoke_transfer_checked,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use compliance_modules::state::ProgramAllowlist;
+use srwa_controller::program::SrwaController;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+#[instruction(quantity: u64, price_per_token: u64, timestamp: i64)]
+pub struct CreateSellOrder<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// Mint do token SRWA sendo vendido
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// PDA da sell order
+    /// Derivado de: [b"sell_order", mint, holder, timestamp]
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + SellOrder::INIT_SPACE,
+        seeds = [
+            SellOrder::SEED_PREFIX,
+            mint.key().as_ref(),
+            holder.key().as_ref(),
+            &timestamp.to_le_bytes()
+        ],
+        bump
+    )]
+    pub sell_order: Account<'info, SellOrder>,
+
+    /// Escrow dos tokens SRWA, authority = sell order
+    #[account(
+        init,
+        payer = holder,
+        seeds = [SellOrder::ESCROW_SEED_PREFIX, sell_order.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = sell_order,
+        token::token_program = token_program,
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Conta do holder (origem dos tokens)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = holder,
+        token::token_program = token_program,
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Liquidity vault que pagará a order
+    #[account(
+        seeds = [LiquidityVault::SEED_PREFIX, mint.key().as_ref(), liquidity_vault.quote_mint.as_ref()],
+        bump = liquidity_vault.bump
+    )]
+    pub liquidity_vault: Account<'info, LiquidityVault>,
+
+    /// ProgramAllowlist do mint; o escrow é um venue do transfer hook porque
+    /// a authority é a sell order, um PDA deste programa
+    #[account(
+        seeds = [b"program_allowlist", mint.key().as_ref()],
+        bump = program_allowlist.bump,
+        seeds::program = compliance_modules::ID,
+        constraint = program_allowlist.contains(&crate::ID) @ PurchaseOrderError::VenueNotAllowlisted
+    )]
+    pub program_allowlist: Box<Account<'info, ProgramAllowlist>>,
+
+    /// CHECK: Holder checkpoint do escrow, criado pelo controller
+    #[account(mut)]
+    pub escrow_checkpoint: UncheckedAccount<'info>,
+
+    pub controller_program: Program<'info, SrwaController>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// As contas extras do transfer hook vão em remaining_accounts.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateSellOrder<'info>>,
+    quantity: u64,
+    price_per_token: u64,
+    timestamp: i64,
+) -> Result<()> {
+    require!(quantity > 0, PurchaseOrderError::InvalidQuantity);
+    require!(price_per_token > 0, PurchaseOrderError::InvalidPrice);
+
+    let total_payout = PurchaseOrder::quote_total(quantity, price_per_token, ctx.accounts.mint.decimals)?;
+    require!(total_payout > 0, PurchaseOrderError::InvalidQuantity);
+
+    // A transferência para o escrow passa pelo transfer hook, que trata o
+    // escrow como venue; ele só precisa do próprio checkpoint
+    srwa_controller::cpi::open_checkpoint(CpiContext::new(
+        ctx.accounts.controller_program.to_account_info(),
+        srwa_controller::cpi::accounts::OpenCheckpoint {
+            payer: ctx.accounts.holder.to_account_info(),
+            token_account: ctx.accounts.escrow.to_account_info(),
+            checkpoint: ctx.accounts.escrow_checkpoint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+    ))?;
+
+    // Transferir os tokens do holder para o escrow
+    invoke_transfer_checked(
+        ctx.accounts.token_program.key,
+        ctx.accounts.holder_token_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.escrow.to_account_info(),
+        ctx.accounts.holder.to_account_info(),
+        ctx.remaining_accounts,
+        quantity,
+        ctx.accounts.mint.decimals,
+        &[],
+    )?;
+
+    let clock = Clock::get()?;
+    let sell_order = &mut ctx.accounts.sell_order;
+    sell_order.bump = ctx.bumps.sell_order;
+    sell_order.holder = ctx.accounts.holder.key();
+    sell_order.mint = ctx.accounts.mint.key();
+    sell_order.quantity = quantity;
+    sell_order.price_per_token = price_per_token;
+    sell_order.quote_mint = ctx.accounts.liquidity_vault.quote_mint;
+    sell_order.total_payout = total_payout;
+    sell_order.status = PurchaseOrderStatus::Pending;
+    sell_order.created_at = timestamp;
+    sell_order.updated_at = clock.unix_timestamp;
+    sell_order.processed_by = None;
+    sell_order.reject_reason = None;
+
+    msg!(
+        "Sell order criada: {} {} tokens por {} {} no total",
+        quantity,
+        sell_order.mint,
+        total_payout,
+        sell_order.quote_mint
+    );
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use srwa_factory::state::PricingModel;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+#[instruction(quantity: u64, price_per_token: u64, timestamp: i64)]
+pub struct CreateTokenPurchaseOrder<'info> {
+    #[account(mut)]
+    pub investor: Signer<'info>,
+
+    /// Mint do token SRWA sendo comprado
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Oferta do SRWA, se existir
+    /// CHECK: PDA da factory, lido apenas se inicializado
+    #[account(
+        seeds = [b"offering", mint.key().as_ref()],
+        bump,
+        seeds::program = srwa_factory::ID
+    )]
+    pub offering: UncheckedAccount<'info>,
+
+    /// PDA da purchase order
+    /// Derivado de: [b"purchase_order", mint, investor, timestamp]
+    #[account(
+        init,
+        payer = investor,
+        space = PurchaseOrder::space(),
+        seeds = [
+            PurchaseOrder::SEED_PREFIX,
+            mint.key().as_ref(),
+            investor.key().as_ref(),
+            &timestamp.to_le_bytes()
+        ],
+        bump
+    )]
+    pub purchase_order: Account<'info, PurchaseOrder>,
+
+    /// Escrow da order, authority da conta SPL abaixo
+    #[account(
+        init,
+        payer = investor,
+        space = 8 + OrderEscrow::INIT_SPACE,
+        seeds = [OrderEscrow::SEED_PREFIX, purchase_order.key().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, OrderEscrow>,
+
+    /// Mint de pagamento (USDC, PYUSD, ...)
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+
+    /// Conta do investor no quote mint (origem do pagamento)
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::authority = investor,
+        token::token_program = quote_token_program,
+    )]
+    pub investor_quote_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Escrow SPL que guarda o pagamento até aprovação ou reembolso
+    #[account(
+        init,
+        payer = investor,
+        seeds = [OrderEscrow::TOKEN_SEED_PREFIX, purchase_order.key().as_ref()],
+        bump,
+        token::mint = quote_mint,
+        token::authority = escrow,
+        token::token_program = quote_token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateTokenPurchaseOrder>,
+    quantity: u64,
+    price_per_token: u64,
+    timestamp: i64,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    require!(quantity > 0, PurchaseOrderError::InvalidQuantity);
+    require!(price_per_token > 0, PurchaseOrderError::InvalidPrice);
+
+    let clock = Clock::get()?;
+    if let Some(expires_at) = expires_at {
+        require!(expires_at > clock.unix_timestamp, PurchaseOrderError::InvalidExpiry);
+    }
+
+    // Com oferta, quote mint e preço vêm do Pricing; o preço informado
+    // protege o investor contra mudanças entre assinatura e execução.
+    // Ofertas precificadas pelo NAV usam o preço informado como máximo e
+    // o preço final é definido na aprovação.
+    let mut max_price_per_token = None;
+    if let Some(offering) = load_offering(&ctx.accounts.offering)? {
+        require_keys_eq!(
+            offering.pricing.quote_mint,
+            ctx.accounts.quote_mint.key(),
+            PurchaseOrderError::QuoteMintMismatch
+        );
+        if offering.pricing.model == PricingModel::NAV {
+            max_price_per_token = Some(price_per_token);
+        } else {
+            require!(
+                offering.pricing.unit_price == price_per_token,
+                PurchaseOrderError::PriceMismatch
+            );
+        }
+    }
+
+    let total = PurchaseOrder::quote_total(quantity, price_per_token, ctx.accounts.mint.decimals)?;
+    require!(total > 0, PurchaseOrderError::InvalidQuantity);
+
+    // Transferir o pagamento do investor para o escrow SPL
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.quote_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.investor_quote_account.to_account_info(),
+                mint: ctx.accounts.quote_mint.to_account_info(),
+                to: ctx.accounts.escrow_token_account.to_account_info(),
+                authority: ctx.accounts.investor.to_account_info(),
+            },
+        ),
+        total,
+        ctx.accounts.quote_mint.decimals,
+    )?;
+
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.purchase_order = ctx.accounts.purchase_order.key();
+    escrow.bump = ctx.bumps.escrow;
+
+    // Inicializar a purchase order
+    let purchase_order = &mut ctx.accounts.purchase_order;
+    purchase_order.bump = ctx.bumps.purchase_order;
+    purchase_order.investor = ctx.accounts.investor.key();
+    purchase_order.mint = ctx.accounts.mint.key();
+    purchase_order.quantity = quantity;
+    purchase_order.price_per_token_lamports = price_per_token;
+    purchase_order.total_lamports = total;
+    purchase_order.quote_mint = Some(ctx.accounts.quote_mint.key());
+    purchase_order.max_price_per_token = max_price_per_token;
+    purchase_order.filled_quantity = 0;
+    purchase_order.expires_at = expires_at;
+    purchase_order.status = PurchaseOrderStatus::Pending;
+    purchase_order.created_at = timestamp;
+    purchase_order.updated_at = clock.unix_timestamp;
+    purchase_order.processed_by = None;
+    purchase_order.approval_tx = None;
+    purchase_order.reject_reason = None;
+
+    msg!(
+        "Purchase order criada: {} {} tokens por {} {} no total",
+        quantity,
+        ctx.accounts.mint.key(),
+        total,
+        ctx.accounts.quote_mint.key()
+    );
+
+    Ok(())
+}
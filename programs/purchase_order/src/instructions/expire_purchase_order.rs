@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::escrow::QuoteEscrow;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct ExpirePurchaseOrder<'info> {
+    /// Crank permissionless após `expires_at`
+    pub payer: Signer<'info>,
+
+    /// Purchase order sendo expirada
+    #[account(
+        mut,
+        seeds = [
+            PurchaseOrder::SEED_PREFIX,
+            purchase_order.mint.as_ref(),
+            purchase_order.investor.as_ref(),
+            &purchase_order.created_at.to_le_bytes()
+        ],
+        bump = purchase_order.bump,
+        constraint = purchase_order.status == PurchaseOrderStatus::Pending @ PurchaseOrderError::NotPending
+    )]
+    pub purchase_order: Account<'info, PurchaseOrder>,
+
+    /// Escrow da order, fechado para o investor (reembolso + rent)
+    #[account(
+        mut,
+        seeds = [OrderEscrow::SEED_PREFIX, purchase_order.key().as_ref()],
+        bump = escrow.bump,
+        close = investor
+    )]
+    pub escrow: Account<'info, OrderEscrow>,
+
+    /// Investor que recebe o reembolso
+    #[account(
+        mut,
+        constraint = investor.key() == purchase_order.investor
+    )]
+    pub investor: SystemAccount<'info>,
+
+    /// Quote mint da order (apenas orders pagas em SPL)
+    #[account(address = purchase_order.quote_mint.unwrap_or_default() @ PurchaseOrderError::QuoteMintMismatch)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Escrow SPL da order
+    #[account(
+        mut,
+        seeds = [OrderEscrow::TOKEN_SEED_PREFIX, purchase_order.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Conta do investor no quote mint que recebe o reembolso
+    #[account(
+        mut,
+        constraint = investor_quote_account.mint == purchase_order.quote_mint.unwrap_or_default() @ PurchaseOrderError::QuoteMintMismatch,
+        constraint = investor_quote_account.owner == purchase_order.investor
+    )]
+    pub investor_quote_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<ExpirePurchaseOrder>) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        ctx.accounts.purchase_order.is_expired(clock.unix_timestamp),
+        PurchaseOrderError::OrderNotExpired
+    );
+
+    // Orders em SPL: reembolso no mesmo quote mint
+    let refunded = if ctx.accounts.purchase_order.quote_mint.is_some() {
+        let accounts = &ctx.accounts;
+        QuoteEscrow::load(
+            &accounts.escrow,
+            accounts.quote_mint.as_deref(),
+            accounts.escrow_token_account.as_deref(),
+            accounts.quote_token_program.as_ref(),
+        )?
+        .refund_and_close(
+            accounts.investor_quote_account.as_deref().ok_or(PurchaseOrderError::MissingQuoteAccounts)?.to_account_info(),
+            accounts.investor.to_account_info(),
+        )?
+    } else {
+        // O SOL acima do rent volta ao investor ao fechar o escrow
+        let escrow_info = ctx.accounts.escrow.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(escrow_info.data_len());
+        escrow_info.lamports().saturating_sub(rent_exempt)
+    };
+
+    let purchase_order = &mut ctx.accounts.purchase_order;
+
+    // Atualizar purchase order
+    purchase_order.status = PurchaseOrderStatus::Expired;
+    purchase_order.updated_at = clock.unix_timestamp;
+
+    msg!(
+        "Purchase order expirada: {} reembolsados para {}",
+        refunded,
+        purchase_order.investor
+    );
+
+    Ok(())
+}
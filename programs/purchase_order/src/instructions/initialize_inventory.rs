@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use compliance_modules::program::ComplianceModules;
+use srwa_controller::program::SrwaController;
+use srwa_factory::state::{PlatformAdminRegistry, SRWAConfig};
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeInventory<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Mint do token SRWA
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Configuração do SRWA, define os papéis autorizados
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        constraint = is_order_admin(&admin.key(), &srwa_config, admin_registry.as_deref()) @ PurchaseOrderError::UnauthorizedAdmin
+    )]
+    pub srwa_config: Box<Account<'info, SRWAConfig>>,
+
+    /// Obrigatório quando o admin é um admin da plataforma
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub admin_registry: Option<Account<'info, PlatformAdminRegistry>>,
+
+    /// PDA do inventário, authority do vault abaixo
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + IssuerInventory::INIT_SPACE,
+        seeds = [IssuerInventory::SEED_PREFIX, mint.key().as_ref()],
+        bump
+    )]
+    pub inventory: Account<'info, IssuerInventory>,
+
+    /// Conta de tokens que o issuer abastece para entregar as orders
+    #[account(
+        init,
+        payer = admin,
+        seeds = [IssuerInventory::VAULT_SEED_PREFIX, inventory.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = inventory,
+        token::token_program = token_program,
+    )]
+    pub inventory_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: ProgramAllowlist do mint, criado pelo compliance_modules
+    #[account(mut)]
+    pub program_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: AccountAllowlist do mint, criado pelo compliance_modules
+    #[account(mut)]
+    pub account_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: Holder checkpoint do vault, criado pelo controller
+    #[account(mut)]
+    pub inventory_checkpoint: UncheckedAccount<'info>,
+
+    pub compliance_program: Program<'info, ComplianceModules>,
+    pub controller_program: Program<'info, SrwaController>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeInventory>) -> Result<()> {
+    // As entregas passam pelo transfer hook, então o vault entra na
+    // allowlist de venues do mint (o admin precisa ser autoridade de
+    // compliance) e ganha o próprio checkpoint
+    compliance_modules::cpi::allowlist_venue(
+        CpiContext::new(
+            ctx.accounts.compliance_program.to_account_info(),
+            compliance_modules::cpi::accounts::AllowlistVenue {
+                authority: ctx.accounts.admin.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                srwa_config: ctx.accounts.srwa_config.to_account_info(),
+                admin_registry: ctx
+                    .accounts
+                    .admin_registry
+                    .as_ref()
+                    .map(|registry| registry.to_account_info()),
+                program_allowlist: ctx.accounts.program_allowlist.to_account_info(),
+                account_allowlist: ctx.accounts.account_allowlist.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+        ),
+        crate::ID,
+        vec![ctx.accounts.inventory_vault.key()],
+    )?;
+    srwa_controller::cpi::open_checkpoint(CpiContext::new(
+        ctx.accounts.controller_program.to_account_info(),
+        srwa_controller::cpi::accounts::OpenCheckpoint {
+            payer: ctx.accounts.admin.to_account_info(),
+            token_account: ctx.accounts.inventory_vault.to_account_info(),
+            checkpoint: ctx.accounts.inventory_checkpoint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+    ))?;
+
+    let inventory = &mut ctx.accounts.inventory;
+    inventory.mint = ctx.accounts.mint.key();
+    inventory.vault = ctx.accounts.inventory_vault.key();
+    inventory.delivered = 0;
+    inventory.bump = ctx.bumps.inventory;
+
+    msg!(
+        "Inventário criado para {}: vault {}",
+        inventory.mint,
+        inventory.vault
+    );
+
+    Ok(())
+}
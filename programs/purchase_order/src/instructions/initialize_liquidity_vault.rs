@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use compliance_modules::program::ComplianceModules;
+use srwa_factory::state::{PlatformAdminRegistry, SRWAConfig};
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeLiquidityVault<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Mint do token SRWA
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Configuração do SRWA, define os papéis autorizados
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        constraint = is_order_admin(&admin.key(), &srwa_config, admin_registry.as_deref()) @ PurchaseOrderError::UnauthorizedAdmin
+    )]
+    pub srwa_config: Box<Account<'info, SRWAConfig>>,
+
+    /// Obrigatório quando o admin é um admin da plataforma
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub admin_registry: Option<Account<'info, PlatformAdminRegistry>>,
+
+    /// Mint de pagamento das sell orders
+    #[account(mint::token_program = quote_token_program)]
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + LiquidityVault::INIT_SPACE,
+        seeds = [LiquidityVault::SEED_PREFIX, mint.key().as_ref(), quote_mint.key().as_ref()],
+        bump
+    )]
+    pub liquidity_vault: Account<'info, LiquidityVault>,
+
+    /// Conta que o issuer/market maker abastece para pagar as sell orders
+    #[account(
+        init,
+        payer = admin,
+        seeds = [LiquidityVault::TOKEN_SEED_PREFIX, liquidity_vault.key().as_ref()],
+        bump,
+        token::mint = quote_mint,
+        token::authority = liquidity_vault,
+        token::token_program = quote_token_program,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: ProgramAllowlist do mint, criado pelo compliance_modules
+    #[account(mut)]
+    pub program_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: AccountAllowlist do mint, criado pelo compliance_modules
+    #[account(mut)]
+    pub account_allowlist: UncheckedAccount<'info>,
+
+    pub compliance_program: Program<'info, ComplianceModules>,
+    pub quote_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeLiquidityVault>) -> Result<()> {
+    // Os escrows das sell orders pertencem a PDAs deste programa, então o
+    // programa entra na allowlist de venues do mint (o admin precisa ser
+    // autoridade de compliance)
+    compliance_modules::cpi::allowlist_venue(
+        CpiContext::new(
+            ctx.accounts.compliance_program.to_account_info(),
+            compliance_modules::cpi::accounts::AllowlistVenue {
+                authority: ctx.accounts.admin.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                srwa_config: ctx.accounts.srwa_config.to_account_info(),
+                admin_registry: ctx
+                    .accounts
+                    .admin_registry
+                    .as_ref()
+                    .map(|registry| registry.to_account_info()),
+                program_allowlist: ctx.accounts.program_allowlist.to_account_info(),
+                account_allowlist: ctx.accounts.account_allowlist.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+        ),
+        crate::ID,
+        vec![],
+    )?;
+
+    let liquidity_vault = &mut ctx.accounts.liquidity_vault;
+    liquidity_vault.mint = ctx.accounts.mint.key();
+    liquidity_vault.quote_mint = ctx.accounts.quote_mint.key();
+    liquidity_vault.vault = ctx.accounts.vault_token_account.key();
+    liquidity_vault.paid_out = 0;
+    liquidity_vault.bump = ctx.bumps.liquidity_vault;
+
+    msg!(
+        "Liquidity vault criado para {} em {}: {}",
+        liquidity_vault.mint,
+        liquidity_vault.quote_mint,
+        liquidity_vault.vault
+    );
+
+    Ok(())
+}
@@ -1,9 +1,27 @@
 pub mod create_purchase_order;
+pub mod create_token_purchase_order;
+pub mod initialize_inventory;
 pub mod approve_purchase_order;
 pub mod reject_purchase_order;
 pub mod cancel_purchase_order;
+pub mod expire_purchase_order;
+pub mod initialize_liquidity_vault;
+pub mod withdraw_liquidity;
+pub mod create_sell_order;
+pub mod approve_sell_order;
+pub mod reject_sell_order;
+pub mod cancel_sell_order;
 
 pub use create_purchase_order::*;
+pub use create_token_purchase_order::*;
+pub use initialize_inventory::*;
 pub use approve_purchase_order::*;
 pub use reject_purchase_order::*;
 pub use cancel_purchase_order::*;
+pub use expire_purchase_order::*;
+pub use initialize_liquidity_vault::*;
+pub use withdraw_liquidity::*;
+pub use create_sell_order::*;
+pub use approve_sell_order::*;
+pub use reject_sell_order::*;
+pub use cancel_sell_order::*;
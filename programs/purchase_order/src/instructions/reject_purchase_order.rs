@@ -1,4 +1,7 @@
-use anchor_lang::{prelude::*, system_program};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use srwa_factory::state::{PlatformAdminRegistry, SRWAConfig};
+use crate::escrow::QuoteEscrow;
 use crate::state::*;
 use crate::errors::*;
 
@@ -22,13 +25,31 @@ pub struct RejectPurchaseOrder<'info> {
     )]
     pub purchase_order: Account<'info, PurchaseOrder>,
 
-    /// Vault do admin que retornará o SOL
-    /// CHECK: Deve ser a mesma conta que recebeu o SOL originalmente
+    /// Configuração do SRWA, define os papéis autorizados
+    #[account(
+        seeds = [b"srwa_config", purchase_order.mint.as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        constraint = is_order_admin(&admin.key(), &srwa_config, admin_registry.as_deref()) @ PurchaseOrderError::UnauthorizedAdmin
+    )]
+    pub srwa_config: Box<Account<'info, SRWAConfig>>,
+
+    /// Obrigatório quando o admin é um admin da plataforma
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub admin_registry: Option<Account<'info, PlatformAdminRegistry>>,
+
+    /// Escrow da order, fechado para o investor (reembolso + rent)
     #[account(
         mut,
-        constraint = admin_vault.key() == admin.key() @ PurchaseOrderError::AdminVaultMismatch
+        seeds = [OrderEscrow::SEED_PREFIX, purchase_order.key().as_ref()],
+        bump = escrow.bump,
+        close = investor
     )]
-    pub admin_vault: UncheckedAccount<'info>,
+    pub escrow: Account<'info, OrderEscrow>,
 
     /// Investor que receberá o reembolso
     /// CHECK: Validado pelo PDA
@@ -38,7 +59,27 @@ pub struct RejectPurchaseOrder<'info> {
     )]
     pub investor: SystemAccount<'info>,
 
-    pub system_program: Program<'info, System>,
+    /// Quote mint da order (apenas orders pagas em SPL)
+    #[account(address = purchase_order.quote_mint.unwrap_or_default() @ PurchaseOrderError::QuoteMintMismatch)]
+    pub quote_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Escrow SPL da order
+    #[account(
+        mut,
+        seeds = [OrderEscrow::TOKEN_SEED_PREFIX, purchase_order.key().as_ref()],
+        bump
+    )]
+    pub escrow_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Conta do investor no quote mint que recebe o reembolso
+    #[account(
+        mut,
+        constraint = investor_quote_account.mint == purchase_order.quote_mint.unwrap_or_default() @ PurchaseOrderError::QuoteMintMismatch,
+        constraint = investor_quote_account.owner == purchase_order.investor
+    )]
+    pub investor_quote_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub quote_token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 pub fn handler(ctx: Context<RejectPurchaseOrder>, reason: String) -> Result<()> {
@@ -47,21 +88,28 @@ pub fn handler(ctx: Context<RejectPurchaseOrder>, reason: String) -> Result<()>
         PurchaseOrderError::RejectReasonTooLong
     );
 
+    // Orders em SPL: reembolso no mesmo quote mint
+    let refunded = if ctx.accounts.purchase_order.quote_mint.is_some() {
+        let accounts = &ctx.accounts;
+        QuoteEscrow::load(
+            &accounts.escrow,
+            accounts.quote_mint.as_deref(),
+            accounts.escrow_token_account.as_deref(),
+            accounts.quote_token_program.as_ref(),
+        )?
+        .refund_and_close(
+            accounts.investor_quote_account.as_deref().ok_or(PurchaseOrderError::MissingQuoteAccounts)?.to_account_info(),
+            accounts.investor.to_account_info(),
+        )?
+    } else {
+        // O SOL acima do rent volta ao investor ao fechar o escrow
+        let escrow_info = ctx.accounts.escrow.to_account_info();
+        let rent_exempt = Rent::get()?.minimum_balance(escrow_info.data_len());
+        escrow_info.lamports().saturating_sub(rent_exempt)
+    };
+
     let purchase_order = &mut ctx.accounts.purchase_order;
     let clock = Clock::get()?;
-    let refund_amount = purchase_order.total_lamports;
-
-    // Reembolsar SOL utilizando transferência do System Program
-    system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            system_program::Transfer {
-                from: ctx.accounts.admin.to_account_info(),
-                to: ctx.accounts.investor.to_account_info(),
-            },
-        ),
-        refund_amount,
-    )?;
 
     // Atualizar purchase order
     purchase_order.status = PurchaseOrderStatus::Rejected;
@@ -70,8 +118,8 @@ pub fn handler(ctx: Context<RejectPurchaseOrder>, reason: String) -> Result<()>
     purchase_order.reject_reason = Some(reason.clone());
 
     msg!(
-        "Purchase order rejeitada: {} SOL reembolsados. Motivo: {}",
-        purchase_order.total_lamports,
+        "Purchase order rejeitada: {} reembolsados. Motivo: {}",
+        refunded,
         reason
     );
 
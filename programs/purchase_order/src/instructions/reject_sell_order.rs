@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use srwa_factory::state::{PlatformAdminRegistry, SRWAConfig};
+use crate::escrow::release_sell_escrow;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+#[instruction(reason: String)]
+pub struct RejectSellOrder<'info> {
+    pub admin: Signer<'info>,
+
+    /// Sell order sendo rejeitada
+    #[account(
+        mut,
+        seeds = [
+            SellOrder::SEED_PREFIX,
+            sell_order.mint.as_ref(),
+            sell_order.holder.as_ref(),
+            &sell_order.created_at.to_le_bytes()
+        ],
+        bump = sell_order.bump,
+        constraint = sell_order.status == PurchaseOrderStatus::Pending @ PurchaseOrderError::NotPending
+    )]
+    pub sell_order: Account<'info, SellOrder>,
+
+    /// Configuração do SRWA, define os papéis autorizados
+    #[account(
+        seeds = [b"srwa_config", sell_order.mint.as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        constraint = is_order_admin(&admin.key(), &srwa_config, admin_registry.as_deref()) @ PurchaseOrderError::UnauthorizedAdmin
+    )]
+    pub srwa_config: Box<Account<'info, SRWAConfig>>,
+
+    /// Obrigatório quando o admin é um admin da plataforma
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub admin_registry: Option<Account<'info, PlatformAdminRegistry>>,
+
+    /// Mint do token
+    #[account(
+        address = sell_order.mint,
+        mint::token_program = token_program
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Escrow dos tokens SRWA
+    #[account(
+        mut,
+        seeds = [SellOrder::ESCROW_SEED_PREFIX, sell_order.key().as_ref()],
+        bump
+    )]
+    pub escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Conta do holder que recebe os tokens de volta
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = holder_token_account.owner == sell_order.holder
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Holder que recebe o rent do escrow
+    #[account(
+        mut,
+        constraint = holder.key() == sell_order.holder
+    )]
+    pub holder: SystemAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// As contas extras do transfer hook vão em remaining_accounts.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, RejectSellOrder<'info>>,
+    reason: String,
+) -> Result<()> {
+    require!(
+        reason.len() <= 200,
+        PurchaseOrderError::RejectReasonTooLong
+    );
+
+    // Devolver os tokens do escrow para o holder
+    release_sell_escrow(
+        &ctx.accounts.sell_order,
+        &ctx.accounts.mint,
+        &ctx.accounts.escrow,
+        ctx.accounts.holder_token_account.to_account_info(),
+        ctx.accounts.holder.to_account_info(),
+        &ctx.accounts.token_program,
+        ctx.remaining_accounts,
+    )?;
+
+    let clock = Clock::get()?;
+    let sell_order = &mut ctx.accounts.sell_order;
+    sell_order.status = PurchaseOrderStatus::Rejected;
+    sell_order.updated_at = clock.unix_timestamp;
+    sell_order.processed_by = Some(ctx.accounts.admin.key());
+    sell_order.reject_reason = Some(reason.clone());
+
+    msg!(
+        "Sell order rejeitada: {} tokens devolvidos. Motivo: {}",
+        sell_order.quantity,
+        reason
+    );
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use srwa_factory::state::{PlatformAdminRegistry, SRWAConfig};
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct WithdrawLiquidity<'info> {
+    pub admin: Signer<'info>,
+
+    /// Configuração do SRWA, define os papéis autorizados
+    #[account(
+        seeds = [b"srwa_config", liquidity_vault.mint.as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        constraint = is_order_admin(&admin.key(), &srwa_config, admin_registry.as_deref()) @ PurchaseOrderError::UnauthorizedAdmin
+    )]
+    pub srwa_config: Box<Account<'info, SRWAConfig>>,
+
+    /// Obrigatório quando o admin é um admin da plataforma
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub admin_registry: Option<Account<'info, PlatformAdminRegistry>>,
+
+    #[account(
+        seeds = [LiquidityVault::SEED_PREFIX, liquidity_vault.mint.as_ref(), liquidity_vault.quote_mint.as_ref()],
+        bump = liquidity_vault.bump,
+        has_one = quote_mint
+    )]
+    pub liquidity_vault: Account<'info, LiquidityVault>,
+
+    #[account(mut, address = liquidity_vault.vault)]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub quote_mint: InterfaceAccount<'info, Mint>,
+
+    /// Destino dos fundos retirados
+    #[account(mut, token::mint = quote_mint)]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    pub quote_token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<WithdrawLiquidity>, amount: u64) -> Result<()> {
+    require!(amount > 0, PurchaseOrderError::InvalidQuantity);
+    require!(
+        ctx.accounts.vault_token_account.amount >= amount,
+        PurchaseOrderError::InsufficientLiquidity
+    );
+
+    let liquidity_vault = &ctx.accounts.liquidity_vault;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        LiquidityVault::SEED_PREFIX,
+        liquidity_vault.mint.as_ref(),
+        liquidity_vault.quote_mint.as_ref(),
+        &[liquidity_vault.bump],
+    ]];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.quote_token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                mint: ctx.accounts.quote_mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: liquidity_vault.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.quote_mint.decimals,
+    )?;
+
+    msg!(
+        "Liquidity vault {}: {} retirados para {}",
+        liquidity_vault.key(),
+        amount,
+        ctx.accounts.destination.key()
+    );
+
+    Ok(())
+}
@@ -6,6 +6,7 @@ declare_id!("43iQGS4Xyg6aGcpL52n7KurMm5eVNvQKEtgdPSxZwKPU");
 pub mod state;
 pub mod instructions;
 pub mod errors;
+pub mod escrow;
 
 use instructions::*;
 use state::*;
@@ -14,22 +15,44 @@ use state::*;
 pub mod purchase_order {
     use super::*;
 
-    /// Cria uma nova purchase order (investor envia SOL, espera aprovação do admin)
+    /// Cria uma nova purchase order (investor deposita SOL no escrow, espera aprovação do admin)
     pub fn create_purchase_order(
         ctx: Context<CreatePurchaseOrder>,
         quantity: u64,
         price_per_token_lamports: u64,
         timestamp: i64,
+        expires_at: Option<i64>,
     ) -> Result<()> {
-        instructions::create_purchase_order::handler(ctx, quantity, price_per_token_lamports, timestamp)
+        instructions::create_purchase_order::handler(ctx, quantity, price_per_token_lamports, timestamp, expires_at)
     }
 
-    /// Admin aprova a purchase order e transfere os tokens
-    pub fn approve_purchase_order(ctx: Context<ApprovePurchaseOrder>) -> Result<()> {
-        instructions::approve_purchase_order::handler(ctx)
+    /// Cria uma purchase order paga em SPL/Token-2022 (USDC, PYUSD), com escrow no quote mint.
+    /// Em ofertas precificadas pelo NAV, `price_per_token` é o preço máximo aceito.
+    pub fn create_token_purchase_order(
+        ctx: Context<CreateTokenPurchaseOrder>,
+        quantity: u64,
+        price_per_token: u64,
+        timestamp: i64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::create_token_purchase_order::handler(ctx, quantity, price_per_token, timestamp, expires_at)
+    }
+
+    /// Cria o inventário do issuer de onde as orders aprovadas são entregues
+    pub fn initialize_inventory(ctx: Context<InitializeInventory>) -> Result<()> {
+        instructions::initialize_inventory::handler(ctx)
+    }
+
+    /// Admin aprova a purchase order: entrega `fill_quantity` tokens do inventário, libera o
+    /// pagamento para o issuer treasury e reembolsa o restante na mesma instrução
+    pub fn approve_purchase_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApprovePurchaseOrder<'info>>,
+        fill_quantity: u64,
+    ) -> Result<()> {
+        instructions::approve_purchase_order::handler(ctx, fill_quantity)
     }
 
-    /// Admin rejeita a purchase order e reembolsa o SOL
+    /// Admin rejeita a purchase order e reembolsa o SOL do escrow
     pub fn reject_purchase_order(
         ctx: Context<RejectPurchaseOrder>,
         reason: String,
@@ -41,4 +64,51 @@ pub mod purchase_order {
     pub fn cancel_purchase_order(ctx: Context<CancelPurchaseOrder>) -> Result<()> {
         instructions::cancel_purchase_order::handler(ctx)
     }
+
+    /// Qualquer um expira uma purchase order vencida, reembolsando o investor
+    pub fn expire_purchase_order(ctx: Context<ExpirePurchaseOrder>) -> Result<()> {
+        instructions::expire_purchase_order::handler(ctx)
+    }
+
+    /// Cria o liquidity vault (SRWA, quote mint) que paga as sell orders
+    pub fn initialize_liquidity_vault(ctx: Context<InitializeLiquidityVault>) -> Result<()> {
+        instructions::initialize_liquidity_vault::handler(ctx)
+    }
+
+    /// Admin retira fundos do liquidity vault
+    pub fn withdraw_liquidity(ctx: Context<WithdrawLiquidity>, amount: u64) -> Result<()> {
+        instructions::withdraw_liquidity::handler(ctx, amount)
+    }
+
+    /// Holder cria uma sell order (tokens SRWA em escrow, espera aprovação do admin)
+    pub fn create_sell_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateSellOrder<'info>>,
+        quantity: u64,
+        price_per_token: u64,
+        timestamp: i64,
+    ) -> Result<()> {
+        instructions::create_sell_order::handler(ctx, quantity, price_per_token, timestamp)
+    }
+
+    /// Admin aprova a sell order: tokens para o inventário e pagamento do liquidity vault
+    pub fn approve_sell_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, ApproveSellOrder<'info>>,
+    ) -> Result<()> {
+        instructions::approve_sell_order::handler(ctx)
+    }
+
+    /// Admin rejeita a sell order e devolve os tokens ao holder
+    pub fn reject_sell_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, RejectSellOrder<'info>>,
+        reason: String,
+    ) -> Result<()> {
+        instructions::reject_sell_order::handler(ctx, reason)
+    }
+
+    /// Holder cancela sell order pendente (tokens devolvidos)
+    pub fn cancel_sell_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelSellOrder<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_sell_order::handler(ctx)
+    }
 }
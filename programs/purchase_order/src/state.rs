@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use srwa_factory::state::{PlatformAdminRegistry, SRWAConfig};
 
 #[account]
 #[derive(InitSpace)]
@@ -15,12 +16,26 @@ pub struct PurchaseOrder {
     /// Quantidade de tokens solicitados (em unidades base)
     pub quantity: u64,
 
-    /// Preço por token em lamports
+    /// Preço por token: lamports por unidade base (SOL) ou
+    /// unidades do quote mint por token inteiro (SPL, mesmo formato do Pricing)
     pub price_per_token_lamports: u64,
 
-    /// Total em SOL (lamports) que o investor pagou
+    /// Total que o investor pagou (lamports ou unidades do quote mint)
     pub total_lamports: u64,
 
+    /// Mint de pagamento (None = SOL)
+    pub quote_mint: Option<Pubkey>,
+
+    /// Preço máximo aceito quando o preço vem do NAV na aprovação
+    /// (None = preço fixo em `price_per_token_lamports`)
+    pub max_price_per_token: Option<u64>,
+
+    /// Quantidade entregue na aprovação (o restante é reembolsado)
+    pub filled_quantity: u64,
+
+    /// Após este timestamp qualquer um pode expirar a order e reembolsar o investor
+    pub expires_at: Option<i64>,
+
     /// Status da purchase order
     pub status: PurchaseOrderStatus,
 
@@ -47,6 +62,7 @@ pub enum PurchaseOrderStatus {
     Approved,
     Rejected,
     Cancelled,
+    Expired,
 }
 
 impl Default for PurchaseOrderStatus {
@@ -55,9 +71,144 @@ impl Default for PurchaseOrderStatus {
     }
 }
 
+/// Ordem de venda (sell-back): o holder deposita os tokens SRWA em escrow e o
+/// issuer/market maker paga a partir do liquidity vault
+#[account]
+#[derive(InitSpace)]
+pub struct SellOrder {
+    /// Bump seed para o PDA
+    pub bump: u8,
+
+    /// Holder que está vendendo
+    pub holder: Pubkey,
+
+    /// Mint do token SRWA sendo vendido
+    pub mint: Pubkey,
+
+    /// Quantidade de tokens em escrow (em unidades base)
+    pub quantity: u64,
+
+    /// Preço acordado em unidades do quote mint por token inteiro
+    pub price_per_token: u64,
+
+    /// Mint de pagamento
+    pub quote_mint: Pubkey,
+
+    /// Total a receber do liquidity vault
+    pub total_payout: u64,
+
+    /// Status da sell order
+    pub status: PurchaseOrderStatus,
+
+    /// Timestamp de criação
+    pub created_at: i64,
+
+    /// Timestamp de atualização
+    pub updated_at: i64,
+
+    /// Admin que aprovou/rejeitou (se aplicável)
+    pub processed_by: Option<Pubkey>,
+
+    /// Motivo da rejeição (se rejeitado)
+    #[max_len(200)]
+    pub reject_reason: Option<String>,
+}
+
+impl SellOrder {
+    pub const SEED_PREFIX: &'static [u8] = b"sell_order";
+    pub const ESCROW_SEED_PREFIX: &'static [u8] = b"sell_escrow";
+}
+
+/// Liquidity vault do par (SRWA, quote mint) que paga as sell orders
+#[account]
+#[derive(InitSpace)]
+pub struct LiquidityVault {
+    /// Mint do token SRWA
+    pub mint: Pubkey,
+
+    /// Mint de pagamento
+    pub quote_mint: Pubkey,
+
+    /// Conta de tokens do vault no quote mint
+    pub vault: Pubkey,
+
+    /// Total pago a holders
+    pub paid_out: u64,
+
+    /// Bump seed para o PDA
+    pub bump: u8,
+}
+
+impl LiquidityVault {
+    pub const SEED_PREFIX: &'static [u8] = b"liquidity_vault";
+    pub const TOKEN_SEED_PREFIX: &'static [u8] = b"liquidity_vault_token";
+}
+
+/// Escrow PDA que guarda o SOL do investor até a aprovação
+#[account]
+#[derive(InitSpace)]
+pub struct OrderEscrow {
+    /// Purchase order dona deste escrow
+    pub purchase_order: Pubkey,
+
+    /// Bump seed para o PDA
+    pub bump: u8,
+}
+
+/// Inventário do issuer: authority da conta de tokens usada para entregar as orders aprovadas
+#[account]
+#[derive(InitSpace)]
+pub struct IssuerInventory {
+    /// Mint do token SRWA
+    pub mint: Pubkey,
+
+    /// Conta de tokens do inventário
+    pub vault: Pubkey,
+
+    /// Total entregue a investors
+    pub delivered: u64,
+
+    /// Bump seed para o PDA
+    pub bump: u8,
+}
+
+impl IssuerInventory {
+    pub const SEED_PREFIX: &'static [u8] = b"inventory";
+    pub const VAULT_SEED_PREFIX: &'static [u8] = b"inventory_vault";
+}
+
+impl OrderEscrow {
+    pub const SEED_PREFIX: &'static [u8] = b"order_escrow";
+    pub const TOKEN_SEED_PREFIX: &'static [u8] = b"order_escrow_token";
+}
+
 impl PurchaseOrder {
     pub const SEED_PREFIX: &'static [u8] = b"purchase_order";
 
+    /// Total em unidades do quote mint para `quantity` unidades base do SRWA
+    pub fn quote_total(quantity: u64, price_per_token: u64, decimals: u8) -> Result<u64> {
+        let total = (quantity as u128)
+            .checked_mul(price_per_token as u128)
+            .ok_or(crate::errors::PurchaseOrderError::MathOverflow)?
+            / 10u128.pow(decimals as u32);
+        u64::try_from(total).map_err(|_| error!(crate::errors::PurchaseOrderError::MathOverflow))
+    }
+
+    /// Custo de `quantity` unidades base ao preço `price_per_token`, no formato da order
+    pub fn cost_for(&self, quantity: u64, price_per_token: u64, decimals: u8) -> Result<u64> {
+        if self.quote_mint.is_some() {
+            return Self::quote_total(quantity, price_per_token, decimals);
+        }
+        let total = (quantity as u128)
+            .checked_mul(price_per_token as u128)
+            .ok_or(crate::errors::PurchaseOrderError::MathOverflow)?;
+        u64::try_from(total).map_err(|_| error!(crate::errors::PurchaseOrderError::MathOverflow))
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+
     /// Calcula o espaço necessário para a conta
     pub const fn space() -> usize {
         8 + // discriminator
@@ -67,6 +218,10 @@ impl PurchaseOrder {
         8 + // quantity
         8 + // price_per_token_lamports
         8 + // total_lamports
+        (1 + 32) + // quote_mint Option<Pubkey>
+        (1 + 8) + // max_price_per_token Option<u64>
+        8 + // filled_quantity
+        (1 + 8) + // expires_at Option<i64>
         1 + // status enum
         8 + // created_at
         8 + // updated_at
@@ -75,3 +230,27 @@ impl PurchaseOrder {
         (1 + 4 + 200) // reject_reason Option<String> max 200 chars
     }
 }
+
+/// Issuer admin ou transfer agent do mint, ou admin da plataforma
+pub fn is_order_admin(
+    authority: &Pubkey,
+    srwa_config: &SRWAConfig,
+    admin_registry: Option<&PlatformAdminRegistry>,
+) -> bool {
+    *authority == srwa_config.roles.issuer_admin
+        || *authority == srwa_config.roles.transfer_agent
+        || admin_registry.is_some_and(|registry| {
+            registry.super_admin == *authority || registry.authorized_admins.contains(authority)
+        })
+}
+
+/// Lê a OfferingState do SRWA, se o mint tiver uma oferta criada pela factory
+pub fn load_offering(offering_info: &AccountInfo) -> Result<Option<srwa_factory::state::OfferingState>> {
+    if offering_info.owner != &srwa_factory::ID || offering_info.data_is_empty() {
+        return Ok(None);
+    }
+    let offering = srwa_factory::state::OfferingState::try_deserialize(
+        &mut &offering_info.try_borrow_data()?[..],
+    )?;
+    Ok(Some(offering))
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ControllerError {
+    #[msg("Missing KYC accounts in remaining_accounts")]
+    MissingKYCAccounts,
+    #[msg("Invalid KYC account")]
+    InvalidKYCAccount,
+    #[msg("KYC not completed")]
+    KYCNotCompleted,
+    #[msg("User is not active")]
+    UserNotActive,
+    #[msg("Unauthorized")]
+    Unauthorized,
+    #[msg("Transfer hook was not invoked by a Token-2022 transfer")]
+    NotTransferring,
+    #[msg("Invalid token account")]
+    InvalidTokenAccount,
+    #[msg("Holder checkpoint is not initialized")]
+    CheckpointNotInitialized,
+    #[msg("Invalid holder checkpoint account")]
+    InvalidCheckpoint,
+    #[msg("Record date is older than the retained balance history")]
+    CheckpointPruned,
+    #[msg("Record date has not been declared")]
+    UnknownRecordDate,
+    #[msg("Allowlist does not belong to this mint")]
+    InvalidAllowlist,
+    #[msg("Volume usage account is not initialized")]
+    VolumeUsageNotInitialized,
+    #[msg("Invalid volume usage account")]
+    InvalidVolumeUsage,
+    #[msg("Holder count is not initialized")]
+    HolderCountNotInitialized,
+    #[msg("Invalid holder count account")]
+    InvalidHolderCount,
+    #[msg("Invalid custom modules registry")]
+    InvalidCustomModules,
+    #[msg("Insufficient token balance")]
+    InsufficientBalance,
+    #[msg("Holder position is not initialized")]
+    HolderPositionNotInitialized,
+    #[msg("Invalid holder position account")]
+    InvalidHolderPosition,
+    #[msg("Custom modules are registered but the account layout lacks their slots")]
+    CustomModuleSlotsMissing,
+    #[msg("Balance changed outside the transfer hook; call sync_checkpoint")]
+    CheckpointOutOfSync,
+    #[msg("Token account does not hold the hooked mint")]
+    TokenMintMismatch,
+    #[msg("Extra accounts do not match the ExtraAccountMetaList")]
+    ExtraAccountsMismatch,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct SnapshotsInitialized {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct RecordDateDeclared {
+    pub mint: Pubkey,
+    pub record_date_id: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+    pub declared_by: Pubkey,
+}
+
+#[event]
+pub struct CheckpointOpened {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct VolumeUsageOpened {
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+}
+
+#[event]
+pub struct HolderCountSet {
+    pub mint: Pubkey,
+    pub holders: u32,
+    pub non_accredited: u32,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct HolderPositionOpened {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct HolderPositionSet {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub old_balance: u64,
+    pub balance: u64,
+    pub accredited: bool,
+    pub authority: Pubkey,
+}
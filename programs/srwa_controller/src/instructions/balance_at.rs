@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(record_date_id: u64)]
+pub struct BalanceAt<'info> {
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [HolderCheckpoint::SEED_PREFIX, token_account.key().as_ref()],
+        bump = checkpoint.bump,
+        has_one = token_account,
+    )]
+    pub checkpoint: Account<'info, HolderCheckpoint>,
+
+    #[account(
+        seeds = [
+            RecordDate::SEED_PREFIX,
+            token_account.mint.as_ref(),
+            &record_date_id.to_le_bytes()
+        ],
+        bump = record_date.bump,
+    )]
+    pub record_date: Account<'info, RecordDate>,
+}
+
+pub fn handler(ctx: Context<BalanceAt>, record_date_id: u64) -> Result<u64> {
+    let balance = ctx
+        .accounts
+        .checkpoint
+        .balance_at(record_date_id, ctx.accounts.token_account.amount)?;
+
+    msg!(
+        "Balance of {} at record date {} (slot {}): {}",
+        ctx.accounts.token_account.key(),
+        record_date_id,
+        ctx.accounts.record_date.slot,
+        balance
+    );
+
+    Ok(balance)
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use srwa_factory::state::SRWAConfig;
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+pub struct DeclareRecordDate<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin
+            || authority.key() == srwa_config.roles.transfer_agent @ ControllerError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        mut,
+        seeds = [SnapshotRegistry::SEED_PREFIX, mint.key().as_ref()],
+        bump = snapshot_registry.bump,
+        has_one = mint,
+    )]
+    pub snapshot_registry: Account<'info, SnapshotRegistry>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RecordDate::INIT_SPACE,
+        seeds = [
+            RecordDate::SEED_PREFIX,
+            mint.key().as_ref(),
+            &(snapshot_registry.current_record_date_id + 1).to_le_bytes()
+        ],
+        bump
+    )]
+    pub record_date: Account<'info, RecordDate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DeclareRecordDate>) -> Result<()> {
+    let registry = &mut ctx.accounts.snapshot_registry;
+    let record_date = &mut ctx.accounts.record_date;
+    let clock = Clock::get()?;
+
+    let id = registry.current_record_date_id + 1;
+
+    record_date.mint = registry.mint;
+    record_date.id = id;
+    record_date.slot = clock.slot;
+    record_date.timestamp = clock.unix_timestamp;
+    record_date.declared_by = ctx.accounts.authority.key();
+    record_date.bump = ctx.bumps.record_date;
+
+    registry.current_record_date_id = id;
+    registry.last_record_slot = clock.slot;
+    registry.last_record_ts = clock.unix_timestamp;
+
+    emit!(RecordDateDeclared {
+        mint: registry.mint,
+        record_date_id: id,
+        slot: clock.slot,
+        timestamp: clock.unix_timestamp,
+        declared_by: record_date.declared_by,
+    });
+
+    msg!("Record date {} declared at slot {}", id, clock.slot);
+
+    Ok(())
+}
@@ -0,0 +1,564 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{create_account, transfer, CreateAccount, Transfer},
+};
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, pubkey_data::PubkeyData, seeds::Seed, state::ExtraAccountMetaList,
+};
+use compliance_modules::state::{InvestorProfile, SanctionedAddress};
+use identity_claims::state::topics;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+use srwa_factory::state::CustomModules;
+use crate::instructions::transfer_hook::CUSTOM_MODULES_INDEX;
+use crate::state::{HolderCount, HolderPosition, VolumeUsage};
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The mint account
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: ExtraAccountMetaList Account
+    #[account(
+        mut,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The list content is fixed by this program, so anyone may migrate a mint
+/// to the current layout.
+#[derive(Accounts)]
+pub struct UpdateExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The mint account
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: ExtraAccountMetaList Account
+    #[account(
+        mut,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// CHECK: CustomModules registry of the mint; may not exist yet
+    #[account(
+        seeds = [CustomModules::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+        seeds::program = srwa_factory::ID
+    )]
+    pub custom_modules: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Extra accounts resolved by Token-2022 for every transfer. The order must
+/// match the `*_INDEX` constants in `transfer_hook`. The CustomModules registry
+/// is always listed so the hook can see registered modules; the program slots
+/// are read from its data, so they are only listed once it exists.
+pub fn extra_account_metas(custom_modules: bool) -> Result<Vec<ExtraAccountMeta>> {
+    let mut metas = vec![
+        // Sender KYC Registry (index 0 after base accounts)
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: b"kyc".to_vec() },
+                Seed::AccountKey { index: 3 }, // authority (sender owner)
+            ],
+            false, // not signer
+            false, // not writable
+        )?,
+        // Recipient KYC Registry (index 1 after base accounts)
+        // Usamos o destination token account para extrair o owner
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: b"kyc".to_vec() },
+                Seed::AccountData {
+                    account_index: 2, // destination token account
+                    data_index: 32,   // owner field offset
+                    length: 32,       // pubkey length
+                },
+            ],
+            false, // not signer
+            false, // not writable
+        )?,
+        // Snapshot registry of the mint (index 2)
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: b"snapshots".to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+            ],
+            false,
+            false,
+        )?,
+        // Source holder checkpoint (index 3)
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: b"checkpoint".to_vec() },
+                Seed::AccountKey { index: 0 }, // source token account
+            ],
+            false,
+            true,
+        )?,
+        // Destination holder checkpoint (index 4)
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: b"checkpoint".to_vec() },
+                Seed::AccountKey { index: 2 }, // destination token account
+            ],
+            false,
+            true,
+        )?,
+        // compliance_modules program, owner of the allowlists (index 5)
+        ExtraAccountMeta::new_with_pubkey(&compliance_modules::ID, false, false)?,
+        // Account allowlist of the mint, e.g. AMM pool vaults (index 6)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10, // compliance_modules program (5 base accounts + index 5)
+            &[
+                Seed::Literal { bytes: b"account_allowlist".to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+            ],
+            false,
+            false,
+        )?,
+        // identity_claims program, owner of the residency claims (index 7)
+        ExtraAccountMeta::new_with_pubkey(&identity_claims::ID, false, false)?,
+        // Jurisdiction config of the mint (index 8)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10, // compliance_modules program
+            &[
+                Seed::Literal { bytes: b"jurisdiction".to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+            ],
+            false,
+            false,
+        )?,
+        // Residency claim of the source owner (index 9)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            12, // identity_claims program (5 base accounts + index 7)
+            &[
+                Seed::Literal { bytes: b"claim".to_vec() },
+                Seed::AccountData {
+                    account_index: 0, // source token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+                Seed::Literal { bytes: topics::RESIDENCY.to_le_bytes().to_vec() },
+            ],
+            false,
+            false,
+        )?,
+        // Residency claim of the destination owner (index 10)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            12, // identity_claims program
+            &[
+                Seed::Literal { bytes: b"claim".to_vec() },
+                Seed::AccountData {
+                    account_index: 2, // destination token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+                Seed::Literal { bytes: topics::RESIDENCY.to_le_bytes().to_vec() },
+            ],
+            false,
+            false,
+        )?,
+        // Mint sanctions marker of the source owner (index 11)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10, // compliance_modules program
+            &[
+                Seed::Literal { bytes: SanctionedAddress::SEED_PREFIX.to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+                Seed::AccountData {
+                    account_index: 0, // source token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+            ],
+            false,
+            false,
+        )?,
+        // Mint sanctions marker of the destination owner (index 12)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10, // compliance_modules program
+            &[
+                Seed::Literal { bytes: SanctionedAddress::SEED_PREFIX.to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+                Seed::AccountData {
+                    account_index: 2, // destination token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+            ],
+            false,
+            false,
+        )?,
+        // Platform sanctions marker of the source owner (index 13)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10, // compliance_modules program
+            &[
+                Seed::Literal { bytes: SanctionedAddress::SEED_PREFIX.to_vec() },
+                Seed::Literal { bytes: SanctionedAddress::PLATFORM_SCOPE.to_bytes().to_vec() },
+                Seed::AccountData {
+                    account_index: 0, // source token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+            ],
+            false,
+            false,
+        )?,
+        // Platform sanctions marker of the destination owner (index 14)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10, // compliance_modules program
+            &[
+                Seed::Literal { bytes: SanctionedAddress::SEED_PREFIX.to_vec() },
+                Seed::Literal { bytes: SanctionedAddress::PLATFORM_SCOPE.to_bytes().to_vec() },
+                Seed::AccountData {
+                    account_index: 2, // destination token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+            ],
+            false,
+            false,
+        )?,
+        // Lockup of the source owner (index 15)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10, // compliance_modules program
+            &[
+                Seed::Literal { bytes: b"lockup".to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+                Seed::AccountData {
+                    account_index: 0, // source token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+            ],
+            false,
+            false,
+        )?,
+        // Volume caps of the mint (index 16)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10, // compliance_modules program
+            &[
+                Seed::Literal { bytes: b"volume_caps".to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+            ],
+            false,
+            false,
+        )?,
+        // Volume usage of the whole mint (index 17)
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: VolumeUsage::SEED_PREFIX.to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+                Seed::Literal { bytes: VolumeUsage::MINT_TOTAL.to_bytes().to_vec() },
+            ],
+            false,
+            true,
+        )?,
+        // Volume usage of the source owner (index 18)
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: VolumeUsage::SEED_PREFIX.to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+                Seed::AccountData {
+                    account_index: 0, // source token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+            ],
+            false,
+            true,
+        )?,
+        // Transfer window of the mint (index 19)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10, // compliance_modules program
+            &[
+                Seed::Literal { bytes: b"transfer_window".to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+            ],
+            false,
+            false,
+        )?,
+        // Holder caps of the mint (index 20)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10, // compliance_modules program
+            &[
+                Seed::Literal { bytes: b"max_holders".to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+            ],
+            false,
+            false,
+        )?,
+        // Holder count of the mint (index 21)
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: HolderCount::SEED_PREFIX.to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+            ],
+            false,
+            true,
+        )?,
+        // ACCREDITED claim of the source owner (index 22)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            12, // identity_claims program
+            &[
+                Seed::Literal { bytes: b"claim".to_vec() },
+                Seed::AccountData {
+                    account_index: 0, // source token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+                Seed::Literal { bytes: topics::ACCREDITED.to_le_bytes().to_vec() },
+            ],
+            false,
+            false,
+        )?,
+        // ACCREDITED claim of the destination owner (index 23)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            12, // identity_claims program
+            &[
+                Seed::Literal { bytes: b"claim".to_vec() },
+                Seed::AccountData {
+                    account_index: 2, // destination token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+                Seed::Literal { bytes: topics::ACCREDITED.to_le_bytes().to_vec() },
+            ],
+            false,
+            false,
+        )?,
+        // Investor profile of the destination owner (index 24)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10, // compliance_modules program
+            &[
+                Seed::Literal { bytes: InvestorProfile::SEED_PREFIX.to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+                Seed::AccountData {
+                    account_index: 2, // destination token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+            ],
+            false,
+            false,
+        )?,
+        // Investor profile of the source owner (index 25)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10, // compliance_modules program
+            &[
+                Seed::Literal { bytes: InvestorProfile::SEED_PREFIX.to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+                Seed::AccountData {
+                    account_index: 0, // source token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+            ],
+            false,
+            false,
+        )?,
+        // srwa_factory program, owner of the SRWA config (index 26)
+        ExtraAccountMeta::new_with_pubkey(&srwa_factory::ID, false, false)?,
+        // SRWA config of the mint, source of the trusted issuers (index 27)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            31, // srwa_factory program (5 base accounts + index 26)
+            &[
+                Seed::Literal { bytes: b"srwa_config".to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+            ],
+            false,
+            false,
+        )?,
+        // Accreditation requirement of the mint (index 28)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10, // compliance_modules program
+            &[
+                Seed::Literal { bytes: b"accredited".to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+            ],
+            false,
+            false,
+        )?,
+        // KYB claim of the destination owner (index 29)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            12, // identity_claims program
+            &[
+                Seed::Literal { bytes: b"claim".to_vec() },
+                Seed::AccountData {
+                    account_index: 2, // destination token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+                Seed::Literal { bytes: topics::KYB.to_le_bytes().to_vec() },
+            ],
+            false,
+            false,
+        )?,
+        // Owner of the source token account, to recognise program vaults (index 30)
+        ExtraAccountMeta::new_with_pubkey_data(
+            &PubkeyData::AccountData {
+                account_index: 0, // source token account
+                data_index: 32,   // owner field offset
+            },
+            false,
+            false,
+        )?,
+        // Owner of the destination token account (index 31)
+        ExtraAccountMeta::new_with_pubkey_data(
+            &PubkeyData::AccountData {
+                account_index: 2, // destination token account
+                data_index: 32,   // owner field offset
+            },
+            false,
+            false,
+        )?,
+        // Program allowlist of the mint, e.g. lending and AMM programs (index 32)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            10, // compliance_modules program
+            &[
+                Seed::Literal { bytes: b"program_allowlist".to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+            ],
+            false,
+            false,
+        )?,
+        // Holder position of the source owner (index 33)
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: HolderPosition::SEED_PREFIX.to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+                Seed::AccountData {
+                    account_index: 0, // source token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+            ],
+            false,
+            true,
+        )?,
+        // Holder position of the destination owner (index 34)
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: HolderPosition::SEED_PREFIX.to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+                Seed::AccountData {
+                    account_index: 2, // destination token account
+                    data_index: 32,   // owner field offset
+                    length: 32,
+                },
+            ],
+            false,
+            true,
+        )?,
+        // Custom compliance module registry (index 35)
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            31, // srwa_factory program
+            &[
+                Seed::Literal { bytes: CustomModules::SEED_PREFIX.to_vec() },
+                Seed::AccountKey { index: 1 }, // mint
+            ],
+            false,
+            false,
+        )?,
+    ];
+
+    if custom_modules {
+        // Module programs, one per registry slot (index 36..)
+        for slot in 0..CustomModules::MAX_MODULES {
+            metas.push(ExtraAccountMeta::new_with_pubkey_data(
+                &PubkeyData::AccountData {
+                    account_index: 5 + CUSTOM_MODULES_INDEX as u8,
+                    data_index: CustomModules::slot_offset(slot) as u8,
+                },
+                false,
+                false,
+            )?);
+        }
+    }
+
+    Ok(metas)
+}
+
+pub fn handler(ctx: Context<InitializeExtraAccountMetaList>) -> Result<()> {
+    let account_metas = extra_account_metas(false)?;
+
+    // Initialize the ExtraAccountMetaList account
+    let account_size = ExtraAccountMetaList::size_of(account_metas.len())?;
+    let lamports = Rent::get()?.minimum_balance(account_size);
+
+    let mint = ctx.accounts.mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"extra-account-metas",
+        mint.as_ref(),
+        &[ctx.bumps.extra_account_meta_list],
+    ]];
+
+    // Create the account
+    create_account(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.extra_account_meta_list.to_account_info(),
+            },
+        )
+        .with_signer(signer_seeds),
+        lamports,
+        account_size as u64,
+        ctx.program_id,
+    )?;
+
+    // Initialize the account data
+    let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &account_metas)?;
+
+    msg!("✅ ExtraAccountMetaList initialized with {} extra accounts", account_metas.len());
+    Ok(())
+}
+
+pub fn update_handler(ctx: Context<UpdateExtraAccountMetaList>) -> Result<()> {
+    let custom_modules = &ctx.accounts.custom_modules;
+    let account_metas = extra_account_metas(
+        custom_modules.owner == &srwa_factory::ID && !custom_modules.data_is_empty(),
+    )?;
+    let list_info = ctx.accounts.extra_account_meta_list.to_account_info();
+
+    let account_size = ExtraAccountMetaList::size_of(account_metas.len())?;
+    let required_lamports = Rent::get()?.minimum_balance(account_size);
+
+    // Top up rent before growing the account
+    if required_lamports > list_info.lamports() {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: list_info.clone(),
+                },
+            ),
+            required_lamports - list_info.lamports(),
+        )?;
+    }
+
+    if list_info.data_len() != account_size {
+        list_info.resize(account_size)?;
+    }
+
+    let mut data = list_info.try_borrow_mut_data()?;
+    ExtraAccountMetaList::update::<ExecuteInstruction>(&mut data, &account_metas)?;
+
+    msg!("✅ ExtraAccountMetaList updated to {} extra accounts", account_metas.len());
+    Ok(())
+}
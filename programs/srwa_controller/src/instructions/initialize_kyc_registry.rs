@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeKYCRegistry<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: User to initialize KYC for
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + 32 + 1 + 1 + 8 + 1, // discriminator + pubkey + 2 bools + i64 + bump
+        seeds = [b"kyc", user.key().as_ref()],
+        bump
+    )]
+    pub kyc_registry: Account<'info, KYCRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeKYCRegistry>,
+    kyc_completed: bool,
+    is_active: bool,
+) -> Result<()> {
+    let kyc_registry = &mut ctx.accounts.kyc_registry;
+
+    kyc_registry.user = ctx.accounts.user.key();
+    kyc_registry.kyc_completed = kyc_completed;
+    kyc_registry.is_active = is_active;
+    kyc_registry.updated_at = Clock::get()?.unix_timestamp;
+    kyc_registry.bump = ctx.bumps.kyc_registry;
+
+    msg!("✅ KYC Registry initialized for {}", ctx.accounts.user.key());
+    msg!("  - KYC completed: {}", kyc_completed);
+    msg!("  - Is active: {}", is_active);
+
+    Ok(())
+}
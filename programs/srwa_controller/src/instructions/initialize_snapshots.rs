@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use srwa_factory::state::SRWAConfig;
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+pub struct InitializeSnapshots<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin @ ControllerError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SnapshotRegistry::INIT_SPACE,
+        seeds = [SnapshotRegistry::SEED_PREFIX, mint.key().as_ref()],
+        bump
+    )]
+    pub snapshot_registry: Account<'info, SnapshotRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeSnapshots>) -> Result<()> {
+    let registry = &mut ctx.accounts.snapshot_registry;
+
+    registry.mint = ctx.accounts.mint.key();
+    registry.current_record_date_id = 0;
+    registry.last_record_slot = 0;
+    registry.last_record_ts = 0;
+    registry.bump = ctx.bumps.snapshot_registry;
+
+    emit!(SnapshotsInitialized {
+        mint: registry.mint,
+        authority: ctx.accounts.authority.key(),
+    });
+
+    msg!("Snapshots enabled for mint {}", registry.mint);
+
+    Ok(())
+}
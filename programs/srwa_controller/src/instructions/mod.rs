@@ -0,0 +1,30 @@
+pub mod initialize_extra_account_meta_list;
+pub mod initialize_kyc_registry;
+pub mod update_kyc_status;
+pub mod transfer_hook;
+pub mod initialize_snapshots;
+pub mod declare_record_date;
+pub mod open_checkpoint;
+pub mod sync_checkpoint;
+pub mod balance_at;
+pub mod open_volume_usage;
+pub mod volume_capacity;
+pub mod set_holder_count;
+pub mod open_holder_position;
+pub mod set_holder_position;
+pub mod simulate_transfer;
+
+pub use initialize_extra_account_meta_list::*;
+pub use initialize_kyc_registry::*;
+pub use update_kyc_status::*;
+pub use transfer_hook::*;
+pub use initialize_snapshots::*;
+pub use declare_record_date::*;
+pub use open_checkpoint::*;
+pub use sync_checkpoint::*;
+pub use balance_at::*;
+pub use open_volume_usage::*;
+pub use volume_capacity::*;
+pub use set_holder_count::*;
+pub use open_holder_position::*;
+pub use set_holder_position::*;
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::{state::*, events::*};
+
+#[derive(Accounts)]
+pub struct OpenCheckpoint<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + HolderCheckpoint::INIT_SPACE,
+        seeds = [HolderCheckpoint::SEED_PREFIX, token_account.key().as_ref()],
+        bump
+    )]
+    pub checkpoint: Account<'info, HolderCheckpoint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenCheckpoint>) -> Result<()> {
+    let token_account = &ctx.accounts.token_account;
+    let checkpoint = &mut ctx.accounts.checkpoint;
+    let clock = Clock::get()?;
+
+    // No entry is needed yet: while the snapshot registry exists this account
+    // cannot have moved through the hook without a checkpoint, so its current
+    // balance is also its balance at every record date declared so far.
+    checkpoint.mint = token_account.mint;
+    checkpoint.token_account = token_account.key();
+    checkpoint.owner = token_account.owner;
+    checkpoint.entries = vec![];
+    checkpoint.pruned_through = 0;
+    checkpoint.last_update_slot = clock.slot;
+    checkpoint.last_update_ts = clock.unix_timestamp;
+    checkpoint.tracked_balance = token_account.amount;
+    checkpoint.tracked_record_date_id = 0;
+    checkpoint.bump = ctx.bumps.checkpoint;
+
+    emit!(CheckpointOpened {
+        mint: checkpoint.mint,
+        token_account: checkpoint.token_account,
+        owner: checkpoint.owner,
+    });
+
+    Ok(())
+}
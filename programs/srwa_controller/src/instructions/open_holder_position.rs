@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::{state::*, events::*};
+
+/// Permissionless, like `open_volume_usage`. The position starts empty, so it
+/// is only correct for owners that hold nothing yet; existing holders are
+/// seeded by the transfer agent with `set_holder_position`.
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct OpenHolderPosition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + HolderPosition::INIT_SPACE,
+        seeds = [HolderPosition::SEED_PREFIX, mint.key().as_ref(), owner.as_ref()],
+        bump
+    )]
+    pub holder_position: Account<'info, HolderPosition>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenHolderPosition>, owner: Pubkey) -> Result<()> {
+    let position = &mut ctx.accounts.holder_position;
+    position.mint = ctx.accounts.mint.key();
+    position.owner = owner;
+    position.balance = 0;
+    position.counted = false;
+    position.accredited = false;
+    position.bump = ctx.bumps.holder_position;
+
+    emit!(HolderPositionOpened {
+        mint: position.mint,
+        owner,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::{state::*, events::*};
+
+/// Permissionless, like `open_checkpoint`. Pass `VolumeUsage::MINT_TOTAL` as
+/// the holder to open the per-mint aggregate required once caps are set.
+#[derive(Accounts)]
+#[instruction(holder: Pubkey)]
+pub struct OpenVolumeUsage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + VolumeUsage::INIT_SPACE,
+        seeds = [VolumeUsage::SEED_PREFIX, mint.key().as_ref(), holder.as_ref()],
+        bump
+    )]
+    pub volume_usage: Account<'info, VolumeUsage>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenVolumeUsage>, holder: Pubkey) -> Result<()> {
+    let usage = &mut ctx.accounts.volume_usage;
+    usage.mint = ctx.accounts.mint.key();
+    usage.holder = holder;
+    usage.last_day = VolumeUsage::day_of(Clock::get()?.unix_timestamp);
+    usage.buckets = [0; 30];
+    usage.bump = ctx.bumps.volume_usage;
+
+    emit!(VolumeUsageOpened {
+        mint: usage.mint,
+        holder,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use srwa_factory::state::SRWAConfig;
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+pub struct SetHolderCount<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin
+            || authority.key() == srwa_config.roles.transfer_agent @ ControllerError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + HolderCount::INIT_SPACE,
+        seeds = [HolderCount::SEED_PREFIX, mint.key().as_ref()],
+        bump
+    )]
+    pub holder_count: Account<'info, HolderCount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Start or correct the count from the issuer's cap table; the hook keeps it
+/// up to date from then on
+pub fn handler(ctx: Context<SetHolderCount>, holders: u32, non_accredited: u32) -> Result<()> {
+    let holder_count = &mut ctx.accounts.holder_count;
+
+    holder_count.mint = ctx.accounts.mint.key();
+    holder_count.holders = holders;
+    holder_count.non_accredited = non_accredited.min(holders);
+    holder_count.bump = ctx.bumps.holder_count;
+
+    emit!(HolderCountSet {
+        mint: holder_count.mint,
+        holders,
+        non_accredited: holder_count.non_accredited,
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use srwa_factory::state::SRWAConfig;
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey)]
+pub struct SetHolderPosition<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin
+            || authority.key() == srwa_config.roles.transfer_agent @ ControllerError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + HolderPosition::INIT_SPACE,
+        seeds = [HolderPosition::SEED_PREFIX, mint.key().as_ref(), owner.as_ref()],
+        bump
+    )]
+    pub holder_position: Account<'info, HolderPosition>,
+
+    /// CHECK: Mint's HolderCount, updated once it is initialized
+    #[account(
+        mut,
+        seeds = [HolderCount::SEED_PREFIX, mint.key().as_ref()],
+        bump,
+    )]
+    pub holder_count: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Seed or correct an owner's position from the issuer's cap table (e.g.
+/// after a mint or burn), moving the HolderCount along with it
+pub fn handler(
+    ctx: Context<SetHolderPosition>,
+    owner: Pubkey,
+    balance: u64,
+    accredited: bool,
+) -> Result<()> {
+    let position = &mut ctx.accounts.holder_position;
+    let old_balance = position.balance;
+
+    let count_info = ctx.accounts.holder_count.to_account_info();
+    if count_info.owner == &crate::ID && !count_info.data_is_empty() {
+        let mut count = HolderCount::try_deserialize(&mut &count_info.try_borrow_data()?[..])?;
+        if position.counted {
+            count.leave(position.accredited);
+        }
+        if balance > 0 {
+            count.join(accredited);
+        }
+        count.try_serialize(&mut &mut count_info.try_borrow_mut_data()?[..])?;
+    }
+
+    position.mint = ctx.accounts.mint.key();
+    position.owner = owner;
+    position.balance = balance;
+    position.counted = balance > 0;
+    position.accredited = accredited;
+    position.bump = ctx.bumps.holder_position;
+
+    emit!(HolderPositionSet {
+        mint: position.mint,
+        owner,
+        old_balance,
+        balance,
+        accredited,
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
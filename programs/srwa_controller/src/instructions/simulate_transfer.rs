@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use crate::{errors::*, state::*};
+use super::transfer_hook::*;
+
+/// Evaluate the transfer hook's rules against the current (pre-transfer)
+/// balances. Nothing is written: volume, holder positions and counts are
+/// projected in memory instead of recorded.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    amount: u64,
+) -> Result<TransferSimulation> {
+    let remaining_accounts = ctx.remaining_accounts;
+    require!(
+        remaining_accounts.len() >= 2,
+        ControllerError::MissingKYCAccounts
+    );
+    check_token_accounts(&ctx)?;
+
+    let source_balance = token_account_amount(&ctx.accounts.source_token)?;
+    let moved = if ctx.accounts.source_token.key() == ctx.accounts.destination_token.key() {
+        0
+    } else {
+        amount
+    };
+
+    let (source_allowlisted, destination_allowlisted) = venue_accounts(&ctx)?;
+    let trusted_issuers = load_trusted_issuers(remaining_accounts, &ctx.accounts.mint.key())?;
+
+    let mut results = vec![
+        outcome(ComplianceCheck::Balance, check_balance(source_balance, amount)),
+        outcome(
+            ComplianceCheck::SenderKyc,
+            if source_allowlisted {
+                Ok(())
+            } else {
+                validate_kyc_account(&remaining_accounts[SENDER_KYC_INDEX], "Sender")
+            },
+        ),
+        outcome(
+            ComplianceCheck::RecipientKyc,
+            if destination_allowlisted {
+                Ok(())
+            } else {
+                validate_kyc_account(&remaining_accounts[RECIPIENT_KYC_INDEX], "Recipient")
+            },
+        ),
+        outcome(ComplianceCheck::TransferWindow, check_transfer_window(&ctx)),
+        outcome(ComplianceCheck::Sanctions, check_sanctions(&ctx)),
+        outcome(
+            ComplianceCheck::Jurisdiction,
+            check_jurisdiction(&ctx, source_allowlisted, destination_allowlisted),
+        ),
+        outcome(
+            ComplianceCheck::Lockup,
+            check_lockup(&ctx, source_balance.saturating_sub(moved)),
+        ),
+        outcome(
+            ComplianceCheck::Accreditation,
+            check_accreditation(&ctx, &trusted_issuers, destination_allowlisted),
+        ),
+        outcome(
+            ComplianceCheck::VolumeCaps,
+            project_volume(&ctx, amount, source_allowlisted).map(|_| ()),
+        ),
+        outcome(
+            ComplianceCheck::HolderCaps,
+            project_holders(&ctx, amount, &trusted_issuers, source_allowlisted, destination_allowlisted)
+                .map(|_| ()),
+        ),
+        outcome(
+            ComplianceCheck::InvestorLimits,
+            check_investor_limits(
+                &ctx,
+                project_destination_position(&ctx, amount, destination_allowlisted)?.as_ref(),
+                destination_allowlisted,
+                amount,
+            ),
+        ),
+    ];
+    let approved = results.iter().all(|result| result.passed);
+
+    // A rejecting custom module aborts the whole instruction, so modules only
+    // run once every built-in rule passed; their error is the simulation error.
+    // Otherwise they are reported as not evaluated.
+    if remaining_accounts.len() > CUSTOM_MODULES_INDEX {
+        if approved {
+            check_custom_modules(&ctx, amount)?;
+            results.push(outcome(ComplianceCheck::CustomModules, Ok(())));
+        } else {
+            results.push(CheckResult {
+                check: ComplianceCheck::CustomModules,
+                evaluated: false,
+                passed: false,
+                reason: 0,
+            });
+        }
+    }
+
+    msg!("Transfer simulation: approved = {}", approved);
+
+    Ok(TransferSimulation {
+        approved,
+        checks: results,
+    })
+}
+
+fn outcome(check: ComplianceCheck, result: Result<()>) -> CheckResult {
+    let reason = match result {
+        Ok(()) => 0,
+        Err(Error::AnchorError(error)) => u64::from(error.error_code_number),
+        Err(Error::ProgramError(error)) => u64::from(error.program_error.clone()),
+    };
+
+    CheckResult {
+        check,
+        evaluated: true,
+        passed: reason == 0,
+        reason,
+    }
+}
+
+/// Receiving owner's position after the transfer
+fn project_destination_position<'info>(
+    ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    amount: u64,
+    destination_allowlisted: bool,
+) -> Result<Option<HolderPosition>> {
+    let remaining_accounts = ctx.remaining_accounts;
+    if destination_allowlisted || remaining_accounts.len() <= DESTINATION_POSITION_INDEX {
+        return Ok(None);
+    }
+
+    let owner = token_account_owner(&ctx.accounts.destination_token)?;
+    let position = load_position(&remaining_accounts[DESTINATION_POSITION_INDEX], &ctx.accounts.mint.key(), &owner)?;
+    let moved = if owner == token_account_owner(&ctx.accounts.source_token)? { 0 } else { amount };
+
+    Ok(position.map(|mut position| {
+        position.balance = position.balance.saturating_add(moved);
+        position
+    }))
+}
+
+fn check_balance(source_balance: u64, amount: u64) -> Result<()> {
+    require!(source_balance >= amount, ControllerError::InsufficientBalance);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::*;
+
+/// Mints and burns do not invoke the transfer hook. Issuer tooling calls this
+/// in the same transaction before the mint/burn, so the pre-change balance is
+/// captured for the current record date, and after it, so the checkpoint
+/// tracks the new balance. A change synced only afterwards makes the record
+/// dates declared since the last sync unanswerable.
+#[derive(Accounts)]
+pub struct SyncCheckpoint<'info> {
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [SnapshotRegistry::SEED_PREFIX, token_account.mint.as_ref()],
+        bump = snapshot_registry.bump,
+    )]
+    pub snapshot_registry: Account<'info, SnapshotRegistry>,
+
+    #[account(
+        mut,
+        seeds = [HolderCheckpoint::SEED_PREFIX, token_account.key().as_ref()],
+        bump = checkpoint.bump,
+        has_one = token_account,
+    )]
+    pub checkpoint: Account<'info, HolderCheckpoint>,
+}
+
+pub fn handler(ctx: Context<SyncCheckpoint>) -> Result<()> {
+    let current_balance = ctx.accounts.token_account.amount;
+    let current_record_date_id = ctx.accounts.snapshot_registry.current_record_date_id;
+    let checkpoint = &mut ctx.accounts.checkpoint;
+    let clock = Clock::get()?;
+
+    checkpoint.track(current_record_date_id, current_balance, current_balance);
+    checkpoint.last_update_slot = clock.slot;
+    checkpoint.last_update_ts = clock.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,918 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions},
+    state::{Account as SplTokenAccount, Mint as SplMint},
+};
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::{ExecuteInstruction, TransferHookInstruction};
+use spl_type_length_value::state::TlvStateBorrowed;
+use compliance_modules::{checks, state::{AccountAllowlist, JurisdictionConfig, ProgramAllowlist}};
+use solana_curve25519::edwards::{validate_edwards, PodEdwardsPoint};
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+use srwa_factory::state::{custom_module, CustomModules, TrustedIssuerEntry};
+use crate::{errors::*, state::*};
+
+// Positions of the extra accounts in remaining_accounts
+// (see `initialize_extra_account_meta_list::extra_account_metas`)
+pub const SENDER_KYC_INDEX: usize = 0;
+pub const RECIPIENT_KYC_INDEX: usize = 1;
+pub const SNAPSHOT_REGISTRY_INDEX: usize = 2;
+pub const SOURCE_CHECKPOINT_INDEX: usize = 3;
+pub const DESTINATION_CHECKPOINT_INDEX: usize = 4;
+pub const COMPLIANCE_PROGRAM_INDEX: usize = 5;
+pub const ACCOUNT_ALLOWLIST_INDEX: usize = 6;
+pub const IDENTITY_PROGRAM_INDEX: usize = 7;
+pub const JURISDICTION_CONFIG_INDEX: usize = 8;
+pub const SOURCE_RESIDENCY_INDEX: usize = 9;
+pub const DESTINATION_RESIDENCY_INDEX: usize = 10;
+pub const SOURCE_SANCTIONS_INDEX: usize = 11;
+pub const DESTINATION_SANCTIONS_INDEX: usize = 12;
+pub const SOURCE_PLATFORM_SANCTIONS_INDEX: usize = 13;
+pub const DESTINATION_PLATFORM_SANCTIONS_INDEX: usize = 14;
+pub const SOURCE_LOCKUP_INDEX: usize = 15;
+pub const VOLUME_CAPS_INDEX: usize = 16;
+pub const MINT_VOLUME_USAGE_INDEX: usize = 17;
+pub const SOURCE_VOLUME_USAGE_INDEX: usize = 18;
+pub const TRANSFER_WINDOW_INDEX: usize = 19;
+pub const MAX_HOLDERS_INDEX: usize = 20;
+pub const HOLDER_COUNT_INDEX: usize = 21;
+pub const SOURCE_ACCREDITED_INDEX: usize = 22;
+pub const DESTINATION_ACCREDITED_INDEX: usize = 23;
+pub const DESTINATION_PROFILE_INDEX: usize = 24;
+pub const SOURCE_PROFILE_INDEX: usize = 25;
+pub const FACTORY_PROGRAM_INDEX: usize = 26;
+pub const SRWA_CONFIG_INDEX: usize = 27;
+pub const ACCREDITED_CONFIG_INDEX: usize = 28;
+pub const DESTINATION_KYB_INDEX: usize = 29;
+pub const SOURCE_OWNER_INDEX: usize = 30;
+pub const DESTINATION_OWNER_INDEX: usize = 31;
+pub const PROGRAM_ALLOWLIST_INDEX: usize = 32;
+pub const SOURCE_POSITION_INDEX: usize = 33;
+pub const DESTINATION_POSITION_INDEX: usize = 34;
+pub const CUSTOM_MODULES_INDEX: usize = 35;
+// Only present once the mint has a CustomModules registry
+pub const CUSTOM_MODULE_PROGRAMS_INDEX: usize = 36;
+
+// srwa_factory checks the layout size before registering a module
+const _: () = assert!(
+    CUSTOM_MODULE_PROGRAMS_INDEX + CustomModules::MAX_MODULES == CustomModules::HOOK_EXTRA_METAS
+);
+
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    /// CHECK: Source token account; its mint is checked in `check_token_accounts`
+    #[account(owner = spl_token_2022::ID @ ControllerError::InvalidTokenAccount)]
+    pub source_token: UncheckedAccount<'info>,
+
+    /// CHECK: Mint
+    #[account(owner = spl_token_2022::ID @ ControllerError::InvalidTokenAccount)]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Destination token account; its mint is checked in `check_token_accounts`
+    #[account(owner = spl_token_2022::ID @ ControllerError::InvalidTokenAccount)]
+    pub destination_token: UncheckedAccount<'info>,
+
+    /// CHECK: Authority
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: ExtraAccountMetaList
+    #[account(
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    amount: u64,
+) -> Result<()> {
+    msg!("🔒 Transfer Hook: Validating KYC for {} tokens", amount);
+
+    // Get remaining accounts (KYC registries passed via ExtraAccountMetaList)
+    let remaining_accounts = ctx.remaining_accounts;
+
+    require!(
+        remaining_accounts.len() >= 2,
+        ControllerError::MissingKYCAccounts
+    );
+
+    check_token_accounts(&ctx)?;
+    check_extra_accounts(&ctx, amount)?;
+
+    let sender_kyc = &remaining_accounts[SENDER_KYC_INDEX];
+    let recipient_kyc = &remaining_accounts[RECIPIENT_KYC_INDEX];
+
+    msg!("👤 Sender KYC: {}", sender_kyc.key());
+    msg!("👤 Recipient KYC: {}", recipient_kyc.key());
+
+    // Venue accounts (AMM pool vaults, lending reserves) have no KYC record of
+    // their own; the counterparty of a swap or deposit is still checked
+    let (source_allowlisted, destination_allowlisted) = venue_accounts(&ctx)?;
+
+    // Validate sender KYC
+    if source_allowlisted {
+        msg!("  Sender is an allowlisted venue account");
+    } else {
+        validate_kyc_account(sender_kyc, "Sender")?;
+    }
+
+    // Validate recipient KYC
+    if destination_allowlisted {
+        msg!("  Recipient is an allowlisted venue account");
+    } else {
+        validate_kyc_account(recipient_kyc, "Recipient")?;
+    }
+
+    check_transfer_window(&ctx)?;
+    check_sanctions(&ctx)?;
+    check_jurisdiction(&ctx, source_allowlisted, destination_allowlisted)?;
+    check_lockup(&ctx, token_account_amount(&ctx.accounts.source_token)?)?;
+
+    // Claims only count when issued by one of the mint's trusted issuers
+    let trusted_issuers = load_trusted_issuers(remaining_accounts, &ctx.accounts.mint.key())?;
+    check_accreditation(&ctx, &trusted_issuers, destination_allowlisted)?;
+    check_custom_modules(&ctx, amount)?;
+
+    // Everything below mutates state, so it must only run inside a real transfer
+    assert_is_transferring(&ctx.accounts.source_token)?;
+
+    update_checkpoints(&ctx, amount)?;
+    record_volume(&ctx, amount, source_allowlisted)?;
+    let holders =
+        update_holder_positions(&ctx, amount, &trusted_issuers, source_allowlisted, destination_allowlisted)?;
+    check_investor_limits(&ctx, holders.destination.as_ref(), destination_allowlisted, 0)?;
+
+    msg!("✅ Transfer approved - Both parties have active KYC");
+    Ok(())
+}
+
+// Validate KYC by reading KYC Registry account
+pub fn validate_kyc_account(account: &AccountInfo, label: &str) -> Result<()> {
+    // Verify account has data
+    let data = account.try_borrow_data()?;
+
+    require!(
+        data.len() >= 8 + 43, // discriminator + KYCRegistry size
+        ControllerError::InvalidKYCAccount
+    );
+
+    // Deserialize KYCRegistry (skip 8-byte discriminator)
+    let kyc_registry: KYCRegistry = AnchorDeserialize::deserialize(&mut &data[8..])?;
+
+    msg!("  {} KYC status:", label);
+    msg!("    - Completed: {}", kyc_registry.kyc_completed);
+    msg!("    - Active: {}", kyc_registry.is_active);
+
+    // Validate KYC completed and user is active
+    require!(
+        kyc_registry.kyc_completed,
+        ControllerError::KYCNotCompleted
+    );
+
+    require!(
+        kyc_registry.is_active,
+        ControllerError::UserNotActive
+    );
+
+    Ok(())
+}
+
+/// Whether the source and destination token accounts are venue accounts
+pub(crate) fn venue_accounts<'info>(
+    ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>,
+) -> Result<(bool, bool)> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let mint = ctx.accounts.mint.key();
+    let allowlist = load_account_allowlist(remaining_accounts, &mint)?;
+    let program_allowlist = load_program_allowlist(remaining_accounts, &mint)?;
+
+    Ok((
+        is_venue_account(
+            &ctx.accounts.source_token,
+            remaining_accounts.get(SOURCE_OWNER_INDEX),
+            allowlist.as_ref(),
+            program_allowlist.as_ref(),
+        )?,
+        is_venue_account(
+            &ctx.accounts.destination_token,
+            remaining_accounts.get(DESTINATION_OWNER_INDEX),
+            allowlist.as_ref(),
+            program_allowlist.as_ref(),
+        )?,
+    ))
+}
+
+/// Read the mint's AccountAllowlist, if the mint uses the current layout and
+/// one has been created
+pub fn load_account_allowlist(
+    remaining_accounts: &[AccountInfo],
+    mint: &Pubkey,
+) -> Result<Option<AccountAllowlist>> {
+    let Some(allowlist_info) = remaining_accounts.get(ACCOUNT_ALLOWLIST_INDEX) else {
+        return Ok(None);
+    };
+    if allowlist_info.owner != &compliance_modules::ID || allowlist_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let allowlist = AccountAllowlist::try_deserialize(&mut &allowlist_info.try_borrow_data()?[..])?;
+    require_keys_eq!(allowlist.mint, *mint, ControllerError::InvalidAllowlist);
+
+    Ok(Some(allowlist))
+}
+
+/// Read the mint's ProgramAllowlist, if the mint uses the current layout and
+/// one has been created
+pub fn load_program_allowlist(
+    remaining_accounts: &[AccountInfo],
+    mint: &Pubkey,
+) -> Result<Option<ProgramAllowlist>> {
+    let Some(allowlist_info) = remaining_accounts.get(PROGRAM_ALLOWLIST_INDEX) else {
+        return Ok(None);
+    };
+    if allowlist_info.owner != &compliance_modules::ID || allowlist_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let allowlist = ProgramAllowlist::try_deserialize(&mut &allowlist_info.try_borrow_data()?[..])?;
+    require_keys_eq!(allowlist.mint, *mint, ControllerError::InvalidAllowlist);
+
+    Ok(Some(allowlist))
+}
+
+/// A venue account is either listed in the AccountAllowlist or owned by a PDA
+/// of an allowlisted program. The PDA must be an account held by that program
+/// (pool state, reserve, vault authority with data); the off-curve check keeps
+/// a keypair assigned to the program from passing as one.
+pub fn is_venue_account(
+    token_account: &AccountInfo,
+    owner_info: Option<&AccountInfo>,
+    allowlist: Option<&AccountAllowlist>,
+    program_allowlist: Option<&ProgramAllowlist>,
+) -> Result<bool> {
+    if allowlist.is_some_and(|list| list.contains(token_account.key)) {
+        return Ok(true);
+    }
+    let (Some(owner_info), Some(program_allowlist)) = (owner_info, program_allowlist) else {
+        return Ok(false);
+    };
+
+    Ok(owner_info.key() == token_account_owner(token_account)?
+        && program_allowlist.contains(owner_info.owner)
+        && !validate_edwards(&PodEdwardsPoint(owner_info.key().to_bytes())))
+}
+
+/// Both token accounts must hold the hooked mint
+pub(crate) fn check_token_accounts<'info>(
+    ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>,
+) -> Result<()> {
+    let mint = ctx.accounts.mint.key();
+    require_keys_eq!(
+        token_account_mint(&ctx.accounts.source_token)?,
+        mint,
+        ControllerError::TokenMintMismatch
+    );
+    require_keys_eq!(
+        token_account_mint(&ctx.accounts.destination_token)?,
+        mint,
+        ControllerError::TokenMintMismatch
+    );
+    Ok(())
+}
+
+/// The extra accounts must be exactly the ones the mint's ExtraAccountMetaList
+/// resolves to, so a caller cannot substitute its own state accounts
+fn check_extra_accounts<'info>(
+    ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let mut account_infos = ctx.accounts.to_account_infos();
+    account_infos.extend(ctx.remaining_accounts.iter().cloned());
+
+    let data = ctx.accounts.extra_account_meta_list.try_borrow_data()?;
+    let state = TlvStateBorrowed::unpack(&data)
+        .map_err(|_| ControllerError::ExtraAccountsMismatch)?;
+    let extra_metas = ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&state)
+        .map_err(|_| ControllerError::ExtraAccountsMismatch)?
+        .data()
+        .len();
+    require!(
+        ctx.remaining_accounts.len() == extra_metas,
+        ControllerError::ExtraAccountsMismatch
+    );
+
+    ExtraAccountMetaList::check_account_infos::<ExecuteInstruction>(
+        &account_infos,
+        &TransferHookInstruction::Execute { amount }.pack(),
+        &crate::ID,
+        &data,
+    )
+    .map_err(|_| ControllerError::ExtraAccountsMismatch)?;
+
+    Ok(())
+}
+
+/// Token-2022 flags the source account as `transferring` only for the
+/// duration of the hook CPI, so direct calls cannot forge balance changes.
+fn assert_is_transferring(source_token: &AccountInfo) -> Result<()> {
+    let data = source_token.try_borrow_data()?;
+    let account = StateWithExtensions::<SplTokenAccount>::unpack(&data)
+        .map_err(|_| ControllerError::InvalidTokenAccount)?;
+    let extension = account
+        .get_extension::<TransferHookAccount>()
+        .map_err(|_| ControllerError::NotTransferring)?;
+
+    require!(
+        bool::from(extension.transferring),
+        ControllerError::NotTransferring
+    );
+
+    Ok(())
+}
+
+/// Read the (post-transfer) balance of a Token-2022 account
+pub fn token_account_amount(token_account: &AccountInfo) -> Result<u64> {
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<SplTokenAccount>::unpack(&data)
+        .map_err(|_| ControllerError::InvalidTokenAccount)?;
+    Ok(account.base.amount)
+}
+
+/// Read the mint of a Token-2022 account
+pub fn token_account_mint(token_account: &AccountInfo) -> Result<Pubkey> {
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<SplTokenAccount>::unpack(&data)
+        .map_err(|_| ControllerError::InvalidTokenAccount)?;
+    Ok(account.base.mint)
+}
+
+/// Read the owner of a Token-2022 account
+pub fn token_account_owner(token_account: &AccountInfo) -> Result<Pubkey> {
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<SplTokenAccount>::unpack(&data)
+        .map_err(|_| ControllerError::InvalidTokenAccount)?;
+    Ok(account.base.owner)
+}
+
+pub(crate) fn check_transfer_window<'info>(ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>) -> Result<()> {
+    let Some(window_info) = ctx.remaining_accounts.get(TRANSFER_WINDOW_INDEX) else {
+        // Mint still uses an older account layout
+        return Ok(());
+    };
+
+    checks::check_transfer_window(window_info, &ctx.accounts.mint.key(), Clock::get()?.unix_timestamp)
+}
+
+/// Reject transfers from or to a wallet on the mint's or the platform's
+/// sanctions list
+pub(crate) fn check_sanctions<'info>(ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    if remaining_accounts.len() <= DESTINATION_PLATFORM_SANCTIONS_INDEX {
+        // Mint still uses an older account layout
+        return Ok(());
+    }
+
+    checks::check_sanctions(
+        &remaining_accounts[SOURCE_SANCTIONS_INDEX],
+        &remaining_accounts[SOURCE_PLATFORM_SANCTIONS_INDEX],
+        &token_account_owner(&ctx.accounts.source_token)?,
+    )?;
+    checks::check_sanctions(
+        &remaining_accounts[DESTINATION_SANCTIONS_INDEX],
+        &remaining_accounts[DESTINATION_PLATFORM_SANCTIONS_INDEX],
+        &token_account_owner(&ctx.accounts.destination_token)?,
+    )
+}
+
+/// Apply the mint's jurisdiction rules to the residency claims of both
+/// owners. Allowlisted venue accounts hold no claims and are skipped.
+pub(crate) fn check_jurisdiction<'info>(
+    ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    source_allowlisted: bool,
+    destination_allowlisted: bool,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    if remaining_accounts.len() <= DESTINATION_RESIDENCY_INDEX {
+        // Mint still uses an older account layout
+        return Ok(());
+    }
+
+    let Some(config) = checks::load_jurisdiction_config(
+        &remaining_accounts[JURISDICTION_CONFIG_INDEX],
+        &ctx.accounts.mint.key(),
+    )?
+    else {
+        return Ok(());
+    };
+    let now = Clock::get()?.unix_timestamp;
+
+    if !source_allowlisted && !config.has_flag(JurisdictionConfig::FLAG_RECIPIENT_ONLY) {
+        let owner = token_account_owner(&ctx.accounts.source_token)?;
+        checks::check_jurisdiction_with(&config, &remaining_accounts[SOURCE_RESIDENCY_INDEX], &owner, now)?;
+    }
+    if !destination_allowlisted {
+        let owner = token_account_owner(&ctx.accounts.destination_token)?;
+        checks::check_jurisdiction_with(&config, &remaining_accounts[DESTINATION_RESIDENCY_INDEX], &owner, now)?;
+    }
+
+    Ok(())
+}
+
+/// The post-transfer source balance must still cover the sender's locked
+/// principal (the hook runs after balances moved)
+pub(crate) fn check_lockup<'info>(
+    ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    source_balance_after: u64,
+) -> Result<()> {
+    let Some(lockup_info) = ctx.remaining_accounts.get(SOURCE_LOCKUP_INDEX) else {
+        // Mint still uses an older account layout
+        return Ok(());
+    };
+
+    checks::check_lockup(
+        lockup_info,
+        &ctx.accounts.mint.key(),
+        &token_account_owner(&ctx.accounts.source_token)?,
+        source_balance_after,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+fn update_checkpoints<'info>(
+    ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    if remaining_accounts.len() <= DESTINATION_CHECKPOINT_INDEX {
+        // Mint still uses the KYC-only account layout
+        return Ok(());
+    }
+
+    let registry_info = &remaining_accounts[SNAPSHOT_REGISTRY_INDEX];
+    if registry_info.owner != &crate::ID || registry_info.data_is_empty() {
+        // Snapshots are not enabled for this mint
+        return Ok(());
+    }
+    let registry = SnapshotRegistry::try_deserialize(&mut &registry_info.try_borrow_data()?[..])?;
+
+    let source_key = ctx.accounts.source_token.key();
+    let destination_key = ctx.accounts.destination_token.key();
+    if source_key == destination_key {
+        return Ok(());
+    }
+
+    // The hook runs after balances moved, so rebuild the pre-transfer balances
+    let source_before = token_account_amount(&ctx.accounts.source_token)?
+        .checked_add(amount)
+        .ok_or(ControllerError::InvalidTokenAccount)?;
+    let destination_before = token_account_amount(&ctx.accounts.destination_token)?
+        .checked_sub(amount)
+        .ok_or(ControllerError::InvalidTokenAccount)?;
+
+    record_checkpoint(
+        &remaining_accounts[SOURCE_CHECKPOINT_INDEX],
+        source_key,
+        registry.current_record_date_id,
+        source_before,
+        source_before - amount,
+    )?;
+    record_checkpoint(
+        &remaining_accounts[DESTINATION_CHECKPOINT_INDEX],
+        destination_key,
+        registry.current_record_date_id,
+        destination_before,
+        destination_before + amount,
+    )?;
+
+    Ok(())
+}
+
+/// Add the transfer to the sender's usage and to the mint aggregate
+fn record_volume<'info>(
+    ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    amount: u64,
+    source_allowlisted: bool,
+) -> Result<()> {
+    for (usage_info, usage) in project_volume(ctx, amount, source_allowlisted)? {
+        usage.try_serialize(&mut &mut usage_info.try_borrow_mut_data()?[..])?;
+    }
+    Ok(())
+}
+
+/// Sender and mint usage including this transfer, checked against the
+/// per-holder and mint caps before anything is written. The sender's usage is
+/// required once the mint has holder caps; venue accounts have none. The mint
+/// aggregate is required once the mint has caps.
+pub(crate) fn project_volume<'a, 'info>(
+    ctx: &'a Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    amount: u64,
+    source_allowlisted: bool,
+) -> Result<Vec<(&'a AccountInfo<'info>, VolumeUsage)>> {
+    let remaining_accounts = ctx.remaining_accounts;
+    if remaining_accounts.len() <= SOURCE_VOLUME_USAGE_INDEX {
+        // Mint still uses an older account layout
+        return Ok(vec![]);
+    }
+
+    let mint = ctx.accounts.mint.key();
+    let day = VolumeUsage::day_of(Clock::get()?.unix_timestamp);
+    let caps = checks::load_volume_caps(&remaining_accounts[VOLUME_CAPS_INDEX], &mint)?;
+    let holder_capped = !source_allowlisted && caps.as_ref().is_some_and(|caps| caps.has_holder_caps());
+    let mut usages = vec![];
+
+    let holder_usage_info = &remaining_accounts[SOURCE_VOLUME_USAGE_INDEX];
+    if holder_usage_info.owner == &crate::ID && !holder_usage_info.data_is_empty() {
+        let owner = token_account_owner(&ctx.accounts.source_token)?;
+        let mut usage = load_usage(holder_usage_info, &mint, &owner)?;
+        usage.record(day, amount);
+        if let (true, Some(caps)) = (holder_capped, &caps) {
+            checks::check_holder_volume_caps(caps, usage.day_volume(day), usage.window_volume(day))?;
+        }
+        usages.push((holder_usage_info, usage));
+    } else {
+        require!(!holder_capped, ControllerError::VolumeUsageNotInitialized);
+    }
+
+    let Some(caps) = caps else {
+        return Ok(usages);
+    };
+    let mint_usage_info = &remaining_accounts[MINT_VOLUME_USAGE_INDEX];
+    require!(
+        mint_usage_info.owner == &crate::ID && !mint_usage_info.data_is_empty(),
+        ControllerError::VolumeUsageNotInitialized
+    );
+    let mut usage = load_usage(mint_usage_info, &mint, &VolumeUsage::MINT_TOTAL)?;
+    usage.record(day, amount);
+    checks::check_volume_caps(&caps, amount, usage.day_volume(day), usage.window_volume(day))?;
+    usages.push((mint_usage_info, usage));
+
+    Ok(usages)
+}
+
+fn load_usage(usage_info: &AccountInfo, mint: &Pubkey, holder: &Pubkey) -> Result<VolumeUsage> {
+    let usage = VolumeUsage::try_deserialize(&mut &usage_info.try_borrow_data()?[..])?;
+    require!(
+        usage.mint == *mint && usage.holder == *holder,
+        ControllerError::InvalidVolumeUsage
+    );
+
+    Ok(usage)
+}
+
+/// Trusted issuers of the mint's SRWAConfig, empty for older account layouts
+pub(crate) fn load_trusted_issuers(remaining_accounts: &[AccountInfo], mint: &Pubkey) -> Result<Vec<TrustedIssuerEntry>> {
+    let Some(config_info) = remaining_accounts.get(SRWA_CONFIG_INDEX) else {
+        return Ok(vec![]);
+    };
+    Ok(checks::load_srwa_config(config_info, mint)?
+        .map(|config| config.trusted_issuers_data)
+        .unwrap_or_default())
+}
+
+/// Call `check_transfer` on every custom compliance program registered for
+/// the mint; any error from a module rejects the transfer
+pub(crate) fn check_custom_modules<'info>(
+    ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    let Some(registry_info) = remaining_accounts.get(CUSTOM_MODULES_INDEX) else {
+        // Layout predates custom modules
+        return Ok(());
+    };
+    if registry_info.owner != &srwa_factory::ID || registry_info.data_is_empty() {
+        // No registry for this mint
+        return Ok(());
+    }
+
+    let mint = ctx.accounts.mint.key();
+    let registry = CustomModules::try_deserialize(&mut &registry_info.try_borrow_data()?[..])?;
+    require_keys_eq!(registry.mint, mint, ControllerError::InvalidCustomModules);
+    if registry.programs.iter().all(|p| *p == Pubkey::default()) {
+        return Ok(());
+    }
+    // Registered modules must never be skipped because the layout cannot reach them
+    require!(
+        remaining_accounts.len() >= CustomModules::HOOK_EXTRA_METAS,
+        ControllerError::CustomModuleSlotsMissing
+    );
+
+    let data = (
+        custom_module::CHECK_TRANSFER_DISCRIMINATOR,
+        custom_module::CheckTransferArgs {
+            mint,
+            from: token_account_owner(&ctx.accounts.source_token)?,
+            to: token_account_owner(&ctx.accounts.destination_token)?,
+            amount,
+        },
+    )
+        .try_to_vec()?;
+
+    for (slot, program) in registry.programs.iter().enumerate() {
+        if *program == Pubkey::default() {
+            continue;
+        }
+        let program_info = &remaining_accounts[CUSTOM_MODULE_PROGRAMS_INDEX + slot];
+        require_keys_eq!(program_info.key(), *program, ControllerError::InvalidCustomModules);
+
+        let instruction = Instruction {
+            program_id: *program,
+            accounts: vec![
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(ctx.accounts.source_token.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.destination_token.key(), false),
+            ],
+            data: data.clone(),
+        };
+        invoke(
+            &instruction,
+            &[
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.source_token.to_account_info(),
+                ctx.accounts.destination_token.to_account_info(),
+                program_info.clone(),
+            ],
+        )?;
+        msg!("  Custom module {} approved", program);
+    }
+
+    Ok(())
+}
+
+/// Recipients of a mint that requires accreditation need a trusted
+/// ACCREDITED claim, unless exempt as allowlisted accounts or KYB holders
+pub(crate) fn check_accreditation<'info>(
+    ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    trusted_issuers: &[TrustedIssuerEntry],
+    destination_allowlisted: bool,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    if remaining_accounts.len() <= DESTINATION_KYB_INDEX {
+        // Mint still uses an older account layout
+        return Ok(());
+    }
+
+    let Some(config) = checks::load_accredited_config(
+        &remaining_accounts[ACCREDITED_CONFIG_INDEX],
+        &ctx.accounts.mint.key(),
+    )?
+    else {
+        return Ok(());
+    };
+
+    checks::check_accreditation_with(
+        &config,
+        trusted_issuers,
+        &remaining_accounts[DESTINATION_ACCREDITED_INDEX],
+        &remaining_accounts[DESTINATION_KYB_INDEX],
+        &token_account_owner(&ctx.accounts.destination_token)?,
+        destination_allowlisted,
+        Clock::get()?.unix_timestamp,
+    )
+}
+
+/// Holder positions of both owners and the mint's HolderCount after a
+/// transfer, computed in memory
+#[derive(Default)]
+pub(crate) struct HolderUpdate {
+    pub source: Option<HolderPosition>,
+    pub destination: Option<HolderPosition>,
+    pub count: Option<HolderCount>,
+}
+
+fn update_holder_positions<'info>(
+    ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    amount: u64,
+    trusted_issuers: &[TrustedIssuerEntry],
+    source_allowlisted: bool,
+    destination_allowlisted: bool,
+) -> Result<HolderUpdate> {
+    let update = project_holders(ctx, amount, trusted_issuers, source_allowlisted, destination_allowlisted)?;
+    let remaining_accounts = ctx.remaining_accounts;
+
+    if let Some(position) = &update.source {
+        position.try_serialize(&mut &mut remaining_accounts[SOURCE_POSITION_INDEX].try_borrow_mut_data()?[..])?;
+    }
+    if let Some(position) = &update.destination {
+        position.try_serialize(&mut &mut remaining_accounts[DESTINATION_POSITION_INDEX].try_borrow_mut_data()?[..])?;
+    }
+    if let Some(count) = &update.count {
+        count.try_serialize(&mut &mut remaining_accounts[HOLDER_COUNT_INDEX].try_borrow_mut_data()?[..])?;
+    }
+
+    Ok(update)
+}
+
+/// Holders are owners: an owner is counted when its position across all its
+/// token accounts goes from zero and uncounted, with the accreditation it was
+/// counted with, when it returns to zero. New holders beyond the mint's caps
+/// are rejected. Venue accounts are not holders.
+pub(crate) fn project_holders<'info>(
+    ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    amount: u64,
+    trusted_issuers: &[TrustedIssuerEntry],
+    source_allowlisted: bool,
+    destination_allowlisted: bool,
+) -> Result<HolderUpdate> {
+    let remaining_accounts = ctx.remaining_accounts;
+    if remaining_accounts.len() <= DESTINATION_POSITION_INDEX {
+        // Mint still uses an older account layout
+        return Ok(HolderUpdate::default());
+    }
+
+    let mint = ctx.accounts.mint.key();
+    let config = checks::load_max_holders(&remaining_accounts[MAX_HOLDERS_INDEX], &mint)?;
+    let count_info = &remaining_accounts[HOLDER_COUNT_INDEX];
+    let count = if count_info.owner == &crate::ID && !count_info.data_is_empty() {
+        let count = HolderCount::try_deserialize(&mut &count_info.try_borrow_data()?[..])?;
+        require_keys_eq!(count.mint, mint, ControllerError::InvalidHolderCount);
+        Some(count)
+    } else {
+        // Counting is only required once the mint has holder caps
+        require!(config.is_none(), ControllerError::HolderCountNotInitialized);
+        None
+    };
+
+    let source_owner = token_account_owner(&ctx.accounts.source_token)?;
+    let destination_owner = token_account_owner(&ctx.accounts.destination_token)?;
+    let mut update = HolderUpdate {
+        source: if source_allowlisted {
+            None
+        } else {
+            load_position(&remaining_accounts[SOURCE_POSITION_INDEX], &mint, &source_owner)?
+        },
+        destination: if destination_allowlisted {
+            None
+        } else {
+            load_position(&remaining_accounts[DESTINATION_POSITION_INDEX], &mint, &destination_owner)?
+        },
+        count,
+    };
+
+    // Moving tokens between accounts of the same owner changes no position
+    if amount == 0 || source_owner == destination_owner {
+        update.source = None;
+        return Ok(update);
+    }
+
+    if !source_allowlisted {
+        match update.source.as_mut() {
+            Some(position) => {
+                position.balance = position.balance.saturating_sub(amount);
+                if position.counted && position.balance == 0 {
+                    position.counted = false;
+                    if let Some(count) = update.count.as_mut() {
+                        count.leave(position.accredited);
+                    }
+                }
+            }
+            None => require!(config.is_none(), ControllerError::HolderPositionNotInitialized),
+        }
+    }
+
+    if !destination_allowlisted {
+        match update.destination.as_mut() {
+            Some(position) => {
+                position.balance = position
+                    .balance
+                    .checked_add(amount)
+                    .ok_or(ControllerError::InvalidHolderPosition)?;
+                if !position.counted {
+                    let accredited = checks::is_accredited(
+                        &remaining_accounts[DESTINATION_ACCREDITED_INDEX],
+                        &destination_owner,
+                        trusted_issuers,
+                        Clock::get()?.unix_timestamp,
+                    )?;
+                    if let Some(count) = update.count.as_mut() {
+                        if let Some(config) = &config {
+                            checks::check_new_holder(config, count.holders, count.non_accredited, accredited)?;
+                        }
+                        count.join(accredited);
+                    }
+                    position.counted = true;
+                    position.accredited = accredited;
+                }
+            }
+            None => require!(config.is_none(), ControllerError::HolderPositionNotInitialized),
+        }
+    }
+
+    Ok(update)
+}
+
+/// Read an owner's HolderPosition, if it has been opened
+pub(crate) fn load_position(
+    position_info: &AccountInfo,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> Result<Option<HolderPosition>> {
+    if position_info.owner != &crate::ID || position_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let position = HolderPosition::try_deserialize(&mut &position_info.try_borrow_data()?[..])?;
+    require!(
+        position.mint == *mint && position.owner == *owner,
+        ControllerError::InvalidHolderPosition
+    );
+
+    Ok(Some(position))
+}
+
+/// Position cap and concentration of the receiving owner's total position
+/// (all its token accounts, after the transfer), and daily volume of the
+/// sender. `pending_volume` is the part of the transfer not yet recorded in
+/// the sender's volume usage (0 inside the hook). Venue accounts hold pooled
+/// tokens and have no position limits.
+pub(crate) fn check_investor_limits<'info>(
+    ctx: &Context<'_, '_, '_, 'info, TransferHook<'info>>,
+    destination_position: Option<&HolderPosition>,
+    destination_allowlisted: bool,
+    pending_volume: u64,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    if remaining_accounts.len() <= SOURCE_PROFILE_INDEX {
+        // Mint still uses an older account layout
+        return Ok(());
+    }
+
+    let mint = ctx.accounts.mint.key();
+
+    let destination_owner = token_account_owner(&ctx.accounts.destination_token)?;
+    let destination_profile = if destination_allowlisted {
+        None
+    } else {
+        checks::load_investor_profile(&remaining_accounts[DESTINATION_PROFILE_INDEX], &mint, &destination_owner)?
+    };
+    if let Some(profile) = destination_profile.filter(|profile| {
+        profile.limits.position_cap > 0 || profile.limits.concentration_bps > 0
+    }) {
+        let position = destination_position.ok_or(ControllerError::HolderPositionNotInitialized)?;
+        let supply = {
+            let data = ctx.accounts.mint.try_borrow_data()?;
+            StateWithExtensions::<SplMint>::unpack(&data)
+                .map_err(|_| ControllerError::InvalidTokenAccount)?
+                .base
+                .supply
+        };
+        checks::check_position(&profile.limits, position.balance, supply)?;
+    }
+
+    let source_owner = token_account_owner(&ctx.accounts.source_token)?;
+    if let Some(profile) = checks::load_investor_profile(
+        &remaining_accounts[SOURCE_PROFILE_INDEX],
+        &mint,
+        &source_owner,
+    )? {
+        if profile.limits.daily_volume > 0 {
+            let usage_info = &remaining_accounts[SOURCE_VOLUME_USAGE_INDEX];
+            require!(
+                usage_info.owner == &crate::ID && !usage_info.data_is_empty(),
+                ControllerError::VolumeUsageNotInitialized
+            );
+            let usage = VolumeUsage::try_deserialize(&mut &usage_info.try_borrow_data()?[..])?;
+            let day = VolumeUsage::day_of(Clock::get()?.unix_timestamp);
+            checks::check_investor_daily_volume(
+                &profile.limits,
+                usage.day_volume(day).saturating_add(pending_volume),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn record_checkpoint(
+    checkpoint_info: &AccountInfo,
+    token_account: Pubkey,
+    current_record_date_id: u64,
+    balance_before: u64,
+    balance_after: u64,
+) -> Result<()> {
+    require!(
+        checkpoint_info.owner == &crate::ID && !checkpoint_info.data_is_empty(),
+        ControllerError::CheckpointNotInitialized
+    );
+
+    let mut checkpoint =
+        HolderCheckpoint::try_deserialize(&mut &checkpoint_info.try_borrow_data()?[..])?;
+    require_keys_eq!(
+        checkpoint.token_account,
+        token_account,
+        ControllerError::InvalidCheckpoint
+    );
+
+    let clock = Clock::get()?;
+    checkpoint.track(current_record_date_id, balance_before, balance_after);
+    checkpoint.last_update_slot = clock.slot;
+    checkpoint.last_update_ts = clock.unix_timestamp;
+    checkpoint.try_serialize(&mut &mut checkpoint_info.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
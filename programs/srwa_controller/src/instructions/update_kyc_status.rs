@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateKYCStatus<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"kyc", kyc_registry.user.as_ref()],
+        bump = kyc_registry.bump
+    )]
+    pub kyc_registry: Account<'info, KYCRegistry>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateKYCStatus>,
+    kyc_completed: bool,
+    is_active: bool,
+) -> Result<()> {
+    let kyc_registry = &mut ctx.accounts.kyc_registry;
+
+    kyc_registry.kyc_completed = kyc_completed;
+    kyc_registry.is_active = is_active;
+    kyc_registry.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("✅ KYC status updated for {}", kyc_registry.user);
+    msg!("  - KYC completed: {}", kyc_completed);
+    msg!("  - Is active: {}", is_active);
+
+    Ok(())
+}
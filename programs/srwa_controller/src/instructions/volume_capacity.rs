@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use compliance_modules::state::VolumeCapsConfig;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(holder: Pubkey)]
+pub struct VolumeCapacity<'info> {
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"volume_caps", mint.key().as_ref()],
+        bump = volume_caps.bump,
+        seeds::program = compliance_modules::ID,
+    )]
+    pub volume_caps: Account<'info, VolumeCapsConfig>,
+
+    #[account(
+        seeds = [VolumeUsage::SEED_PREFIX, mint.key().as_ref(), VolumeUsage::MINT_TOTAL.as_ref()],
+        bump = mint_usage.bump,
+    )]
+    pub mint_usage: Option<Account<'info, VolumeUsage>>,
+
+    #[account(
+        seeds = [VolumeUsage::SEED_PREFIX, mint.key().as_ref(), holder.as_ref()],
+        bump = holder_usage.bump,
+    )]
+    pub holder_usage: Option<Account<'info, VolumeUsage>>,
+}
+
+pub fn handler(ctx: Context<VolumeCapacity>, _holder: Pubkey) -> Result<VolumeAllowance> {
+    let caps = &ctx.accounts.volume_caps;
+    let day = VolumeUsage::day_of(Clock::get()?.unix_timestamp);
+
+    let (mint_daily, mint_monthly) = ctx
+        .accounts
+        .mint_usage
+        .as_ref()
+        .map_or((0, 0), |usage| (usage.day_volume(day), usage.window_volume(day)));
+    let (holder_daily, holder_monthly) = ctx
+        .accounts
+        .holder_usage
+        .as_ref()
+        .map_or((0, 0), |usage| (usage.day_volume(day), usage.window_volume(day)));
+
+    // A cap of 0 is disabled
+    let remaining = |cap: u64, used: u64| if cap == 0 { u64::MAX } else { cap.saturating_sub(used) };
+
+    let capacity = VolumeAllowance {
+        max_tx: if caps.max_tx == 0 { u64::MAX } else { caps.max_tx },
+        daily_remaining: remaining(caps.daily_cap, mint_daily),
+        monthly_remaining: remaining(caps.monthly_cap, mint_monthly),
+        holder_daily_volume: holder_daily,
+        holder_monthly_volume: holder_monthly,
+        holder_daily_remaining: remaining(caps.holder_daily_cap, holder_daily),
+        holder_monthly_remaining: remaining(caps.holder_monthly_cap, holder_monthly),
+    };
+
+    msg!(
+        "Volume capacity of {}: {} today, {} over 30 days",
+        ctx.accounts.mint.key(),
+        capacity.daily_remaining,
+        capacity.monthly_remaining
+    );
+
+    Ok(capacity)
+}
@@ -0,0 +1,297 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ControllerError;
+
+// KYC Registry Account (owned by Transfer Hook program)
+#[account]
+pub struct KYCRegistry {
+    pub user: Pubkey,        // 32
+    pub kyc_completed: bool,  // 1
+    pub is_active: bool,      // 1
+    pub updated_at: i64,      // 8
+    pub bump: u8,             // 1
+}
+
+/// Per-mint record date counter. Once this account exists the transfer hook
+/// requires both legs of every transfer to have an open `HolderCheckpoint`.
+#[account]
+#[derive(InitSpace)]
+pub struct SnapshotRegistry {
+    pub mint: Pubkey,
+    pub current_record_date_id: u64, // 0 = no record date declared yet
+    pub last_record_slot: u64,
+    pub last_record_ts: i64,
+    pub bump: u8,
+}
+
+/// A declared record date (snapshot point) for a mint
+#[account]
+#[derive(InitSpace)]
+pub struct RecordDate {
+    pub mint: Pubkey,
+    pub id: u64,
+    pub slot: u64,
+    pub timestamp: i64,
+    pub declared_by: Pubkey,
+    pub bump: u8,
+}
+
+/// Balance history of a single token account, written lazily by the transfer
+/// hook. `tracked_balance` is the balance last seen by the hook or
+/// `sync_checkpoint`; a different balance on the next observation means a
+/// mint or burn bypassed the hook in between.
+#[account]
+#[derive(InitSpace)]
+pub struct HolderCheckpoint {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    #[max_len(32)]
+    pub entries: Vec<BalanceCheckpoint>,
+    pub pruned_through: u64, // record dates <= this id are no longer answerable
+    pub last_update_slot: u64,
+    pub last_update_ts: i64,
+    pub tracked_balance: u64,
+    pub tracked_record_date_id: u64, // current record date when tracked_balance was seen
+    pub bump: u8,
+}
+
+/// Balance held at the given record date, stored on the first change after it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct BalanceCheckpoint {
+    pub record_date_id: u64,
+    pub balance: u64,
+}
+
+/// Transfer volume of one holder, or of the whole mint (`MINT_TOTAL`), kept
+/// in daily buckets over a rolling 30-day window. Written by the transfer hook.
+#[account]
+#[derive(InitSpace)]
+pub struct VolumeUsage {
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub last_day: i64,       // unix day of the newest bucket
+    pub buckets: [u64; 30],  // indexed by day % WINDOW_DAYS
+    pub bump: u8,
+}
+
+/// Live number of holders (owners with a non-zero position) of a mint,
+/// maintained by the transfer hook. Mints and burns bypass the hook, so the
+/// issuer corrects positions with `set_holder_position` after issuing outside
+/// of transfers.
+#[account]
+#[derive(InitSpace)]
+pub struct HolderCount {
+    pub mint: Pubkey,
+    pub holders: u32,
+    pub non_accredited: u32,
+    pub bump: u8,
+}
+
+/// Total balance of one owner across all its token accounts of a mint,
+/// maintained by the transfer hook. `counted` is set while the owner is
+/// included in the HolderCount and `accredited` keeps the status it was
+/// counted with, so leaving undoes exactly what joining did.
+#[account]
+#[derive(InitSpace)]
+pub struct HolderPosition {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub balance: u64,
+    pub counted: bool,
+    pub accredited: bool,
+    pub bump: u8,
+}
+
+/// Remaining allowance returned by `volume_capacity` (u64::MAX = uncapped)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct VolumeAllowance {
+    pub max_tx: u64,
+    pub daily_remaining: u64,
+    pub monthly_remaining: u64,
+    pub holder_daily_volume: u64,
+    pub holder_monthly_volume: u64,
+    pub holder_daily_remaining: u64,
+    pub holder_monthly_remaining: u64,
+}
+
+impl HolderCount {
+    pub const SEED_PREFIX: &'static [u8] = b"holder_count";
+
+    /// Count an owner that joins, with the accreditation it joins with
+    pub fn join(&mut self, accredited: bool) {
+        self.holders += 1;
+        if !accredited {
+            self.non_accredited += 1;
+        }
+    }
+
+    /// Uncount an owner that leaves, with the accreditation it was counted with
+    pub fn leave(&mut self, accredited: bool) {
+        self.holders = self.holders.saturating_sub(1);
+        if !accredited {
+            self.non_accredited = self.non_accredited.saturating_sub(1);
+        }
+    }
+}
+
+impl HolderPosition {
+    pub const SEED_PREFIX: &'static [u8] = b"holder_position";
+}
+
+impl SnapshotRegistry {
+    pub const SEED_PREFIX: &'static [u8] = b"snapshots";
+}
+
+impl RecordDate {
+    pub const SEED_PREFIX: &'static [u8] = b"record_date";
+}
+
+impl HolderCheckpoint {
+    pub const SEED_PREFIX: &'static [u8] = b"checkpoint";
+    pub const MAX_ENTRIES: usize = 32;
+
+    /// Record the balance held before a change, if the current record date
+    /// has not been captured yet for this holder.
+    pub fn record_before_change(&mut self, current_record_date_id: u64, balance_before: u64) {
+        if current_record_date_id == 0 {
+            return;
+        }
+
+        let last_recorded = self.entries.last().map(|e| e.record_date_id).unwrap_or(0);
+        if last_recorded >= current_record_date_id {
+            return;
+        }
+
+        if self.entries.len() >= Self::MAX_ENTRIES {
+            let dropped = self.entries.remove(0);
+            self.pruned_through = dropped.record_date_id;
+        }
+
+        self.entries.push(BalanceCheckpoint {
+            record_date_id: current_record_date_id,
+            balance: balance_before,
+        });
+    }
+
+    /// Observe a change from `balance_before` to `balance_after` (equal for a
+    /// plain sync). If the balance moved untracked while record dates were
+    /// declared, it is unknown on which side of them it moved, so those
+    /// record dates become unanswerable.
+    pub fn track(&mut self, current_record_date_id: u64, balance_before: u64, balance_after: u64) {
+        if balance_before != self.tracked_balance
+            && self.tracked_record_date_id < current_record_date_id
+        {
+            self.entries.clear();
+            self.pruned_through = current_record_date_id;
+        } else {
+            self.record_before_change(current_record_date_id, balance_before);
+        }
+
+        self.tracked_balance = balance_after;
+        self.tracked_record_date_id = current_record_date_id;
+    }
+
+    /// Balance held at `record_date_id`, given the account's current balance.
+    pub fn balance_at(&self, record_date_id: u64, current_balance: u64) -> Result<u64> {
+        require!(
+            record_date_id > self.pruned_through,
+            ControllerError::CheckpointPruned
+        );
+
+        match self.entries.iter().find(|e| e.record_date_id >= record_date_id) {
+            Some(entry) => Ok(entry.balance),
+            None => {
+                // No change recorded since the record date: the current
+                // balance answers only if nothing moved untracked
+                require!(
+                    current_balance == self.tracked_balance,
+                    ControllerError::CheckpointOutOfSync
+                );
+                Ok(current_balance)
+            }
+        }
+    }
+}
+
+impl VolumeUsage {
+    pub const SEED_PREFIX: &'static [u8] = b"volume_usage";
+    pub const WINDOW_DAYS: i64 = 30;
+    /// Holder key of the per-mint aggregate
+    pub const MINT_TOTAL: Pubkey = Pubkey::new_from_array([0; 32]);
+
+    pub fn day_of(unix_timestamp: i64) -> i64 {
+        unix_timestamp.div_euclid(86_400)
+    }
+
+    fn bucket(day: i64) -> usize {
+        day.rem_euclid(Self::WINDOW_DAYS) as usize
+    }
+
+    /// Volume moved on `day`
+    pub fn day_volume(&self, day: i64) -> u64 {
+        if day == self.last_day {
+            self.buckets[Self::bucket(day)]
+        } else {
+            0
+        }
+    }
+
+    /// Volume moved over the 30 days ending on `day`
+    pub fn window_volume(&self, day: i64) -> u64 {
+        let first = (day - Self::WINDOW_DAYS + 1).max(self.last_day - Self::WINDOW_DAYS + 1);
+        (first..=self.last_day.min(day))
+            .map(|d| self.buckets[Self::bucket(d)])
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Add `amount` to `day`, clearing the buckets of days that left the window
+    pub fn record(&mut self, day: i64, amount: u64) {
+        if day > self.last_day {
+            let stale = (day - self.last_day).min(Self::WINDOW_DAYS);
+            for d in (day - stale + 1)..=day {
+                self.buckets[Self::bucket(d)] = 0;
+            }
+            self.last_day = day;
+        }
+        let bucket = &mut self.buckets[Self::bucket(self.last_day)];
+        *bucket = bucket.saturating_add(amount);
+    }
+}
+
+/// Rules evaluated by `simulate_transfer`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ComplianceCheck {
+    Balance,
+    SenderKyc,
+    RecipientKyc,
+    TransferWindow,
+    Sanctions,
+    Jurisdiction,
+    Lockup,
+    Accreditation,
+    VolumeCaps,
+    HolderCaps,
+    InvestorLimits,
+    CustomModules,
+}
+
+/// Outcome of one rule. `reason` is the error code the rule failed with, as
+/// defined by the program owning the rule (0 = passed or not evaluated).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct CheckResult {
+    pub check: ComplianceCheck,
+    pub evaluated: bool,
+    pub passed: bool,
+    pub reason: u64,
+}
+
+/// Result returned by `simulate_transfer`. Custom modules are called only
+/// when every built-in rule passed, since a rejecting module aborts the
+/// instruction: they are then not evaluated, and a rejection by one of them
+/// fails the simulation with the module's error instead of a `CheckResult`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferSimulation {
+    pub approved: bool,
+    pub checks: Vec<CheckResult>,
+}
@@ -114,4 +114,25 @@ pub enum SRWAError {
 
     #[msg("Failed to initialize token-2022 mint")]
     MintInitializationFailed,
+
+    #[msg("Custom module is already registered")]
+    CustomModuleAlreadyRegistered,
+
+    #[msg("Custom module is not registered")]
+    CustomModuleNotRegistered,
+
+    #[msg("All custom module slots are in use")]
+    CustomModulesFull,
+
+    #[msg("Custom module must be an executable program")]
+    InvalidModuleProgram,
+
+    #[msg("Invalid parameters for this module")]
+    InvalidModuleParams,
+
+    #[msg("Transfer hook layout lacks custom module slots; call update_extra_account_meta_list")]
+    CustomModuleSlotsMissing,
+
+    #[msg("Module params need the compliance_modules program and config accounts")]
+    InvalidComplianceConfig,
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::{IdleStrategy, ModuleParams};
 
 #[event]
 pub struct TokenCreated {
@@ -43,6 +44,24 @@ pub struct ModuleUpdated {
     pub enabled: bool,
 }
 
+#[event]
+pub struct ModuleParamsUpdated {
+    pub mint: Pubkey,
+    pub module_id: u8,
+    pub old_params: Option<ModuleParams>,
+    pub new_params: ModuleParams,
+    pub updated_by: Pubkey,
+}
+
+#[event]
+pub struct CustomModuleUpdated {
+    pub mint: Pubkey,
+    pub program: Pubkey,
+    pub slot: u8,
+    pub enabled: bool,
+    pub updated_by: Pubkey,
+}
+
 #[event]
 pub struct OracleConfigUpdated {
     pub mint: Pubkey,
@@ -57,3 +76,10 @@ pub struct RoleRotated {
     pub old_pubkey: Pubkey,
     pub new_pubkey: Pubkey,
 }
+
+#[event]
+pub struct IdleStrategyUpdated {
+    pub mint: Pubkey,
+    pub old_strategy: IdleStrategy,
+    pub new_strategy: IdleStrategy,
+}
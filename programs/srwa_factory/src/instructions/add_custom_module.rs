@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+pub struct AddCustomModule<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin
+            || authority.key() == srwa_config.roles.compliance_officer @ SRWAError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        mut,
+        seeds = [CustomModules::SEED_PREFIX, mint.key().as_ref()],
+        bump = custom_modules.bump,
+        has_one = mint
+    )]
+    pub custom_modules: Account<'info, CustomModules>,
+
+    /// CHECK: The mint's ExtraAccountMetaList; must already list the module slots
+    #[account(
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump,
+        seeds::program = crate::TRANSFER_HOOK_PROGRAM_ID,
+        owner = crate::TRANSFER_HOOK_PROGRAM_ID
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// CHECK: Compliance program implementing `check_transfer`
+    #[account(constraint = module_program.executable @ SRWAError::InvalidModuleProgram)]
+    pub module_program: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<AddCustomModule>) -> Result<()> {
+    // A registered module the hook layout cannot resolve would never run
+    let meta_count = {
+        let data = ctx.accounts.extra_account_meta_list.try_borrow_data()?;
+        let offset = CustomModules::META_COUNT_OFFSET;
+        data.get(offset..offset + 4)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+            .unwrap_or_default()
+    };
+    require!(
+        meta_count >= CustomModules::HOOK_EXTRA_METAS,
+        SRWAError::CustomModuleSlotsMissing
+    );
+
+    let custom_modules = &mut ctx.accounts.custom_modules;
+    let program = ctx.accounts.module_program.key();
+
+    require!(
+        !custom_modules.contains(&program),
+        SRWAError::CustomModuleAlreadyRegistered
+    );
+
+    let slot = custom_modules
+        .programs
+        .iter()
+        .position(|p| *p == Pubkey::default())
+        .ok_or(SRWAError::CustomModulesFull)?;
+    custom_modules.programs[slot] = program;
+
+    emit!(CustomModuleUpdated {
+        mint: custom_modules.mint,
+        program,
+        slot: slot as u8,
+        enabled: true,
+        updated_by: ctx.accounts.authority.key(),
+    });
+
+    msg!("Registered custom module {} in slot {}", program, slot);
+
+    Ok(())
+}
@@ -280,7 +280,7 @@ fn initialise_token_2022_mint<'info>(
             &spl_token_2022::id(),
             mint_info.key,
             Some(config.roles.compliance_officer),
-            Some(srwa_controller::ID),
+            Some(crate::TRANSFER_HOOK_PROGRAM_ID),
         )?,
         &[mint_info.clone()],
     )?;
@@ -1,13 +1,48 @@
-use anchor_lang::prelude::*;
-use crate::{state::*, events::*, errors::*, instructions::enable_module::UpdateModule};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke},
+};
+use crate::{state::*, events::*, errors::*};
 
-pub fn handler(
-    ctx: Context<UpdateModule>,
+/// Modules with a compliance_modules config need
+/// `remaining_accounts = [compliance_modules program, config PDA]`; the config
+/// is closed so the transfer hook stops enforcing the module.
+#[derive(Accounts)]
+#[instruction(module_id: u8)]
+pub struct DisableModule<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin
+            || authority.key() == srwa_config.roles.compliance_officer @ SRWAError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    /// CHECK: ModuleConfig PDA, closed if it exists. It is not created for
+    /// modules enabled before params were typed.
+    #[account(
+        mut,
+        seeds = [ModuleConfig::SEED_PREFIX, mint.key().as_ref(), &[module_id]],
+        bump
+    )]
+    pub module_config: UncheckedAccount<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, DisableModule<'info>>,
     module_id: u8,
 ) -> Result<()> {
     let srwa_config = &mut ctx.accounts.srwa_config;
 
-    let module = module_id_to_enum(module_id)?;
+    let module = ModuleId::try_from(module_id)?;
 
     // Check if enabled
     require!(
@@ -17,6 +52,32 @@ pub fn handler(
 
     srwa_config.modules_enabled.retain(|&m| m != module);
 
+    // The legacy blob cannot be split per module; drop it with the last module
+    if srwa_config.modules_enabled.is_empty() {
+        srwa_config.params_by_module.clear();
+    }
+
+    let module_config = ctx.accounts.module_config.to_account_info();
+    if module_config.owner == &crate::ID && !module_config.data_is_empty() {
+        let authority = ctx.accounts.authority.to_account_info();
+        let lamports = authority
+            .lamports()
+            .checked_add(module_config.lamports())
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        **authority.try_borrow_mut_lamports()? = lamports;
+        **module_config.try_borrow_mut_lamports()? = 0;
+        module_config.assign(&System::id());
+        module_config.resize(0)?;
+    }
+
+    close_compliance_config(
+        &ctx.accounts.authority,
+        &ctx.accounts.mint,
+        &ctx.accounts.srwa_config,
+        module,
+        ctx.remaining_accounts,
+    )?;
+
     emit!(ModuleUpdated {
         mint: ctx.accounts.mint.key(),
         module_id,
@@ -28,19 +89,57 @@ pub fn handler(
     Ok(())
 }
 
-fn module_id_to_enum(id: u8) -> Result<ModuleId> {
-    match id {
-        0 => Ok(ModuleId::Jurisdiction),
-        1 => Ok(ModuleId::Sanctions),
-        2 => Ok(ModuleId::Accredited),
-        3 => Ok(ModuleId::Lockup),
-        4 => Ok(ModuleId::MaxHolders),
-        5 => Ok(ModuleId::VolumeCaps),
-        6 => Ok(ModuleId::TransferWindow),
-        7 => Ok(ModuleId::ProgramAllowlist),
-        8 => Ok(ModuleId::AccountAllowlist),
-        9 => Ok(ModuleId::OfferingRules),
-        10 => Ok(ModuleId::InvestorLimits),
-        _ => Err(error!(SRWAError::InvalidModule)),
+/// Close the compliance_modules config account the transfer hook reads for
+/// `module`, which would otherwise keep being enforced. A config never
+/// created is skipped.
+fn close_compliance_config<'info>(
+    authority: &Signer<'info>,
+    mint: &UncheckedAccount<'info>,
+    srwa_config: &Account<'info, SRWAConfig>,
+    module: ModuleId,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let Some(seed) = module.compliance_config_seed() else {
+        return Ok(());
+    };
+    let [program, config, ..] = remaining_accounts else {
+        return err!(SRWAError::InvalidComplianceConfig);
+    };
+    require_keys_eq!(program.key(), crate::COMPLIANCE_MODULES_PROGRAM_ID, SRWAError::InvalidComplianceConfig);
+    let (address, _) = Pubkey::find_program_address(
+        &[seed, mint.key().as_ref()],
+        &crate::COMPLIANCE_MODULES_PROGRAM_ID,
+    );
+    require_keys_eq!(config.key(), address, SRWAError::InvalidComplianceConfig);
+    if config.data_is_empty() {
+        return Ok(());
     }
+
+    // compliance_modules checks the module is no longer enabled
+    srwa_config.exit(&crate::ID)?;
+
+    let mut data = compliance_config::CLOSE_MODULE_CONFIG_DISCRIMINATOR.to_vec();
+    data.push(module as u8);
+    let instruction = Instruction {
+        program_id: crate::COMPLIANCE_MODULES_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(authority.key(), true),
+            AccountMeta::new_readonly(mint.key(), false),
+            AccountMeta::new_readonly(srwa_config.key(), false),
+            AccountMeta::new(config.key(), false),
+        ],
+        data,
+    };
+    invoke(
+        &instruction,
+        &[
+            authority.to_account_info(),
+            mint.to_account_info(),
+            srwa_config.to_account_info(),
+            config.clone(),
+            program.clone(),
+        ],
+    )?;
+
+    Ok(())
 }
@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
 use crate::{state::*, events::*, errors::*};
 
+/// `module_config` may survive a disable that skipped it, so re-enabling
+/// overwrites it instead of failing on an existing account. Params are
+/// mirrored to compliance_modules as in `update_module_params`.
 #[derive(Accounts)]
-pub struct UpdateModule<'info> {
+#[instruction(module_id: u8)]
+pub struct EnableModule<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -18,16 +22,28 @@ pub struct UpdateModule<'info> {
             || authority.key() == srwa_config.roles.compliance_officer @ SRWAError::Unauthorized
     )]
     pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ModuleConfig::INIT_SPACE,
+        seeds = [ModuleConfig::SEED_PREFIX, mint.key().as_ref(), &[module_id]],
+        bump
+    )]
+    pub module_config: Account<'info, ModuleConfig>,
+
+    pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
-    ctx: Context<UpdateModule>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, EnableModule<'info>>,
     module_id: u8,
-    params: Vec<u8>,
+    params: ModuleParams,
 ) -> Result<()> {
     let srwa_config = &mut ctx.accounts.srwa_config;
 
-    let module = module_id_to_enum(module_id)?;
+    let module = ModuleId::try_from(module_id)?;
+    params.validate(module)?;
 
     // Check if already enabled
     require!(
@@ -37,8 +53,20 @@ pub fn handler(
 
     srwa_config.modules_enabled.push(module);
 
-    // Append params
-    srwa_config.params_by_module.extend(params);
+    let module_config = &mut ctx.accounts.module_config;
+    module_config.mint = ctx.accounts.mint.key();
+    module_config.module = module;
+    module_config.params = params.clone();
+    module_config.updated_at = Clock::get()?.unix_timestamp;
+    module_config.bump = ctx.bumps.module_config;
+
+    super::update_module_params::apply_module_params(
+        &ctx.accounts.authority,
+        &ctx.accounts.mint,
+        &ctx.accounts.srwa_config,
+        &ctx.accounts.module_config,
+        ctx.remaining_accounts,
+    )?;
 
     emit!(ModuleUpdated {
         mint: ctx.accounts.mint.key(),
@@ -46,24 +74,15 @@ pub fn handler(
         enabled: true,
     });
 
+    emit!(ModuleParamsUpdated {
+        mint: ctx.accounts.mint.key(),
+        module_id,
+        old_params: None,
+        new_params: params,
+        updated_by: ctx.accounts.authority.key(),
+    });
+
     msg!("Enabled module: {:?}", module);
 
     Ok(())
 }
-
-fn module_id_to_enum(id: u8) -> Result<ModuleId> {
-    match id {
-        0 => Ok(ModuleId::Jurisdiction),
-        1 => Ok(ModuleId::Sanctions),
-        2 => Ok(ModuleId::Accredited),
-        3 => Ok(ModuleId::Lockup),
-        4 => Ok(ModuleId::MaxHolders),
-        5 => Ok(ModuleId::VolumeCaps),
-        6 => Ok(ModuleId::TransferWindow),
-        7 => Ok(ModuleId::ProgramAllowlist),
-        8 => Ok(ModuleId::AccountAllowlist),
-        9 => Ok(ModuleId::OfferingRules),
-        10 => Ok(ModuleId::InvestorLimits),
-        _ => Err(error!(SRWAError::InvalidModule)),
-    }
-}
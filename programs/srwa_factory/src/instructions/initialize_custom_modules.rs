@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::*};
+
+/// Create the registry before adding modules: the mint's ExtraAccountMetaList
+/// only lists the module slots once it exists, and `add_custom_module`
+/// requires them.
+#[derive(Accounts)]
+pub struct InitializeCustomModules<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin
+            || authority.key() == srwa_config.roles.compliance_officer @ SRWAError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CustomModules::INIT_SPACE,
+        seeds = [CustomModules::SEED_PREFIX, mint.key().as_ref()],
+        bump
+    )]
+    pub custom_modules: Account<'info, CustomModules>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeCustomModules>) -> Result<()> {
+    let custom_modules = &mut ctx.accounts.custom_modules;
+    custom_modules.mint = ctx.accounts.mint.key();
+    custom_modules.programs = [Pubkey::default(); CustomModules::MAX_MODULES];
+    custom_modules.bump = ctx.bumps.custom_modules;
+
+    msg!("Initialized custom modules registry for mint {}", custom_modules.mint);
+
+    Ok(())
+}
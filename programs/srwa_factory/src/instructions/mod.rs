@@ -2,7 +2,12 @@ pub mod create_srwa;
 pub mod update_trusted_issuer;
 pub mod enable_module;
 pub mod disable_module;
+pub mod update_module_params;
+pub mod initialize_custom_modules;
+pub mod add_custom_module;
+pub mod remove_custom_module;
 pub mod set_oracle_cfg;
+pub mod set_idle_strategy;
 pub mod rotate_role;
 pub mod open_offering;
 pub mod request_srwa;
@@ -24,7 +29,12 @@ pub use create_srwa::*;
 pub use update_trusted_issuer::*;
 pub use enable_module::*;
 pub use disable_module::*;
+pub use update_module_params::*;
+pub use initialize_custom_modules::*;
+pub use add_custom_module::*;
+pub use remove_custom_module::*;
 pub use set_oracle_cfg::*;
+pub use set_idle_strategy::*;
 pub use rotate_role::*;
 pub use open_offering::*;
 pub use request_srwa::*;
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, events::*, errors::*};
+
+/// The registry is kept when empty: the mint's ExtraAccountMetaList reads
+/// module programs from it once custom modules are enabled.
+#[derive(Accounts)]
+pub struct RemoveCustomModule<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin
+            || authority.key() == srwa_config.roles.compliance_officer @ SRWAError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        mut,
+        seeds = [CustomModules::SEED_PREFIX, mint.key().as_ref()],
+        bump = custom_modules.bump,
+        has_one = mint
+    )]
+    pub custom_modules: Account<'info, CustomModules>,
+}
+
+pub fn handler(ctx: Context<RemoveCustomModule>, program: Pubkey) -> Result<()> {
+    let custom_modules = &mut ctx.accounts.custom_modules;
+
+    let slot = custom_modules
+        .programs
+        .iter()
+        .position(|p| *p == program && program != Pubkey::default())
+        .ok_or(SRWAError::CustomModuleNotRegistered)?;
+    custom_modules.programs[slot] = Pubkey::default();
+
+    emit!(CustomModuleUpdated {
+        mint: custom_modules.mint,
+        program,
+        slot: slot as u8,
+        enabled: false,
+        updated_by: ctx.accounts.authority.key(),
+    });
+
+    msg!("Removed custom module {} from slot {}", program, slot);
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+pub struct SetIdleStrategy<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin @ SRWAError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"offering", mint.key().as_ref()],
+        bump = offering_state.bump,
+    )]
+    pub offering_state: Account<'info, OfferingState>,
+}
+
+pub fn handler(ctx: Context<SetIdleStrategy>, strategy: IdleStrategy) -> Result<()> {
+    let offering_state = &mut ctx.accounts.offering_state;
+
+    require!(
+        !matches!(
+            offering_state.phase,
+            OfferingPhase::Settlement | OfferingPhase::Refund
+        ),
+        SRWAError::InvalidPhase
+    );
+
+    let old_strategy = offering_state.idle_strategy;
+    offering_state.idle_strategy = strategy;
+
+    emit!(IdleStrategyUpdated {
+        mint: ctx.accounts.mint.key(),
+        old_strategy,
+        new_strategy: strategy,
+    });
+
+    msg!("Idle strategy updated");
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, program::invoke},
+};
+use crate::{state::*, events::*, errors::*};
+
+/// Creates the ModuleConfig for modules enabled before params were typed.
+/// Params mirrored by compliance_modules are also written to its config
+/// account, passed as remaining accounts (see `apply_module_params`).
+#[derive(Accounts)]
+#[instruction(module_id: u8)]
+pub struct UpdateModuleParams<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Mint account
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin
+            || authority.key() == srwa_config.roles.compliance_officer @ SRWAError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ModuleConfig::INIT_SPACE,
+        seeds = [ModuleConfig::SEED_PREFIX, mint.key().as_ref(), &[module_id]],
+        bump
+    )]
+    pub module_config: Account<'info, ModuleConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateModuleParams<'info>>,
+    module_id: u8,
+    params: ModuleParams,
+) -> Result<()> {
+    let module = ModuleId::try_from(module_id)?;
+    params.validate(module)?;
+
+    require!(
+        ctx.accounts.srwa_config.modules_enabled.contains(&module),
+        SRWAError::ModuleNotEnabled
+    );
+
+    let module_config = &mut ctx.accounts.module_config;
+    let old_params = (module_config.mint != Pubkey::default()).then(|| module_config.params.clone());
+
+    module_config.mint = ctx.accounts.mint.key();
+    module_config.module = module;
+    module_config.params = params.clone();
+    module_config.updated_at = Clock::get()?.unix_timestamp;
+    module_config.bump = ctx.bumps.module_config;
+
+    apply_module_params(
+        &ctx.accounts.authority,
+        &ctx.accounts.mint,
+        &ctx.accounts.srwa_config,
+        &ctx.accounts.module_config,
+        ctx.remaining_accounts,
+    )?;
+
+    emit!(ModuleParamsUpdated {
+        mint: ctx.accounts.mint.key(),
+        module_id,
+        old_params,
+        new_params: params,
+        updated_by: ctx.accounts.authority.key(),
+    });
+
+    msg!("Updated params of module: {:?}", module);
+
+    Ok(())
+}
+
+/// Mirror `module_config` into the compliance_modules config account the
+/// transfer hook reads. Modules with such an account need
+/// `remaining_accounts = [compliance_modules program, config PDA]`; a config
+/// not created yet is skipped and picks the params up on the next update.
+pub(crate) fn apply_module_params<'info>(
+    authority: &Signer<'info>,
+    mint: &UncheckedAccount<'info>,
+    srwa_config: &Account<'info, SRWAConfig>,
+    module_config: &Account<'info, ModuleConfig>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let Some(seed) = module_config.params.compliance_config_seed() else {
+        return Ok(());
+    };
+    let [program, config, ..] = remaining_accounts else {
+        return err!(SRWAError::InvalidComplianceConfig);
+    };
+    require_keys_eq!(program.key(), crate::COMPLIANCE_MODULES_PROGRAM_ID, SRWAError::InvalidComplianceConfig);
+    let (address, _) = Pubkey::find_program_address(
+        &[seed, mint.key().as_ref()],
+        &crate::COMPLIANCE_MODULES_PROGRAM_ID,
+    );
+    require_keys_eq!(config.key(), address, SRWAError::InvalidComplianceConfig);
+    if config.data_is_empty() {
+        msg!("Compliance config not created yet, params not applied");
+        return Ok(());
+    }
+
+    // compliance_modules reads the ModuleConfig written by this instruction
+    module_config.exit(&crate::ID)?;
+
+    let instruction = Instruction {
+        program_id: crate::COMPLIANCE_MODULES_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(authority.key(), true),
+            AccountMeta::new_readonly(mint.key(), false),
+            AccountMeta::new_readonly(srwa_config.key(), false),
+            AccountMeta::new_readonly(module_config.key(), false),
+            AccountMeta::new(config.key(), false),
+        ],
+        data: compliance_config::APPLY_MODULE_PARAMS_DISCRIMINATOR.to_vec(),
+    };
+    invoke(
+        &instruction,
+        &[
+            authority.to_account_info(),
+            mint.to_account_info(),
+            srwa_config.to_account_info(),
+            module_config.to_account_info(),
+            config.clone(),
+            program.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
@@ -2,6 +2,12 @@ use anchor_lang::prelude::*;
 
 declare_id!("5suWp35g2vbxzRCTW2fRACD32DaL9Q3wy72Cxz4AesLg");
 
+/// Transfer hook program (srwa_controller) configured on every SRWA mint.
+/// Kept as a constant so the controller can depend on this crate.
+pub const TRANSFER_HOOK_PROGRAM_ID: Pubkey = pubkey!("A6JtsR3Zw1GB1gTJuqdpFiBijarm9pQRTgqVkZaEdBs3");
+/// compliance_modules program, which mirrors module params in its config accounts
+pub const COMPLIANCE_MODULES_PROGRAM_ID: Pubkey = pubkey!("GD3ArP1GPKN9sWYPxiPia2i3iAKKsnbXxpcoB1gQK5D");
+
 pub mod state;
 pub mod instructions;
 pub mod errors;
@@ -63,23 +69,54 @@ pub mod srwa_factory {
         instructions::update_trusted_issuer::handler(ctx, topic, issuer, add)
     }
 
-    /// Enable a compliance module
-    pub fn enable_module(
-        ctx: Context<UpdateModule>,
+    /// Enable a compliance module with its typed params
+    pub fn enable_module<'info>(
+        ctx: Context<'_, '_, '_, 'info, EnableModule<'info>>,
         module_id: u8,
-        params: Vec<u8>,
+        params: ModuleParams,
     ) -> Result<()> {
         instructions::enable_module::handler(ctx, module_id, params)
     }
 
-    /// Disable a compliance module
-    pub fn disable_module(
-        ctx: Context<UpdateModule>,
+    /// Disable a compliance module and close its params
+    pub fn disable_module<'info>(
+        ctx: Context<'_, '_, '_, 'info, DisableModule<'info>>,
         module_id: u8,
     ) -> Result<()> {
         instructions::disable_module::handler(ctx, module_id)
     }
 
+    /// Replace the params of an enabled compliance module
+    pub fn update_module_params<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateModuleParams<'info>>,
+        module_id: u8,
+        params: ModuleParams,
+    ) -> Result<()> {
+        instructions::update_module_params::handler(ctx, module_id, params)
+    }
+
+    /// Create the empty custom modules registry of a mint
+    pub fn initialize_custom_modules(
+        ctx: Context<InitializeCustomModules>,
+    ) -> Result<()> {
+        instructions::initialize_custom_modules::handler(ctx)
+    }
+
+    /// Register a custom compliance program called by the transfer hook
+    pub fn add_custom_module(
+        ctx: Context<AddCustomModule>,
+    ) -> Result<()> {
+        instructions::add_custom_module::handler(ctx)
+    }
+
+    /// Unregister a custom compliance program
+    pub fn remove_custom_module(
+        ctx: Context<RemoveCustomModule>,
+        program: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_custom_module::handler(ctx, program)
+    }
+
     /// Set oracle configuration
     pub fn set_oracle_cfg(
         ctx: Context<SetOracleConfig>,
@@ -91,6 +128,14 @@ pub mod srwa_factory {
         instructions::set_oracle_cfg::handler(ctx, pyth_feeds, heartbeat, max_dev_bps, nav_feeder)
     }
 
+    /// Choose the venue idle offering funds are swept into
+    pub fn set_idle_strategy(
+        ctx: Context<SetIdleStrategy>,
+        strategy: IdleStrategy,
+    ) -> Result<()> {
+        instructions::set_idle_strategy::handler(ctx, strategy)
+    }
+
     /// Rotate a role
     pub fn rotate_role(
         ctx: Context<RotateRole>,
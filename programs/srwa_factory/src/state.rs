@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::errors::SRWAError;
 
 /// Main configuration for an SRWA token
 #[account]
@@ -13,6 +14,7 @@ pub struct SRWAConfig {
     pub trusted_issuers_data: Vec<TrustedIssuerEntry>,
     #[max_len(20)]
     pub modules_enabled: Vec<ModuleId>,
+    /// Legacy untyped params; module params now live in `ModuleConfig` accounts
     #[max_len(1000)]
     pub params_by_module: Vec<u8>,
     pub token_controls: TokenControls,
@@ -52,6 +54,133 @@ pub enum ModuleId {
     InvestorLimits,
 }
 
+impl ModuleId {
+    /// Seed prefix of the compliance_modules config account (["<seed>", mint])
+    /// the transfer hook enforces for this module, if any
+    pub fn compliance_config_seed(&self) -> Option<&'static [u8]> {
+        match self {
+            ModuleId::Jurisdiction => Some(b"jurisdiction"),
+            ModuleId::Accredited => Some(b"accredited"),
+            ModuleId::MaxHolders => Some(b"max_holders"),
+            ModuleId::VolumeCaps => Some(b"volume_caps"),
+            ModuleId::TransferWindow => Some(b"transfer_window"),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<u8> for ModuleId {
+    type Error = anchor_lang::error::Error;
+
+    fn try_from(id: u8) -> Result<Self> {
+        match id {
+            0 => Ok(ModuleId::Jurisdiction),
+            1 => Ok(ModuleId::Sanctions),
+            2 => Ok(ModuleId::Accredited),
+            3 => Ok(ModuleId::Lockup),
+            4 => Ok(ModuleId::MaxHolders),
+            5 => Ok(ModuleId::VolumeCaps),
+            6 => Ok(ModuleId::TransferWindow),
+            7 => Ok(ModuleId::ProgramAllowlist),
+            8 => Ok(ModuleId::AccountAllowlist),
+            9 => Ok(ModuleId::OfferingRules),
+            10 => Ok(ModuleId::InvestorLimits),
+            _ => Err(error!(SRWAError::InvalidModule)),
+        }
+    }
+}
+
+/// Typed parameters of a compliance module, one variant per `ModuleId`.
+/// Limits set to 0 are disabled.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
+pub enum ModuleParams {
+    Jurisdiction { require_claim: bool, recipient_only: bool },
+    Sanctions,
+    Accredited { exempt_allowlisted: bool, exempt_kyb: bool },
+    Lockup { min_lockup_seconds: i64 },
+    MaxHolders { max_holders: u32, max_non_accredited: u32 },
+    VolumeCaps { daily_cap: u64, monthly_cap: u64, max_tx: u64 },
+    TransferWindow { utc_offset_minutes: i16 },
+    ProgramAllowlist,
+    AccountAllowlist,
+    OfferingRules { min_ticket: u64, per_investor_cap: u64 },
+    InvestorLimits { daily_volume: u64, position_cap: u64, concentration_bps: u16 },
+}
+
+impl ModuleParams {
+    pub fn module(&self) -> ModuleId {
+        match self {
+            ModuleParams::Jurisdiction { .. } => ModuleId::Jurisdiction,
+            ModuleParams::Sanctions => ModuleId::Sanctions,
+            ModuleParams::Accredited { .. } => ModuleId::Accredited,
+            ModuleParams::Lockup { .. } => ModuleId::Lockup,
+            ModuleParams::MaxHolders { .. } => ModuleId::MaxHolders,
+            ModuleParams::VolumeCaps { .. } => ModuleId::VolumeCaps,
+            ModuleParams::TransferWindow { .. } => ModuleId::TransferWindow,
+            ModuleParams::ProgramAllowlist => ModuleId::ProgramAllowlist,
+            ModuleParams::AccountAllowlist => ModuleId::AccountAllowlist,
+            ModuleParams::OfferingRules { .. } => ModuleId::OfferingRules,
+            ModuleParams::InvestorLimits { .. } => ModuleId::InvestorLimits,
+        }
+    }
+
+    /// Seed prefix of the compliance_modules config account (["<seed>", mint])
+    /// the transfer hook reads these params from, if any
+    pub fn compliance_config_seed(&self) -> Option<&'static [u8]> {
+        self.module().compliance_config_seed()
+    }
+
+    /// Check the params belong to `module` and are internally consistent
+    pub fn validate(&self, module: ModuleId) -> Result<()> {
+        require!(self.module() == module, SRWAError::InvalidModuleParams);
+
+        let valid = match *self {
+            ModuleParams::Lockup { min_lockup_seconds } => min_lockup_seconds >= 0,
+            ModuleParams::MaxHolders { max_holders, max_non_accredited } => {
+                max_holders > 0 && max_non_accredited <= max_holders
+            }
+            ModuleParams::VolumeCaps { daily_cap, monthly_cap, max_tx } => {
+                (daily_cap == 0 || monthly_cap == 0 || daily_cap <= monthly_cap)
+                    && (max_tx == 0 || daily_cap == 0 || max_tx <= daily_cap)
+            }
+            ModuleParams::TransferWindow { utc_offset_minutes } => {
+                (-14 * 60..=14 * 60).contains(&utc_offset_minutes)
+            }
+            ModuleParams::OfferingRules { min_ticket, per_investor_cap } => {
+                per_investor_cap == 0 || min_ticket <= per_investor_cap
+            }
+            ModuleParams::InvestorLimits { concentration_bps, .. } => concentration_bps <= 10_000,
+            _ => true,
+        };
+        require!(valid, SRWAError::InvalidModuleParams);
+
+        Ok(())
+    }
+}
+
+/// Parameters of an enabled module (seeds: ["module_config", mint, module_id])
+#[account]
+#[derive(InitSpace)]
+pub struct ModuleConfig {
+    pub mint: Pubkey,
+    pub module: ModuleId,
+    pub params: ModuleParams,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl ModuleConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"module_config";
+}
+
+/// CPI interface of compliance_modules used to mirror module params
+pub mod compliance_config {
+    /// Anchor discriminator of `apply_module_params` (sha256("global:apply_module_params")[..8])
+    pub const APPLY_MODULE_PARAMS_DISCRIMINATOR: [u8; 8] = [140, 102, 76, 152, 169, 105, 58, 170];
+    /// Anchor discriminator of `close_module_config` (sha256("global:close_module_config")[..8])
+    pub const CLOSE_MODULE_CONFIG_DISCRIMINATOR: [u8; 8] = [6, 93, 218, 66, 215, 184, 176, 250];
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct TokenControls {
     pub default_frozen: bool,
@@ -133,8 +262,9 @@ pub struct Target {
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct Pricing {
     pub model: PricingModel,
-    pub unit_price: u64,
+    pub unit_price: u64, // quote units per whole token
     pub currency: Currency,
+    pub quote_mint: Pubkey, // payment mint, Pubkey::default() = SOL
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
@@ -192,6 +322,7 @@ pub enum IdleStrategy {
     None,
     Marginfi,
     Solend,
+    LendingInterface,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
@@ -265,6 +396,8 @@ pub enum RoleType {
 pub enum YieldProtocol {
     Marginfi,
     Solend,
+    /// Reserve speaking the generic lending interface of `yield_adapter::venue`
+    LendingInterface,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
@@ -379,3 +512,61 @@ pub struct UserRegistry {
     pub is_active: bool,
     pub bump: u8,
 }
+
+/// Custom compliance programs registered for a mint. The transfer hook calls
+/// `check_transfer` on every non-empty slot; empty slots hold the default key.
+/// Created empty by `initialize_custom_modules`, so the mint's
+/// ExtraAccountMetaList can list the slots before any program is added.
+#[account]
+#[derive(InitSpace)]
+pub struct CustomModules {
+    pub mint: Pubkey,
+    pub programs: [Pubkey; CustomModules::MAX_MODULES],
+    pub bump: u8,
+}
+
+impl CustomModules {
+    pub const SEED_PREFIX: &'static [u8] = b"custom_modules";
+    pub const MAX_MODULES: usize = 4;
+    /// Offset of `programs[0]` in the account data (discriminator + mint)
+    pub const PROGRAMS_OFFSET: usize = 8 + 32;
+    /// Extra accounts in the transfer hook layout once the module slots are
+    /// listed (the slots are its last entries)
+    pub const HOOK_EXTRA_METAS: usize = 40;
+    /// Offset of the entry count in an ExtraAccountMetaList account
+    /// (TLV discriminator + length)
+    pub const META_COUNT_OFFSET: usize = 8 + 4;
+
+    pub fn contains(&self, program: &Pubkey) -> bool {
+        self.programs.contains(program)
+    }
+
+    pub fn slot_offset(slot: usize) -> usize {
+        Self::PROGRAMS_OFFSET + slot * 32
+    }
+}
+
+/// Interface every custom compliance program implements. In Anchor this is
+/// `check_transfer(ctx, mint, from, to, amount)` with the mint, source and
+/// destination token accounts as read-only accounts; `from` and `to` are the
+/// token account owners. Returning an error rejects the transfer.
+///
+/// Modules run at CPI depth 3 or more: Token-2022 invokes the hook, which
+/// invokes the module, and transfers made by another program add a level.
+/// That is at or next to the runtime limit, so modules must not CPI. A
+/// rejection aborts the instruction, so `simulate_transfer` cannot report it
+/// as a check result.
+pub mod custom_module {
+    use super::*;
+
+    /// Anchor discriminator of `check_transfer` (sha256("global:check_transfer")[..8])
+    pub const CHECK_TRANSFER_DISCRIMINATOR: [u8; 8] = [181, 98, 3, 219, 143, 70, 25, 215];
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+    pub struct CheckTransferArgs {
+        pub mint: Pubkey,
+        pub from: Pubkey,
+        pub to: Pubkey,
+        pub amount: u64,
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use srwa_factory::state::SRWAConfig;
 
 declare_id!("C4sJ1phqCh2MxFJJqVHZuddXbp6hWfvz29N4CkscPpaW");
 
@@ -39,6 +40,15 @@ pub struct PublishNAV<'info> {
     /// CHECK: Mint account
     pub mint: UncheckedAccount<'info>,
 
+    /// NAV settles redemptions, so only the configured feeder may publish it
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        constraint = nav_feeder.key() == srwa_config.oracle_cfg.nav_feeder @ OracleError::UnauthorizedFeeder
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
     #[account(
         init_if_needed,
         payer = nav_feeder,
@@ -64,3 +74,9 @@ pub struct ValuationData {
     pub last_update: i64,
     pub signer: Pubkey,
 }
+
+#[error_code]
+pub enum OracleError {
+    #[msg("Signer is not the configured NAV feeder")]
+    UnauthorizedFeeder,
+}
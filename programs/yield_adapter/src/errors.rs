@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum YieldAdapterError {
+    #[msg("Unauthorized")]
+    Unauthorized,
+
+    #[msg("Venue does not serve the offering's idle strategy")]
+    StrategyMismatch,
+
+    #[msg("Offering phase does not allow deploying idle funds")]
+    InvalidPhase,
+
+    #[msg("Invalid amount")]
+    InvalidAmount,
+
+    #[msg("Invalid lending venue")]
+    InvalidVenue,
+
+    #[msg("Position does not hold enough venue shares")]
+    InsufficientShares,
+
+    #[msg("Sharing ratio must add up to 10000 bps")]
+    InvalidSharingRatio,
+
+    #[msg("No yield to skim")]
+    NoYield,
+
+    #[msg("Deposit would exceed the venue cap for this mint")]
+    VenueCapExceeded,
+
+    #[msg("Not a supported Raydium AMM program")]
+    UnsupportedAmm,
+
+    #[msg("Pool vault does not belong to the declared pool")]
+    InvalidPoolVault,
+
+    #[msg("Math overflow")]
+    MathOverflow,
+
+    #[msg("Accrued yield must be skimmed before withdrawing")]
+    YieldNotSkimmed,
+
+    #[msg("No adapter exists for this yield protocol yet")]
+    UnsupportedProtocol,
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use srwa_factory::state::YieldProtocol;
+
+#[event]
+pub struct VenueRegistered {
+    pub venue: Pubkey,
+    pub protocol: YieldProtocol,
+    pub lending_program: Pubkey,
+    pub reserve: Pubkey,
+    pub liquidity_mint: Pubkey,
+}
+
+#[event]
+pub struct PositionOpened {
+    pub mint: Pubkey,
+    pub venue: Pubkey,
+    pub position: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct VenueCapUpdated {
+    pub mint: Pubkey,
+    pub venue: Pubkey,
+    pub old_cap: u64,
+    pub new_cap: u64,
+}
+
+#[event]
+pub struct IdleFundsDeployed {
+    pub mint: Pubkey,
+    pub venue: Pubkey,
+    pub amount: u64,
+    pub shares_minted: u64,
+    pub principal: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IdleFundsRecalled {
+    pub mint: Pubkey,
+    pub venue: Pubkey,
+    pub shares_redeemed: u64,
+    pub amount: u64,
+    pub principal_returned: u64,
+    pub yield_returned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct YieldSharingConfigured {
+    pub mint: Pubkey,
+    pub target_apy_bps: u32,
+    pub issuer_bps: u16,
+    pub platform_bps: u16,
+    pub subscriber_bps: u16,
+    pub subscriber_vault: Pubkey,
+}
+
+#[event]
+pub struct YieldSkimmed {
+    pub mint: Pubkey,
+    pub venue: Pubkey,
+    pub principal: u64,
+    pub venue_value: u64,
+    pub shares_redeemed: u64,
+    pub realized_yield: u64,
+    pub target_yield: u64,
+    pub issuer_amount: u64,
+    pub platform_amount: u64,
+    pub subscriber_amount: u64,
+    pub issuer_treasury: Pubkey,
+    pub fee_treasury: Pubkey,
+    pub subscriber_vault: Pubkey,
+    pub period_start: i64,
+    pub period_end: i64,
+}
+
+#[event]
+pub struct RaydiumPoolRegistered {
+    pub mint: Pubkey,
+    pub pool_id: Pubkey,
+    pub amm_program: Pubkey,
+    pub base_mint: Pubkey,
+    pub token_vault: Pubkey,
+    pub base_vault: Pubkey,
+    pub registered_by: Pubkey,
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use srwa_factory::state::SRWAConfig;
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+pub struct ConfigureYieldSharing<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: SRWA mint, used as a seed
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin @ YieldAdapterError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + YieldSharingConfig::INIT_SPACE,
+        seeds = [YieldSharingConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump
+    )]
+    pub sharing_config: Account<'info, YieldSharingConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureYieldSharing>,
+    target_apy_bps: u32,
+    issuer_bps: u16,
+    platform_bps: u16,
+    subscriber_bps: u16,
+    subscriber_vault: Pubkey,
+) -> Result<()> {
+    require!(
+        issuer_bps as u64 + platform_bps as u64 + subscriber_bps as u64 == BPS_DENOMINATOR,
+        YieldAdapterError::InvalidSharingRatio
+    );
+
+    let sharing_config = &mut ctx.accounts.sharing_config;
+    sharing_config.mint = ctx.accounts.mint.key();
+    sharing_config.target_apy_bps = target_apy_bps;
+    sharing_config.issuer_bps = issuer_bps;
+    sharing_config.platform_bps = platform_bps;
+    sharing_config.subscriber_bps = subscriber_bps;
+    sharing_config.subscriber_vault = subscriber_vault;
+    sharing_config.updated_by = ctx.accounts.authority.key();
+    sharing_config.updated_at = Clock::get()?.unix_timestamp;
+    sharing_config.bump = ctx.bumps.sharing_config;
+
+    emit!(YieldSharingConfigured {
+        mint: sharing_config.mint,
+        target_apy_bps,
+        issuer_bps,
+        platform_bps,
+        subscriber_bps,
+        subscriber_vault,
+    });
+
+    msg!(
+        "Yield sharing: target {}bps, issuer {}bps, platform {}bps, subscribers {}bps",
+        target_apy_bps,
+        issuer_bps,
+        platform_bps,
+        subscriber_bps
+    );
+
+    Ok(())
+}
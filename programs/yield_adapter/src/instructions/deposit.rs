@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use srwa_factory::state::{OfferingPhase, OfferingState, SRWAConfig};
+use crate::{state::*, events::*, errors::*, venue::*};
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: SRWA mint, used as a seed
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_yield_operator(&authority.key(), &srwa_config) @ YieldAdapterError::Unauthorized
+    )]
+    pub srwa_config: Box<Account<'info, SRWAConfig>>,
+
+    #[account(
+        seeds = [b"offering", mint.key().as_ref()],
+        bump = offering_state.bump,
+        seeds::program = srwa_factory::ID,
+        constraint = venue.serves(offering_state.idle_strategy) @ YieldAdapterError::StrategyMismatch
+    )]
+    pub offering_state: Account<'info, OfferingState>,
+
+    #[account(
+        seeds = [VenueConfig::SEED_PREFIX, venue.reserve.as_ref()],
+        bump = venue.bump,
+        has_one = reserve,
+        has_one = liquidity_mint,
+        has_one = liquidity_vault,
+        has_one = collateral_mint,
+    )]
+    pub venue: Account<'info, VenueConfig>,
+
+    #[account(
+        mut,
+        seeds = [YieldPosition::SEED_PREFIX, mint.key().as_ref(), venue.key().as_ref()],
+        bump = position.bump,
+        has_one = collateral_account,
+    )]
+    pub position: Account<'info, YieldPosition>,
+
+    /// CHECK: Validated against the venue config by `load_venue`
+    pub lending_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against the venue config
+    pub reserve: UncheckedAccount<'info>,
+
+    pub liquidity_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub liquidity_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    /// Idle offering funds to sweep
+    #[account(
+        mut,
+        token::mint = liquidity_mint,
+        token::authority = authority,
+    )]
+    pub source_liquidity: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub collateral_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+    require!(amount > 0, YieldAdapterError::InvalidAmount);
+    require!(
+        !matches!(
+            ctx.accounts.offering_state.phase,
+            OfferingPhase::Settlement | OfferingPhase::Refund
+        ),
+        YieldAdapterError::InvalidPhase
+    );
+
+    let principal_after = ctx
+        .accounts
+        .position
+        .principal
+        .checked_add(amount)
+        .ok_or(YieldAdapterError::MathOverflow)?;
+    require!(
+        principal_after <= ctx.accounts.position.deposit_cap,
+        YieldAdapterError::VenueCapExceeded
+    );
+
+    let shares_before = ctx.accounts.collateral_account.amount;
+
+    let venue = load_venue(
+        &ctx.accounts.venue,
+        VenueAccounts {
+            lending_program: ctx.accounts.lending_program.to_account_info(),
+            reserve: ctx.accounts.reserve.to_account_info(),
+            liquidity_vault: ctx.accounts.liquidity_vault.to_account_info(),
+            collateral_mint: ctx.accounts.collateral_mint.to_account_info(),
+            user_liquidity: ctx.accounts.source_liquidity.to_account_info(),
+            user_collateral: ctx.accounts.collateral_account.to_account_info(),
+            liquidity_mint: ctx.accounts.liquidity_mint.to_account_info(),
+            user_authority: ctx.accounts.authority.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    )?;
+    venue.deposit(amount, &[])?;
+
+    ctx.accounts.collateral_account.reload()?;
+    let shares_minted = ctx.accounts.collateral_account.amount - shares_before;
+
+    let clock = Clock::get()?;
+    let position = &mut ctx.accounts.position;
+    if position.principal == 0 {
+        // Target APY accrues from the moment funds are deployed
+        position.last_skim_ts = clock.unix_timestamp;
+    }
+    position.principal = principal_after;
+    position.updated_at = clock.unix_timestamp;
+
+    emit!(IdleFundsDeployed {
+        mint: position.mint,
+        venue: position.venue,
+        amount,
+        shares_minted,
+        principal: position.principal,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Deployed {} idle funds ({} shares)", amount, shares_minted);
+
+    Ok(())
+}
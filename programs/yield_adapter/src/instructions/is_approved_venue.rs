@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use compliance_modules::state::ProgramAllowlist;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct IsApprovedVenue<'info> {
+    #[account(
+        seeds = [b"raydium_pool", pool_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub pool_account: Account<'info, RaydiumPoolAccount>,
+
+    #[account(
+        seeds = [b"program_allowlist", pool_account.token_mint.as_ref()],
+        bump = program_allowlist.bump,
+        seeds::program = compliance_modules::ID,
+    )]
+    pub program_allowlist: Account<'info, ProgramAllowlist>,
+}
+
+/// Whether `program` is an active, allowlisted venue for the pool's mint
+pub fn handler(ctx: Context<IsApprovedVenue>, program: Pubkey) -> Result<bool> {
+    let pool_account = &ctx.accounts.pool_account;
+    let approved = pool_account.is_active
+        && pool_account.amm_program == program
+        && ctx.accounts.program_allowlist.contains(&program);
+
+    msg!("Venue {} approved for {}: {}", program, pool_account.token_mint, approved);
+
+    Ok(approved)
+}
@@ -0,0 +1,21 @@
+pub mod register_venue;
+pub mod open_position;
+pub mod set_venue_cap;
+pub mod deposit;
+pub mod withdraw;
+pub mod configure_yield_sharing;
+pub mod skim_yield;
+pub mod register_raydium_pool;
+pub mod update_pool_status;
+pub mod is_approved_venue;
+
+pub use register_venue::*;
+pub use open_position::*;
+pub use set_venue_cap::*;
+pub use deposit::*;
+pub use withdraw::*;
+pub use configure_yield_sharing::*;
+pub use skim_yield::*;
+pub use register_raydium_pool::*;
+pub use update_pool_status::*;
+pub use is_approved_venue::*;
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use srwa_factory::state::{OfferingState, SRWAConfig};
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+pub struct OpenPosition<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: SRWA mint, used as a seed
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_yield_operator(&authority.key(), &srwa_config) @ YieldAdapterError::Unauthorized
+    )]
+    pub srwa_config: Box<Account<'info, SRWAConfig>>,
+
+    #[account(
+        seeds = [b"offering", mint.key().as_ref()],
+        bump = offering_state.bump,
+        seeds::program = srwa_factory::ID,
+        constraint = venue.serves(offering_state.idle_strategy) @ YieldAdapterError::StrategyMismatch
+    )]
+    pub offering_state: Account<'info, OfferingState>,
+
+    #[account(
+        seeds = [VenueConfig::SEED_PREFIX, venue.reserve.as_ref()],
+        bump = venue.bump,
+        has_one = collateral_mint,
+        has_one = liquidity_mint,
+    )]
+    pub venue: Account<'info, VenueConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + YieldPosition::INIT_SPACE,
+        seeds = [YieldPosition::SEED_PREFIX, mint.key().as_ref(), venue.key().as_ref()],
+        bump
+    )]
+    pub position: Account<'info, YieldPosition>,
+
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [YieldPosition::COLLATERAL_SEED_PREFIX, position.key().as_ref()],
+        bump,
+        token::mint = collateral_mint,
+        token::authority = position,
+        token::token_program = token_program,
+    )]
+    pub collateral_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub liquidity_mint: InterfaceAccount<'info, Mint>,
+
+    /// Holds redeemed yield while it is split between recipients
+    #[account(
+        init,
+        payer = authority,
+        seeds = [YieldPosition::YIELD_BUFFER_SEED_PREFIX, position.key().as_ref()],
+        bump,
+        token::mint = liquidity_mint,
+        token::authority = position,
+        token::token_program = token_program,
+    )]
+    pub yield_buffer: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenPosition>) -> Result<()> {
+    let clock = Clock::get()?;
+    let position = &mut ctx.accounts.position;
+
+    position.mint = ctx.accounts.mint.key();
+    position.venue = ctx.accounts.venue.key();
+    position.authority = ctx.accounts.authority.key();
+    position.collateral_account = ctx.accounts.collateral_account.key();
+    position.principal = 0;
+    position.deposit_cap = 0;
+    position.realized_yield = 0;
+    position.last_skim_ts = clock.unix_timestamp;
+    position.created_at = clock.unix_timestamp;
+    position.updated_at = clock.unix_timestamp;
+    position.bump = ctx.bumps.position;
+
+    emit!(PositionOpened {
+        mint: position.mint,
+        venue: position.venue,
+        position: position.key(),
+        authority: position.authority,
+    });
+
+    msg!("Yield position opened for {}", position.mint);
+
+    Ok(())
+}
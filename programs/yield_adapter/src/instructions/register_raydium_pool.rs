@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use compliance_modules::program::ComplianceModules;
+use srwa_factory::state::{PlatformAdminRegistry, SRWAConfig};
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+#[instruction(pool_id: Pubkey, base_mint: Pubkey)]
+pub struct RegisterRaydiumPool<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + RaydiumPoolAccount::SPACE,
+        seeds = [b"raydium_pool", token_mint.key().as_ref()],
+        bump
+    )]
+    pub pool_account: Account<'info, RaydiumPoolAccount>,
+
+    /// The SRWA token mint that will be paired in the pool
+    /// CHECK: This is just used as a seed, not accessed
+    pub token_mint: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", token_mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        constraint = srwa_config.mint == token_mint.key() @ YieldAdapterError::Unauthorized,
+        constraint = is_venue_admin(&admin.key(), &srwa_config, admin_registry.as_deref()) @ YieldAdapterError::Unauthorized
+    )]
+    pub srwa_config: Box<Account<'info, SRWAConfig>>,
+
+    /// Required when registering as a platform admin
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub admin_registry: Option<Account<'info, PlatformAdminRegistry>>,
+
+    /// CHECK: Raydium AMM program, checked against the supported list
+    #[account(
+        executable,
+        constraint = RAYDIUM_AMM_PROGRAMS.contains(&amm_program.key()) @ YieldAdapterError::UnsupportedAmm
+    )]
+    pub amm_program: UncheckedAccount<'info>,
+
+    /// CHECK: Pool state, must be owned by the AMM program
+    #[account(
+        address = pool_id,
+        owner = amm_program.key() @ YieldAdapterError::UnsupportedAmm
+    )]
+    pub pool_state: UncheckedAccount<'info>,
+
+    #[account(
+        token::mint = token_mint,
+        address = RaydiumPoolAccount::vault_address(&amm_program.key(), &pool_id, &token_mint.key())
+            @ YieldAdapterError::InvalidPoolVault
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        token::mint = base_mint,
+        address = RaydiumPoolAccount::vault_address(&amm_program.key(), &pool_id, &base_mint)
+            @ YieldAdapterError::InvalidPoolVault
+    )]
+    pub base_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Mint's ProgramAllowlist, created by compliance_modules
+    #[account(mut)]
+    pub program_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: Mint's AccountAllowlist, created by compliance_modules
+    #[account(mut)]
+    pub account_allowlist: UncheckedAccount<'info>,
+
+    pub compliance_program: Program<'info, ComplianceModules>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterRaydiumPool>,
+    pool_id: Pubkey,
+    base_mint: Pubkey,
+) -> Result<()> {
+    // Swaps move SRWA in and out of the vault, so the transfer hook must
+    // recognise it as an approved venue account
+    compliance_modules::cpi::allowlist_venue(
+        CpiContext::new(
+            ctx.accounts.compliance_program.to_account_info(),
+            compliance_modules::cpi::accounts::AllowlistVenue {
+                authority: ctx.accounts.admin.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
+                srwa_config: ctx.accounts.srwa_config.to_account_info(),
+                admin_registry: ctx
+                    .accounts
+                    .admin_registry
+                    .as_ref()
+                    .map(|registry| registry.to_account_info()),
+                program_allowlist: ctx.accounts.program_allowlist.to_account_info(),
+                account_allowlist: ctx.accounts.account_allowlist.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+        ),
+        ctx.accounts.amm_program.key(),
+        vec![ctx.accounts.token_vault.key(), ctx.accounts.base_vault.key()],
+    )?;
+
+    let pool_account = &mut ctx.accounts.pool_account;
+    let clock = Clock::get()?;
+
+    pool_account.admin = ctx.accounts.admin.key();
+    pool_account.pool_id = pool_id;
+    pool_account.token_mint = ctx.accounts.token_mint.key();
+    pool_account.base_mint = base_mint;
+    pool_account.created_at = clock.unix_timestamp;
+    pool_account.is_active = true;
+    pool_account.amm_program = ctx.accounts.amm_program.key();
+    pool_account.token_vault = ctx.accounts.token_vault.key();
+    pool_account.base_vault = ctx.accounts.base_vault.key();
+
+    emit!(RaydiumPoolRegistered {
+        mint: pool_account.token_mint,
+        pool_id,
+        amm_program: pool_account.amm_program,
+        base_mint,
+        token_vault: pool_account.token_vault,
+        base_vault: pool_account.base_vault,
+        registered_by: pool_account.admin,
+    });
+
+    msg!(
+        "Raydium pool registered: pool_id={}, token={}, base={}",
+        pool_id,
+        pool_account.token_mint,
+        base_mint
+    );
+
+    Ok(())
+}
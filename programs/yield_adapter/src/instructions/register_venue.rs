@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use srwa_factory::state::{PlatformAdminRegistry, YieldProtocol};
+use crate::{state::*, events::*, errors::*, venue::is_supported};
+
+#[derive(Accounts)]
+pub struct RegisterVenue<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub admin_registry: Account<'info, PlatformAdminRegistry>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + VenueConfig::INIT_SPACE,
+        seeds = [VenueConfig::SEED_PREFIX, reserve.key().as_ref()],
+        bump
+    )]
+    pub venue: Account<'info, VenueConfig>,
+
+    /// CHECK: Lending program implementing the generic reserve interface
+    #[account(executable)]
+    pub lending_program: UncheckedAccount<'info>,
+
+    /// CHECK: Reserve account, must belong to the lending program
+    #[account(owner = lending_program.key() @ YieldAdapterError::InvalidVenue)]
+    pub reserve: UncheckedAccount<'info>,
+
+    pub liquidity_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = liquidity_mint)]
+    pub liquidity_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterVenue>, protocol: YieldProtocol) -> Result<()> {
+    let admin_registry = &ctx.accounts.admin_registry;
+    let admin = ctx.accounts.admin.key();
+    require!(
+        admin == admin_registry.super_admin || admin_registry.authorized_admins.contains(&admin),
+        YieldAdapterError::Unauthorized
+    );
+    require!(is_supported(protocol), YieldAdapterError::UnsupportedProtocol);
+
+    let venue = &mut ctx.accounts.venue;
+    venue.protocol = protocol;
+    venue.lending_program = ctx.accounts.lending_program.key();
+    venue.reserve = ctx.accounts.reserve.key();
+    venue.liquidity_mint = ctx.accounts.liquidity_mint.key();
+    venue.liquidity_vault = ctx.accounts.liquidity_vault.key();
+    venue.collateral_mint = ctx.accounts.collateral_mint.key();
+    venue.registered_by = admin;
+    venue.created_at = Clock::get()?.unix_timestamp;
+    venue.bump = ctx.bumps.venue;
+
+    emit!(VenueRegistered {
+        venue: venue.key(),
+        protocol,
+        lending_program: venue.lending_program,
+        reserve: venue.reserve,
+        liquidity_mint: venue.liquidity_mint,
+    });
+
+    msg!("Yield venue registered: reserve={}", venue.reserve);
+
+    Ok(())
+}
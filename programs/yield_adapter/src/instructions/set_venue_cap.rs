@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use srwa_factory::state::SRWAConfig;
+use crate::{state::*, events::*, errors::*};
+
+#[derive(Accounts)]
+pub struct SetVenueCap<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: SRWA mint, used as a seed
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = authority.key() == srwa_config.roles.issuer_admin @ YieldAdapterError::Unauthorized
+    )]
+    pub srwa_config: Account<'info, SRWAConfig>,
+
+    #[account(
+        mut,
+        seeds = [YieldPosition::SEED_PREFIX, mint.key().as_ref(), position.venue.as_ref()],
+        bump = position.bump,
+    )]
+    pub position: Account<'info, YieldPosition>,
+}
+
+pub fn handler(ctx: Context<SetVenueCap>, cap: u64) -> Result<()> {
+    let position = &mut ctx.accounts.position;
+    let old_cap = position.deposit_cap;
+    position.deposit_cap = cap;
+    position.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(VenueCapUpdated {
+        mint: position.mint,
+        venue: position.venue,
+        old_cap,
+        new_cap: cap,
+    });
+
+    msg!("Venue cap updated: {} -> {}", old_cap, cap);
+
+    Ok(())
+}
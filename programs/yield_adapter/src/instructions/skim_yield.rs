@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use srwa_factory::state::{OfferingState, SRWAConfig};
+use crate::{state::*, events::*, errors::*, venue::*};
+
+#[derive(Accounts)]
+pub struct SkimYield<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: SRWA mint, used as a seed
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_yield_operator(&authority.key(), &srwa_config) @ YieldAdapterError::Unauthorized
+    )]
+    pub srwa_config: Box<Account<'info, SRWAConfig>>,
+
+    #[account(
+        seeds = [b"offering", mint.key().as_ref()],
+        bump = offering_state.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub offering_state: Box<Account<'info, OfferingState>>,
+
+    #[account(
+        seeds = [YieldSharingConfig::SEED_PREFIX, mint.key().as_ref()],
+        bump = sharing_config.bump,
+        has_one = subscriber_vault,
+    )]
+    pub sharing_config: Box<Account<'info, YieldSharingConfig>>,
+
+    #[account(
+        seeds = [VenueConfig::SEED_PREFIX, venue.reserve.as_ref()],
+        bump = venue.bump,
+        has_one = reserve,
+        has_one = liquidity_mint,
+        has_one = liquidity_vault,
+        has_one = collateral_mint,
+    )]
+    pub venue: Box<Account<'info, VenueConfig>>,
+
+    #[account(
+        mut,
+        seeds = [YieldPosition::SEED_PREFIX, mint.key().as_ref(), venue.key().as_ref()],
+        bump = position.bump,
+        has_one = collateral_account,
+    )]
+    pub position: Box<Account<'info, YieldPosition>>,
+
+    /// CHECK: Validated against the venue config by `load_venue`
+    pub lending_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against the venue config
+    pub reserve: UncheckedAccount<'info>,
+
+    pub liquidity_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub liquidity_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub collateral_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub collateral_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [YieldPosition::YIELD_BUFFER_SEED_PREFIX, position.key().as_ref()],
+        bump,
+    )]
+    pub yield_buffer: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = liquidity_mint,
+        token::authority = offering_state.settlement.issuer_treasury,
+    )]
+    pub issuer_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = liquidity_mint,
+        token::authority = offering_state.settlement.fee_treasury,
+    )]
+    pub fee_treasury: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = liquidity_mint)]
+    pub subscriber_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Redeem everything above principal and split it between the issuer
+/// treasury, the platform fee treasury and subscribers
+pub fn handler(ctx: Context<SkimYield>) -> Result<()> {
+    let total_shares = ctx.accounts.collateral_account.amount;
+    let principal = ctx.accounts.position.principal;
+
+    let mint_key = ctx.accounts.mint.key();
+    let venue_key = ctx.accounts.venue.key();
+    let position_bump = ctx.accounts.position.bump;
+    let position_seeds: &[&[u8]] = &[
+        YieldPosition::SEED_PREFIX,
+        mint_key.as_ref(),
+        venue_key.as_ref(),
+        &[position_bump],
+    ];
+    let signer_seeds = &[position_seeds];
+
+    let venue = load_venue(
+        &ctx.accounts.venue,
+        VenueAccounts {
+            lending_program: ctx.accounts.lending_program.to_account_info(),
+            reserve: ctx.accounts.reserve.to_account_info(),
+            liquidity_vault: ctx.accounts.liquidity_vault.to_account_info(),
+            collateral_mint: ctx.accounts.collateral_mint.to_account_info(),
+            user_liquidity: ctx.accounts.yield_buffer.to_account_info(),
+            user_collateral: ctx.accounts.collateral_account.to_account_info(),
+            liquidity_mint: ctx.accounts.liquidity_mint.to_account_info(),
+            user_authority: ctx.accounts.position.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    )?;
+
+    let venue_value = venue.value_of(total_shares)?;
+    let accrued = venue_value.saturating_sub(principal);
+    require!(accrued > 0, YieldAdapterError::NoYield);
+
+    // Shares worth the accrued yield, rounded down so principal stays deployed
+    let shares = ((total_shares as u128) * (accrued as u128) / (venue_value as u128)) as u64;
+    require!(shares > 0, YieldAdapterError::NoYield);
+
+    let buffer_before = ctx.accounts.yield_buffer.amount;
+    venue.withdraw(shares, signer_seeds)?;
+    ctx.accounts.yield_buffer.reload()?;
+    let realized = ctx.accounts.yield_buffer.amount - buffer_before;
+
+    let clock = Clock::get()?;
+    let period_start = ctx.accounts.position.last_skim_ts;
+    let split = ctx
+        .accounts
+        .sharing_config
+        .split(realized, principal, clock.unix_timestamp - period_start);
+
+    let decimals = ctx.accounts.liquidity_mint.decimals;
+    for (to, amount) in [
+        (ctx.accounts.issuer_treasury.to_account_info(), split.issuer_amount),
+        (ctx.accounts.fee_treasury.to_account_info(), split.platform_amount),
+        (ctx.accounts.subscriber_vault.to_account_info(), split.subscriber_amount),
+    ] {
+        if amount == 0 {
+            continue;
+        }
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.yield_buffer.to_account_info(),
+                    mint: ctx.accounts.liquidity_mint.to_account_info(),
+                    to,
+                    authority: ctx.accounts.position.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            decimals,
+        )?;
+    }
+
+    let position = &mut ctx.accounts.position;
+    position.realized_yield = position
+        .realized_yield
+        .checked_add(realized)
+        .ok_or(YieldAdapterError::MathOverflow)?;
+    position.last_skim_ts = clock.unix_timestamp;
+    position.updated_at = clock.unix_timestamp;
+
+    emit!(YieldSkimmed {
+        mint: position.mint,
+        venue: position.venue,
+        principal,
+        venue_value,
+        shares_redeemed: shares,
+        realized_yield: realized,
+        target_yield: split.target_amount,
+        issuer_amount: split.issuer_amount,
+        platform_amount: split.platform_amount,
+        subscriber_amount: split.subscriber_amount,
+        issuer_treasury: ctx.accounts.issuer_treasury.key(),
+        fee_treasury: ctx.accounts.fee_treasury.key(),
+        subscriber_vault: ctx.accounts.subscriber_vault.key(),
+        period_start,
+        period_end: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Yield skimmed: {} (issuer {}, platform {}, subscribers {})",
+        realized,
+        split.issuer_amount,
+        split.platform_amount,
+        split.subscriber_amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use compliance_modules::program::ComplianceModules;
+use srwa_factory::state::{PlatformAdminRegistry, SRWAConfig};
+use crate::{state::*, errors::*};
+
+#[derive(Accounts)]
+pub struct UpdatePoolStatus<'info> {
+    #[account(
+        mut,
+        seeds = [b"raydium_pool", pool_account.token_mint.as_ref()],
+        bump,
+        has_one = token_mint
+    )]
+    pub pool_account: Account<'info, RaydiumPoolAccount>,
+
+    /// CHECK: SRWA token mint of the pool, checked against pool_account
+    pub token_mint: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", token_mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        constraint = srwa_config.mint == token_mint.key() @ YieldAdapterError::Unauthorized,
+        constraint = is_venue_admin(&admin.key(), &srwa_config, admin_registry.as_deref()) @ YieldAdapterError::Unauthorized
+    )]
+    pub srwa_config: Box<Account<'info, SRWAConfig>>,
+
+    /// Required when updating as a platform admin
+    #[account(
+        seeds = [b"admin_registry"],
+        bump = admin_registry.bump,
+        seeds::program = srwa_factory::ID,
+    )]
+    pub admin_registry: Option<Account<'info, PlatformAdminRegistry>>,
+
+    /// CHECK: Mint's ProgramAllowlist, created by compliance_modules
+    #[account(mut)]
+    pub program_allowlist: UncheckedAccount<'info>,
+
+    /// CHECK: Mint's AccountAllowlist, created by compliance_modules
+    #[account(mut)]
+    pub account_allowlist: UncheckedAccount<'info>,
+
+    pub compliance_program: Program<'info, ComplianceModules>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<UpdatePoolStatus>,
+    is_active: bool,
+) -> Result<()> {
+    let pool_account = &ctx.accounts.pool_account;
+    let vaults = vec![pool_account.token_vault, pool_account.base_vault];
+    let admin_registry = ctx
+        .accounts
+        .admin_registry
+        .as_ref()
+        .map(|registry| registry.to_account_info());
+
+    // The hook exempts allowlisted vaults from per-user checks, so they are
+    // only listed while the pool is active
+    if is_active {
+        compliance_modules::cpi::allowlist_venue(
+            CpiContext::new(
+                ctx.accounts.compliance_program.to_account_info(),
+                compliance_modules::cpi::accounts::AllowlistVenue {
+                    authority: ctx.accounts.admin.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    srwa_config: ctx.accounts.srwa_config.to_account_info(),
+                    admin_registry,
+                    program_allowlist: ctx.accounts.program_allowlist.to_account_info(),
+                    account_allowlist: ctx.accounts.account_allowlist.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+            ),
+            pool_account.amm_program,
+            vaults,
+        )?;
+    } else {
+        compliance_modules::cpi::delist_venue_accounts(
+            CpiContext::new(
+                ctx.accounts.compliance_program.to_account_info(),
+                compliance_modules::cpi::accounts::DelistVenueAccounts {
+                    authority: ctx.accounts.admin.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    srwa_config: ctx.accounts.srwa_config.to_account_info(),
+                    admin_registry,
+                    account_allowlist: ctx.accounts.account_allowlist.to_account_info(),
+                },
+            ),
+            vaults,
+        )?;
+    }
+
+    let pool_account = &mut ctx.accounts.pool_account;
+    pool_account.is_active = is_active;
+
+    msg!(
+        "Pool {} status updated to: {}",
+        pool_account.pool_id,
+        is_active
+    );
+
+    Ok(())
+}
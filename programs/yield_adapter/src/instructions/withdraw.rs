@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use srwa_factory::state::SRWAConfig;
+use crate::{state::*, events::*, errors::*, venue::*};
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: SRWA mint, used as a seed
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"srwa_config", mint.key().as_ref()],
+        bump = srwa_config.bump,
+        seeds::program = srwa_factory::ID,
+        has_one = mint,
+        constraint = is_yield_operator(&authority.key(), &srwa_config) @ YieldAdapterError::Unauthorized
+    )]
+    pub srwa_config: Box<Account<'info, SRWAConfig>>,
+
+    #[account(
+        seeds = [VenueConfig::SEED_PREFIX, venue.reserve.as_ref()],
+        bump = venue.bump,
+        has_one = reserve,
+        has_one = liquidity_mint,
+        has_one = liquidity_vault,
+        has_one = collateral_mint,
+    )]
+    pub venue: Account<'info, VenueConfig>,
+
+    #[account(
+        mut,
+        seeds = [YieldPosition::SEED_PREFIX, mint.key().as_ref(), venue.key().as_ref()],
+        bump = position.bump,
+        has_one = collateral_account,
+    )]
+    pub position: Account<'info, YieldPosition>,
+
+    /// CHECK: Validated against the venue config by `load_venue`
+    pub lending_program: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against the venue config
+    pub reserve: UncheckedAccount<'info>,
+
+    pub liquidity_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub liquidity_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub collateral_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub collateral_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Funds return to the account they were swept from
+    #[account(
+        mut,
+        token::mint = liquidity_mint,
+        token::authority = position.authority,
+    )]
+    pub destination_liquidity: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Redeem `shares` (all of them when `None`) back to the position authority.
+/// Only principal leaves this way: accrued yield has to be skimmed first so it
+/// goes through the sharing split.
+pub fn handler(ctx: Context<Withdraw>, shares: Option<u64>) -> Result<()> {
+    let total_shares = ctx.accounts.collateral_account.amount;
+    let shares = shares.unwrap_or(total_shares);
+    require!(shares > 0, YieldAdapterError::InvalidAmount);
+    require!(shares <= total_shares, YieldAdapterError::InsufficientShares);
+
+    let liquidity_before = ctx.accounts.destination_liquidity.amount;
+
+    let mint_key = ctx.accounts.mint.key();
+    let venue_key = ctx.accounts.venue.key();
+    let position_seeds: &[&[u8]] = &[
+        YieldPosition::SEED_PREFIX,
+        mint_key.as_ref(),
+        venue_key.as_ref(),
+        &[ctx.accounts.position.bump],
+    ];
+
+    let venue = load_venue(
+        &ctx.accounts.venue,
+        VenueAccounts {
+            lending_program: ctx.accounts.lending_program.to_account_info(),
+            reserve: ctx.accounts.reserve.to_account_info(),
+            liquidity_vault: ctx.accounts.liquidity_vault.to_account_info(),
+            collateral_mint: ctx.accounts.collateral_mint.to_account_info(),
+            user_liquidity: ctx.accounts.destination_liquidity.to_account_info(),
+            user_collateral: ctx.accounts.collateral_account.to_account_info(),
+            liquidity_mint: ctx.accounts.liquidity_mint.to_account_info(),
+            user_authority: ctx.accounts.position.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    )?;
+
+    // Anything above principal worth at least one share belongs to the split
+    let venue_value = venue.value_of(total_shares)?;
+    let accrued = venue_value.saturating_sub(ctx.accounts.position.principal);
+    if accrued > 0 {
+        let yield_shares = (total_shares as u128) * (accrued as u128) / (venue_value as u128);
+        require!(yield_shares == 0, YieldAdapterError::YieldNotSkimmed);
+    }
+
+    venue.withdraw(shares, &[position_seeds])?;
+
+    ctx.accounts.destination_liquidity.reload()?;
+    let amount = ctx.accounts.destination_liquidity.amount - liquidity_before;
+
+    // Principal leaves in proportion to the shares redeemed, the rest is yield
+    let position = &mut ctx.accounts.position;
+    let principal_returned = ((position.principal as u128) * (shares as u128)
+        / (total_shares as u128)) as u64;
+    position.principal -= principal_returned;
+    let yield_returned = amount.saturating_sub(principal_returned);
+    position.realized_yield = position
+        .realized_yield
+        .checked_add(yield_returned)
+        .ok_or(YieldAdapterError::MathOverflow)?;
+
+    let clock = Clock::get()?;
+    position.updated_at = clock.unix_timestamp;
+
+    emit!(IdleFundsRecalled {
+        mint: position.mint,
+        venue: position.venue,
+        shares_redeemed: shares,
+        amount,
+        principal_returned,
+        yield_returned,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Recalled {} from venue (principal {}, yield {})",
+        amount,
+        principal_returned,
+        yield_returned
+    );
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use srwa_factory::state::{IdleStrategy, PlatformAdminRegistry, SRWAConfig, YieldProtocol};
+
+pub const SECONDS_PER_YEAR: i64 = 365 * 24 * 60 * 60;
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Issuer admin of the mint or a platform admin
+pub fn is_venue_admin(
+    authority: &Pubkey,
+    srwa_config: &SRWAConfig,
+    admin_registry: Option<&PlatformAdminRegistry>,
+) -> bool {
+    *authority == srwa_config.roles.issuer_admin
+        || admin_registry.is_some_and(|registry| {
+            registry.super_admin == *authority || registry.authorized_admins.contains(authority)
+        })
+}
+
+/// Only the issuer admin may move offering funds
+pub fn is_yield_operator(authority: &Pubkey, srwa_config: &SRWAConfig) -> bool {
+    *authority == srwa_config.roles.issuer_admin
+}
+
+/// Account for each registered Raydium pool
+#[account]
+pub struct RaydiumPoolAccount {
+    pub admin: Pubkey,       // 32
+    pub pool_id: Pubkey,     // 32 - Raydium pool address
+    pub token_mint: Pubkey,  // 32 - SRWA token
+    pub base_mint: Pubkey,   // 32 - Base token (SOL, USDC, etc)
+    pub created_at: i64,     // 8
+    pub is_active: bool,     // 1
+    pub amm_program: Pubkey, // 32 - Raydium CPMM/CLMM program owning the pool
+    pub token_vault: Pubkey, // 32 - pool vault holding the SRWA token
+    pub base_vault: Pubkey,  // 32 - pool vault holding the base token
+}
+
+impl RaydiumPoolAccount {
+    pub const SPACE: usize = 32 + 32 + 32 + 32 + 8 + 1 + 32 + 32 + 32;
+    pub const VAULT_SEED: &'static [u8] = b"pool_vault";
+
+    /// Raydium derives each pool vault from the pool state and the vault mint
+    pub fn vault_address(amm_program: &Pubkey, pool_id: &Pubkey, mint: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[Self::VAULT_SEED, pool_id.as_ref(), mint.as_ref()],
+            amm_program,
+        )
+        .0
+    }
+}
+
+/// Raydium programs whose pools may be registered as SRWA venues
+pub const RAYDIUM_AMM_PROGRAMS: [Pubkey; 4] = [
+    pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C"), // CPMM
+    pubkey!("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"), // CLMM
+    pubkey!("DRaycpLY18LhpbydsBWbVJtxpNv9oXPgjRSfpF2bWpYb"), // CPMM (devnet)
+    pubkey!("devi51mZmdwUJGU9hjN27vEz64Gps7uUefqxg27EAtH"),  // CLMM (devnet)
+];
+
+/// Lending reserve idle offering funds can be deployed into
+#[account]
+#[derive(InitSpace)]
+pub struct VenueConfig {
+    pub protocol: YieldProtocol,
+    pub lending_program: Pubkey,
+    pub reserve: Pubkey,
+    pub liquidity_mint: Pubkey,
+    pub liquidity_vault: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub registered_by: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl VenueConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"venue";
+
+    /// Whether this venue serves the offering's configured idle strategy
+    pub fn serves(&self, strategy: IdleStrategy) -> bool {
+        matches!(
+            (self.protocol, strategy),
+            (YieldProtocol::Marginfi, IdleStrategy::Marginfi)
+                | (YieldProtocol::Solend, IdleStrategy::Solend)
+                | (YieldProtocol::LendingInterface, IdleStrategy::LendingInterface)
+        )
+    }
+}
+
+/// Funds of one SRWA offering deployed into one venue
+#[account]
+#[derive(InitSpace)]
+pub struct YieldPosition {
+    pub mint: Pubkey,
+    pub venue: Pubkey,
+    pub authority: Pubkey,          // owner of the swept funds, receives them back
+    pub collateral_account: Pubkey, // venue shares, owned by this PDA
+    pub principal: u64,             // liquidity deposited and not yet withdrawn
+    pub deposit_cap: u64,           // max principal in this venue, set by the issuer admin
+    pub realized_yield: u64,        // skimmed or withdrawn above principal
+    pub last_skim_ts: i64,          // start of the current target APY accrual period
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl YieldPosition {
+    pub const SEED_PREFIX: &'static [u8] = b"position";
+    pub const COLLATERAL_SEED_PREFIX: &'static [u8] = b"position_collateral";
+    pub const YIELD_BUFFER_SEED_PREFIX: &'static [u8] = b"position_yield";
+}
+
+/// How realized yield of a mint is shared
+#[account]
+#[derive(InitSpace)]
+pub struct YieldSharingConfig {
+    pub mint: Pubkey,
+    pub target_apy_bps: u32,       // owed to subscribers first
+    pub issuer_bps: u16,           // shares of the yield above target
+    pub platform_bps: u16,
+    pub subscriber_bps: u16,
+    pub subscriber_vault: Pubkey,  // token account distributing to subscribers
+    pub updated_by: Pubkey,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct YieldSplit {
+    pub target_amount: u64,
+    pub issuer_amount: u64,
+    pub platform_amount: u64,
+    pub subscriber_amount: u64,
+}
+
+impl YieldSharingConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"yield_sharing";
+
+    /// Yield promised to subscribers on `principal` over `elapsed` seconds
+    pub fn target_yield(&self, principal: u64, elapsed: i64) -> u64 {
+        let target = (principal as u128)
+            * (self.target_apy_bps as u128)
+            * (elapsed.max(0) as u128)
+            / (SECONDS_PER_YEAR as u128 * BPS_DENOMINATOR as u128);
+        target.min(u64::MAX as u128) as u64
+    }
+
+    /// Subscribers get the target yield first, the excess is shared by ratio.
+    /// Rounding dust stays with subscribers.
+    pub fn split(&self, realized: u64, principal: u64, elapsed: i64) -> YieldSplit {
+        let target_amount = self.target_yield(principal, elapsed).min(realized);
+        let excess = (realized - target_amount) as u128;
+        let issuer_amount = (excess * self.issuer_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+        let platform_amount = (excess * self.platform_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+
+        YieldSplit {
+            target_amount,
+            issuer_amount,
+            platform_amount,
+            subscriber_amount: realized - issuer_amount - platform_amount,
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use srwa_factory::state::YieldProtocol;
+use crate::{errors::*, state::VenueConfig};
+
+/// A place idle offering liquidity can be parked to earn yield.
+///
+/// Deposits turn liquidity into venue shares held by the position; withdrawals
+/// redeem shares back into liquidity, including whatever interest accrued.
+pub trait YieldVenue {
+    /// Move `amount` of liquidity into the venue
+    fn deposit(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()>;
+
+    /// Redeem `shares` back into liquidity
+    fn withdraw(&self, shares: u64, signer_seeds: &[&[&[u8]]]) -> Result<()>;
+
+    /// Liquidity currently redeemable for `shares`
+    fn value_of(&self, shares: u64) -> Result<u64>;
+}
+
+/// Accounts every venue needs, in the order of the generic lending interface
+pub struct VenueAccounts<'info> {
+    pub lending_program: AccountInfo<'info>,
+    pub reserve: AccountInfo<'info>,
+    pub liquidity_vault: AccountInfo<'info>,
+    pub collateral_mint: AccountInfo<'info>,
+    pub user_liquidity: AccountInfo<'info>,
+    pub user_collateral: AccountInfo<'info>,
+    pub liquidity_mint: AccountInfo<'info>,
+    pub user_authority: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+/// Resolve the venue implementation for a registered reserve
+pub fn load_venue<'info>(
+    config: &VenueConfig,
+    accounts: VenueAccounts<'info>,
+) -> Result<Box<dyn YieldVenue + 'info>> {
+    require_keys_eq!(
+        accounts.lending_program.key(),
+        config.lending_program,
+        YieldAdapterError::InvalidVenue
+    );
+
+    match config.protocol {
+        YieldProtocol::LendingInterface => Ok(Box::new(LendingInterfaceVenue { accounts })),
+        YieldProtocol::Marginfi | YieldProtocol::Solend => {
+            err!(YieldAdapterError::UnsupportedProtocol)
+        }
+    }
+}
+
+/// Whether venues of `protocol` can be registered. Marginfi and Solend have
+/// their own instruction sets and account layouts, and stay unsupported until
+/// adapters for them exist.
+pub fn is_supported(protocol: YieldProtocol) -> bool {
+    matches!(protocol, YieldProtocol::LendingInterface)
+}
+
+/// Generic lending reserve: deposits mint collateral (share) tokens, and the
+/// share price is the reserve's liquidity divided by the collateral supply.
+/// The reserve does not lend, so all of its liquidity sits in the vault; an
+/// adapter for a lending market would also have to count borrowed liquidity.
+///
+/// Instructions:
+/// - `deposit_reserve_liquidity(amount: u64)`
+/// - `redeem_reserve_collateral(collateral_amount: u64)`
+///
+/// Both take `[reserve, liquidity_vault (w), collateral_mint (w),
+/// user_liquidity (w), user_collateral (w), liquidity_mint, user_authority (s),
+/// token_program]`.
+pub struct LendingInterfaceVenue<'info> {
+    accounts: VenueAccounts<'info>,
+}
+
+impl<'info> LendingInterfaceVenue<'info> {
+    pub const DEPOSIT_IX: &'static str = "deposit_reserve_liquidity";
+    pub const REDEEM_IX: &'static str = "redeem_reserve_collateral";
+
+    fn invoke(&self, name: &str, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let a = &self.accounts;
+
+        let mut data = hash(format!("global:{}", name).as_bytes()).to_bytes()[..8].to_vec();
+        data.extend_from_slice(&amount.to_le_bytes());
+
+        let ix = Instruction {
+            program_id: a.lending_program.key(),
+            accounts: vec![
+                AccountMeta::new_readonly(a.reserve.key(), false),
+                AccountMeta::new(a.liquidity_vault.key(), false),
+                AccountMeta::new(a.collateral_mint.key(), false),
+                AccountMeta::new(a.user_liquidity.key(), false),
+                AccountMeta::new(a.user_collateral.key(), false),
+                AccountMeta::new_readonly(a.liquidity_mint.key(), false),
+                AccountMeta::new_readonly(a.user_authority.key(), true),
+                AccountMeta::new_readonly(a.token_program.key(), false),
+            ],
+            data,
+        };
+
+        invoke_signed(
+            &ix,
+            &[
+                a.reserve.clone(),
+                a.liquidity_vault.clone(),
+                a.collateral_mint.clone(),
+                a.user_liquidity.clone(),
+                a.user_collateral.clone(),
+                a.liquidity_mint.clone(),
+                a.user_authority.clone(),
+                a.token_program.clone(),
+                a.lending_program.clone(),
+            ],
+            signer_seeds,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl<'info> YieldVenue for LendingInterfaceVenue<'info> {
+    fn deposit(&self, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        self.invoke(Self::DEPOSIT_IX, amount, signer_seeds)
+    }
+
+    fn withdraw(&self, shares: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        self.invoke(Self::REDEEM_IX, shares, signer_seeds)
+    }
+
+    fn value_of(&self, shares: u64) -> Result<u64> {
+        // Read fresh state, balances change during the CPIs above. The vault
+        // holds the whole reserve, nothing is lent out.
+        let total_liquidity =
+            TokenAccount::try_deserialize(&mut &self.accounts.liquidity_vault.try_borrow_data()?[..])?
+                .amount;
+        let share_supply =
+            Mint::try_deserialize(&mut &self.accounts.collateral_mint.try_borrow_data()?[..])?
+                .supply;
+
+        if share_supply == 0 {
+            return Ok(0);
+        }
+        let value = (shares as u128)
+            .checked_mul(total_liquidity as u128)
+            .ok_or(YieldAdapterError::MathOverflow)?
+            / share_supply as u128;
+        u64::try_from(value).map_err(|_| error!(YieldAdapterError::MathOverflow))
+    }
+}
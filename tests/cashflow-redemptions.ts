@@ -0,0 +1,510 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SrwaFactory } from "../target/types/srwa_factory";
+import { SrwaController } from "../target/types/srwa_controller";
+import { CashflowEngine } from "../target/types/cashflow_engine";
+import { ComplianceModules } from "../target/types/compliance_modules";
+import { Keypair, PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  createMint,
+  createAssociatedTokenAccount,
+  mintTo,
+  getAccount,
+} from "@solana/spl-token";
+import { assert } from "chai";
+
+describe("Cashflow engine - redemption windows", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const factoryProgram = anchor.workspace.SrwaFactory as Program<SrwaFactory>;
+  const controllerProgram = anchor.workspace
+    .SrwaController as Program<SrwaController>;
+  const cashflowProgram = anchor.workspace
+    .CashflowEngine as Program<CashflowEngine>;
+  const complianceProgram = anchor.workspace
+    .ComplianceModules as Program<ComplianceModules>;
+
+  const payer = provider.wallet as anchor.Wallet;
+  const issuerKeypair = Keypair.generate();
+  const holderKeypair = Keypair.generate();
+  const strangerKeypair = Keypair.generate();
+
+  const NAV_CURRENCY_USD = 0;
+
+  let mint: PublicKey;
+  let paymentMint: PublicKey;
+  let srwaConfigPda: PublicKey;
+  let holderAta: PublicKey;
+  let holderPaymentAta: PublicKey;
+  let issuerPaymentAta: PublicKey;
+
+  const windowPdas = (windowId: BN) => {
+    const [window] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("redemption_window"),
+        mint.toBuffer(),
+        windowId.toArrayLike(Buffer, "le", 8),
+      ],
+      cashflowProgram.programId
+    );
+    const [escrow] = PublicKey.findProgramAddressSync(
+      [Buffer.from("redemption_escrow"), window.toBuffer()],
+      cashflowProgram.programId
+    );
+    const [vault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("redemption_vault"), window.toBuffer()],
+      cashflowProgram.programId
+    );
+    const [request] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("redemption_request"),
+        window.toBuffer(),
+        holderKeypair.publicKey.toBuffer(),
+      ],
+      cashflowProgram.programId
+    );
+    return { window, escrow, vault, request };
+  };
+
+  function openWindow(
+    authority: Keypair,
+    windowId: BN,
+    opensAt: number,
+    closesAt: number,
+    maxRedemptionBps: number,
+    navDecimals = 6
+  ) {
+    const { window, escrow, vault } = windowPdas(windowId);
+    const [programAllowlistPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("program_allowlist"), mint.toBuffer()],
+      complianceProgram.programId
+    );
+    const [accountAllowlistPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("account_allowlist"), mint.toBuffer()],
+      complianceProgram.programId
+    );
+    const [escrowCheckpointPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("checkpoint"), escrow.toBuffer()],
+      controllerProgram.programId
+    );
+
+    return cashflowProgram.methods
+      .openRedemptionWindow(
+        windowId,
+        { nav: {} },
+        new BN(opensAt),
+        new BN(closesAt),
+        maxRedemptionBps,
+        NAV_CURRENCY_USD,
+        navDecimals
+      )
+      .accountsPartial({
+        authority: authority.publicKey,
+        mint,
+        srwaConfig: srwaConfigPda,
+        window,
+        escrow,
+        paymentMint,
+        vault,
+        programAllowlist: programAllowlistPda,
+        accountAllowlist: accountAllowlistPda,
+        escrowCheckpoint: escrowCheckpointPda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        paymentTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  function requestRedemption(windowId: BN, amount: number) {
+    const { window, escrow, request } = windowPdas(windowId);
+    return cashflowProgram.methods
+      .requestRedemption(new BN(amount))
+      .accountsPartial({
+        holder: holderKeypair.publicKey,
+        mint,
+        window,
+        request,
+        holderTokenAccount: holderAta,
+        escrow,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([holderKeypair])
+      .rpc();
+  }
+
+  before(async () => {
+    for (const keypair of [issuerKeypair, holderKeypair, strangerKeypair]) {
+      const sig = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        2 * LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(sig);
+    }
+
+    // SRWA Token-2022 mint without a transfer hook, and a USDC-like payment mint
+    mint = await createMint(
+      provider.connection,
+      payer.payer,
+      issuerKeypair.publicKey,
+      null,
+      6,
+      Keypair.generate(),
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    paymentMint = await createMint(
+      provider.connection,
+      payer.payer,
+      payer.publicKey,
+      null,
+      6
+    );
+
+    [srwaConfigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("srwa_config"), mint.toBuffer()],
+      factoryProgram.programId
+    );
+
+    const now = Math.floor(Date.now() / 1000);
+    await factoryProgram.methods
+      .createSrwa(
+        {
+          roles: {
+            issuerAdmin: issuerKeypair.publicKey,
+            complianceOfficer: issuerKeypair.publicKey,
+            transferAgent: issuerKeypair.publicKey,
+          },
+          requiredTopics: [],
+          metadataUri: "https://example.com/metadata.json",
+          defaultFrozen: false,
+          permanentDelegate: PublicKey.default,
+          mintDecimals: 6,
+        },
+        {
+          window: { startTs: new BN(now), endTs: new BN(now + 86400) },
+          target: { softCap: new BN(1), hardCap: new BN(1_000_000_000) },
+          pricing: {
+            model: { fixed: {} },
+            unitPrice: new BN(1_000_000),
+            currency: { usd: {} },
+            quoteMint: PublicKey.default,
+          },
+          rules: {
+            minTicket: new BN(1),
+            perInvestorCap: new BN(1_000_000_000),
+            maxInvestors: 100,
+            eligibility: { jurisdictionsAllow: [], investorTypes: [] },
+          },
+          oversubPolicy: { proRata: {} },
+          feesBps: { originationBps: 0, platformBps: 0, successBps: 0 },
+          issuerTreasury: issuerKeypair.publicKey,
+          feeTreasury: payer.publicKey,
+        }
+      )
+      .accounts({
+        issuer: issuerKeypair.publicKey,
+        mint,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+
+    holderAta = await createAssociatedTokenAccount(
+      provider.connection,
+      payer.payer,
+      mint,
+      holderKeypair.publicKey,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await mintTo(
+      provider.connection,
+      payer.payer,
+      mint,
+      holderAta,
+      issuerKeypair,
+      100_000_000,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    holderPaymentAta = await createAssociatedTokenAccount(
+      provider.connection,
+      payer.payer,
+      paymentMint,
+      holderKeypair.publicKey
+    );
+    issuerPaymentAta = await createAssociatedTokenAccount(
+      provider.connection,
+      payer.payer,
+      paymentMint,
+      issuerKeypair.publicKey
+    );
+  });
+
+  describe("Opening a window", () => {
+    it("Only the issuer admin can open a window", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      try {
+        await openWindow(strangerKeypair, new BN(100), now, now + 3600, 1_000);
+        assert.fail("Should have failed - stranger cannot open a window");
+      } catch (err) {
+        assert.include(err.toString(), "Unauthorized");
+        console.log("✓ Only the issuer admin opens redemption windows");
+      }
+    });
+
+    it("Rejects a window that closes before it opens", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      try {
+        await openWindow(issuerKeypair, new BN(101), now, now, 1_000);
+        assert.fail("Should have failed - empty window");
+      } catch (err) {
+        assert.include(err.toString(), "InvalidWindow");
+        console.log("✓ Window must close after it opens");
+      }
+    });
+
+    it("Rejects a gate outside 1-10000 bps", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      for (const gate of [0, 10_001]) {
+        try {
+          await openWindow(issuerKeypair, new BN(102), now, now + 3600, gate);
+          assert.fail(`Should have failed - gate of ${gate} bps`);
+        } catch (err) {
+          assert.include(err.toString(), "InvalidGate");
+        }
+      }
+      console.log("✓ Redemption gate is bounded");
+    });
+
+    it("Rejects NAV decimals above 18", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      try {
+        await openWindow(issuerKeypair, new BN(103), now, now + 3600, 1_000, 19);
+        assert.fail("Should have failed - NAV decimals out of range");
+      } catch (err) {
+        assert.include(err.toString(), "InvalidNavDecimals");
+        console.log("✓ NAV scale is bounded");
+      }
+    });
+  });
+
+  describe("Window not yet open", () => {
+    const windowId = new BN(1);
+
+    before(async () => {
+      const now = Math.floor(Date.now() / 1000);
+      await openWindow(issuerKeypair, windowId, now + 3600, now + 7200, 5_000);
+    });
+
+    it("Rejects an empty request", async () => {
+      try {
+        await requestRedemption(windowId, 0);
+        assert.fail("Should have failed - zero amount");
+      } catch (err) {
+        assert.include(err.toString(), "InvalidAmount");
+        console.log("✓ Redemption amount must be positive");
+      }
+    });
+
+    it("Rejects requests before the window opens", async () => {
+      try {
+        await requestRedemption(windowId, 1_000_000);
+        assert.fail("Should have failed - window opens later");
+      } catch (err) {
+        assert.include(err.toString(), "WindowNotOpen");
+        console.log("✓ Requests only inside the window");
+      }
+    });
+
+    it("Cannot be closed before it ends", async () => {
+      const { window } = windowPdas(windowId);
+      try {
+        await cashflowProgram.methods
+          .closeRedemptionWindow()
+          .accountsPartial({
+            payer: payer.publicKey,
+            mint,
+            srwaConfig: srwaConfigPda,
+            window,
+            valuationData: null,
+            couponSchedule: null,
+          })
+          .rpc();
+        assert.fail("Should have failed - window still open");
+      } catch (err) {
+        assert.include(err.toString(), "WindowStillOpen");
+        console.log("✓ Windows close only after they end");
+      }
+    });
+
+    it("Can only be swept by the issuer once settled", async () => {
+      const { window, escrow, vault } = windowPdas(windowId);
+      const sweep = (authority: Keypair, authorityPaymentAccount: PublicKey) =>
+        cashflowProgram.methods
+          .sweepRedemptionWindow()
+          .accountsPartial({
+            authority: authority.publicKey,
+            mint,
+            srwaConfig: srwaConfigPda,
+            window,
+            escrow,
+            paymentMint,
+            vault,
+            authorityPaymentAccount,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            paymentTokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc();
+
+      try {
+        await sweep(strangerKeypair, holderPaymentAta);
+        assert.fail("Should have failed - stranger cannot sweep");
+      } catch (err) {
+        assert.include(err.toString(), "Unauthorized");
+      }
+      try {
+        await sweep(issuerKeypair, issuerPaymentAta);
+        assert.fail("Should have failed - window still open");
+      } catch (err) {
+        assert.include(err.toString(), "WindowStillOpen");
+      }
+      console.log("✓ Only the issuer sweeps, and only closed windows");
+    });
+
+    it("Rejects empty funding", async () => {
+      const { window, vault } = windowPdas(windowId);
+      try {
+        await cashflowProgram.methods
+          .fundRedemption(new BN(0))
+          .accountsPartial({
+            funder: issuerKeypair.publicKey,
+            window,
+            paymentMint,
+            funderTokenAccount: issuerPaymentAta,
+            vault,
+            paymentTokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([issuerKeypair])
+          .rpc();
+        assert.fail("Should have failed - zero funding");
+      } catch (err) {
+        assert.include(err.toString(), "InvalidAmount");
+        console.log("✓ Funding amount must be positive");
+      }
+    });
+  });
+
+  describe("Window with a pending request", () => {
+    const windowId = new BN(2);
+
+    before(async () => {
+      const now = Math.floor(Date.now() / 1000);
+      await openWindow(issuerKeypair, windowId, now - 10, now + 3, 5_000);
+      await requestRedemption(windowId, 10_000_000);
+    });
+
+    it("Escrows the requested tokens", async () => {
+      const { escrow } = windowPdas(windowId);
+      const escrowAccount = await getAccount(
+        provider.connection,
+        escrow,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(Number(escrowAccount.amount), 10_000_000);
+      console.log("✓ Requested tokens held in the window escrow");
+    });
+
+    it("Allowlists the escrow as a venue account", async () => {
+      const { escrow } = windowPdas(windowId);
+      const [accountAllowlistPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("account_allowlist"), mint.toBuffer()],
+        complianceProgram.programId
+      );
+      const allowlist = await complianceProgram.account.accountAllowlist.fetch(
+        accountAllowlistPda
+      );
+      assert.isTrue(allowlist.accounts.some((account) => account.equals(escrow)));
+      console.log("✓ Window escrow is a venue account of the mint");
+    });
+
+    it("Cannot be settled while the window is open", async () => {
+      const { window, escrow, vault, request } = windowPdas(windowId);
+      try {
+        await cashflowProgram.methods
+          .settleRedemption()
+          .accountsPartial({
+            payer: payer.publicKey,
+            holder: holderKeypair.publicKey,
+            mint,
+            window,
+            request,
+            escrow,
+            holderTokenAccount: holderAta,
+            paymentMint,
+            vault,
+            holderPaymentAccount: holderPaymentAta,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            paymentTokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+        assert.fail("Should have failed - window not closed");
+      } catch (err) {
+        assert.include(err.toString(), "WindowStillOpen");
+        console.log("✓ Settlement waits for the window to close");
+      }
+    });
+
+    it("Cannot be cancelled once the window ended", async () => {
+      await new Promise((resolve) => setTimeout(resolve, 5000));
+      const { window, escrow, request } = windowPdas(windowId);
+
+      try {
+        await cashflowProgram.methods
+          .cancelRedemption()
+          .accountsPartial({
+            holder: holderKeypair.publicKey,
+            mint,
+            window,
+            request,
+            holderTokenAccount: holderAta,
+            escrow,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([holderKeypair])
+          .rpc();
+        assert.fail("Should have failed - window ended");
+      } catch (err) {
+        assert.include(err.toString(), "WindowNotOpen");
+      }
+      console.log("✓ Requests are locked in once the window ends");
+    });
+
+    it("Requires a NAV to close a NAV window", async () => {
+      const { window } = windowPdas(windowId);
+      try {
+        await cashflowProgram.methods
+          .closeRedemptionWindow()
+          .accountsPartial({
+            payer: payer.publicKey,
+            mint,
+            srwaConfig: srwaConfigPda,
+            window,
+            valuationData: null,
+            couponSchedule: null,
+          })
+          .rpc();
+        assert.fail("Should have failed - no valuation account");
+      } catch (err) {
+        assert.include(err.toString(), "MissingPriceSource");
+        console.log("✓ NAV windows lock the price from the valuation");
+      }
+    });
+  });
+});
@@ -0,0 +1,539 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SrwaFactory } from "../target/types/srwa_factory";
+import { ComplianceModules } from "../target/types/compliance_modules";
+import {
+  Keypair,
+  PublicKey,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import { assert } from "chai";
+
+describe("Compliance configuration and module params", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const factoryProgram = anchor.workspace.SrwaFactory as Program<SrwaFactory>;
+  const complianceProgram = anchor.workspace
+    .ComplianceModules as Program<ComplianceModules>;
+
+  const payer = provider.wallet as anchor.Wallet;
+  const issuerKeypair = Keypair.generate();
+  const strangerKeypair = Keypair.generate();
+  const investor = Keypair.generate().publicKey;
+  const srwaMint = Keypair.generate().publicKey;
+
+  const MAX_HOLDERS_MODULE = 4;
+  const VOLUME_CAPS_MODULE = 5;
+
+  const [srwaConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("srwa_config"), srwaMint.toBuffer()],
+    factoryProgram.programId
+  );
+  const [maxHoldersPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("max_holders"), srwaMint.toBuffer()],
+    complianceProgram.programId
+  );
+  const [programAllowlistPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("program_allowlist"), srwaMint.toBuffer()],
+    complianceProgram.programId
+  );
+  const [accountAllowlistPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("account_allowlist"), srwaMint.toBuffer()],
+    complianceProgram.programId
+  );
+
+  const moduleConfigPda = (moduleId: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("module_config"), srwaMint.toBuffer(), Buffer.from([moduleId])],
+      factoryProgram.programId
+    )[0];
+
+  before(async () => {
+    for (const keypair of [issuerKeypair, strangerKeypair]) {
+      const sig = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        2 * LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(sig);
+    }
+
+    const now = Math.floor(Date.now() / 1000);
+    await factoryProgram.methods
+      .createSrwa(
+        {
+          roles: {
+            issuerAdmin: issuerKeypair.publicKey,
+            complianceOfficer: issuerKeypair.publicKey,
+            transferAgent: issuerKeypair.publicKey,
+          },
+          requiredTopics: [],
+          metadataUri: "https://example.com/metadata.json",
+          defaultFrozen: false,
+          permanentDelegate: PublicKey.default,
+          mintDecimals: 6,
+        },
+        {
+          window: { startTs: new BN(now), endTs: new BN(now + 86400) },
+          target: { softCap: new BN(1), hardCap: new BN(1_000_000_000) },
+          pricing: {
+            model: { fixed: {} },
+            unitPrice: new BN(1_000_000),
+            currency: { usd: {} },
+            quoteMint: PublicKey.default,
+          },
+          rules: {
+            minTicket: new BN(1),
+            perInvestorCap: new BN(1_000_000_000),
+            maxInvestors: 100,
+            eligibility: { jurisdictionsAllow: [], investorTypes: [] },
+          },
+          oversubPolicy: { proRata: {} },
+          feesBps: { originationBps: 0, platformBps: 0, successBps: 0 },
+          issuerTreasury: issuerKeypair.publicKey,
+          feeTreasury: payer.publicKey,
+        }
+      )
+      .accounts({
+        issuer: issuerKeypair.publicKey,
+        mint: srwaMint,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+  });
+
+  describe("Compliance setters", () => {
+    it("Rejects configuration by an account without a compliance role", async () => {
+      try {
+        await complianceProgram.methods
+          .configureMaxHolders(10, 5)
+          .accountsPartial({
+            authority: strangerKeypair.publicKey,
+            mint: srwaMint,
+            srwaConfig: srwaConfigPda,
+            maxHoldersConfig: maxHoldersPda,
+          })
+          .signers([strangerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - stranger cannot configure holder caps");
+      } catch (err) {
+        assert.include(err.toString(), "Unauthorized");
+        console.log("✓ Only the compliance officer or issuer admin can configure");
+      }
+    });
+
+    it("Rejects a concentration limit above 100%", async () => {
+      try {
+        await complianceProgram.methods
+          .setInvestorProfile(
+            { retailQualified: {} },
+            {
+              dailyVolume: new BN(0),
+              positionCap: new BN(0),
+              concentrationBps: 10_001,
+            }
+          )
+          .accountsPartial({
+            authority: issuerKeypair.publicKey,
+            mint: srwaMint,
+            user: investor,
+            srwaConfig: srwaConfigPda,
+          })
+          .signers([issuerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - concentration above 10000 bps");
+      } catch (err) {
+        assert.include(err.toString(), "InvalidInvestorLimits");
+        console.log("✓ Concentration limit is capped at 10000 bps");
+      }
+    });
+
+    it("Rejects transfer windows with invalid hours or blackouts", async () => {
+      try {
+        await complianceProgram.methods
+          .setTransferWindow([9, 24], [], 0, [])
+          .accountsPartial({
+            authority: issuerKeypair.publicKey,
+            mint: srwaMint,
+            srwaConfig: srwaConfigPda,
+          })
+          .signers([issuerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - hour 24 does not exist");
+      } catch (err) {
+        assert.include(err.toString(), "InvalidTransferWindow");
+      }
+
+      const now = Math.floor(Date.now() / 1000);
+      try {
+        await complianceProgram.methods
+          .setTransferWindow([], [], 0, [
+            { startTs: new BN(now + 3600), endTs: new BN(now) },
+          ])
+          .accountsPartial({
+            authority: issuerKeypair.publicKey,
+            mint: srwaMint,
+            srwaConfig: srwaConfigPda,
+          })
+          .signers([issuerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - blackout ends before it starts");
+      } catch (err) {
+        assert.include(err.toString(), "InvalidTransferWindow");
+        console.log("✓ Invalid transfer windows are rejected");
+      }
+    });
+
+    it("Rejects a lockup whose cliff precedes its start", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      try {
+        await complianceProgram.methods
+          .setLockup(
+            {
+              startTs: new BN(now),
+              endTs: new BN(now + 86400),
+              cliffTs: new BN(now - 1),
+              linearVesting: true,
+            },
+            new BN(1_000_000)
+          )
+          .accountsPartial({
+            authority: issuerKeypair.publicKey,
+            mint: srwaMint,
+            srwaConfig: srwaConfigPda,
+            user: investor,
+          })
+          .signers([issuerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - cliff before start");
+      } catch (err) {
+        assert.include(err.toString(), "InvalidLockupSchedule");
+        console.log("✓ Invalid lockup schedules are rejected");
+      }
+    });
+
+    it("Only mint roles or platform admins can allowlist and delist venues", async () => {
+      const vault = Keypair.generate().publicKey;
+
+      try {
+        await complianceProgram.methods
+          .allowlistVenue(Keypair.generate().publicKey, [vault])
+          .accountsPartial({
+            authority: strangerKeypair.publicKey,
+            mint: srwaMint,
+            srwaConfig: srwaConfigPda,
+            adminRegistry: null,
+            programAllowlist: programAllowlistPda,
+            accountAllowlist: accountAllowlistPda,
+          })
+          .signers([strangerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - stranger cannot allowlist a venue");
+      } catch (err) {
+        assert.include(err.toString(), "Unauthorized");
+      }
+
+      await complianceProgram.methods
+        .allowlistVenue(Keypair.generate().publicKey, [vault])
+        .accountsPartial({
+          authority: issuerKeypair.publicKey,
+          mint: srwaMint,
+          srwaConfig: srwaConfigPda,
+          adminRegistry: null,
+          programAllowlist: programAllowlistPda,
+          accountAllowlist: accountAllowlistPda,
+        })
+        .signers([issuerKeypair])
+        .rpc();
+
+      try {
+        await complianceProgram.methods
+          .delistVenueAccounts([vault])
+          .accountsPartial({
+            authority: strangerKeypair.publicKey,
+            mint: srwaMint,
+            srwaConfig: srwaConfigPda,
+            adminRegistry: null,
+            accountAllowlist: accountAllowlistPda,
+          })
+          .signers([strangerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - stranger cannot delist a venue");
+      } catch (err) {
+        assert.include(err.toString(), "Unauthorized");
+      }
+
+      const allowlist = await complianceProgram.account.accountAllowlist.fetch(
+        accountAllowlistPda
+      );
+      assert.isTrue(allowlist.accounts.some((account) => account.equals(vault)));
+      console.log("✓ Venue allowlist changes require a mint role");
+    });
+  });
+
+  describe("Module params", () => {
+    const mirrorAccounts = [
+      { pubkey: complianceProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: maxHoldersPda, isSigner: false, isWritable: true },
+    ];
+
+    before(async () => {
+      // The hook's config account exists before the module is enabled
+      await complianceProgram.methods
+        .configureMaxHolders(100, 35)
+        .accountsPartial({
+          authority: issuerKeypair.publicKey,
+          mint: srwaMint,
+          srwaConfig: srwaConfigPda,
+          maxHoldersConfig: maxHoldersPda,
+        })
+        .signers([issuerKeypair])
+        .rpc();
+    });
+
+    it("Rejects params of another module", async () => {
+      try {
+        await factoryProgram.methods
+          .enableModule(MAX_HOLDERS_MODULE, {
+            volumeCaps: {
+              dailyCap: new BN(0),
+              monthlyCap: new BN(0),
+              maxTx: new BN(0),
+            },
+          })
+          .accountsPartial({
+            authority: issuerKeypair.publicKey,
+            mint: srwaMint,
+            srwaConfig: srwaConfigPda,
+            moduleConfig: moduleConfigPda(MAX_HOLDERS_MODULE),
+          })
+          .remainingAccounts(mirrorAccounts)
+          .signers([issuerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - params belong to VolumeCaps");
+      } catch (err) {
+        assert.include(err.toString(), "InvalidModuleParams");
+        console.log("✓ Params must match the module");
+      }
+    });
+
+    it("Rejects out-of-range params", async () => {
+      try {
+        await factoryProgram.methods
+          .enableModule(MAX_HOLDERS_MODULE, {
+            maxHolders: { maxHolders: 10, maxNonAccredited: 11 },
+          })
+          .accountsPartial({
+            authority: issuerKeypair.publicKey,
+            mint: srwaMint,
+            srwaConfig: srwaConfigPda,
+            moduleConfig: moduleConfigPda(MAX_HOLDERS_MODULE),
+          })
+          .remainingAccounts(mirrorAccounts)
+          .signers([issuerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - more non-accredited than holders");
+      } catch (err) {
+        assert.include(err.toString(), "InvalidModuleParams");
+        console.log("✓ Out-of-range params are rejected");
+      }
+    });
+
+    it("Rejects updating a module that is not enabled", async () => {
+      try {
+        await factoryProgram.methods
+          .updateModuleParams(VOLUME_CAPS_MODULE, {
+            volumeCaps: {
+              dailyCap: new BN(1_000),
+              monthlyCap: new BN(10_000),
+              maxTx: new BN(100),
+            },
+          })
+          .accountsPartial({
+            authority: issuerKeypair.publicKey,
+            mint: srwaMint,
+            srwaConfig: srwaConfigPda,
+            moduleConfig: moduleConfigPda(VOLUME_CAPS_MODULE),
+          })
+          .signers([issuerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - VolumeCaps is not enabled");
+      } catch (err) {
+        assert.include(err.toString(), "ModuleNotEnabled");
+        console.log("✓ Params of disabled modules cannot be updated");
+      }
+    });
+
+    it("Rejects a compliance config that is not the module's PDA", async () => {
+      try {
+        await factoryProgram.methods
+          .enableModule(MAX_HOLDERS_MODULE, {
+            maxHolders: { maxHolders: 50, maxNonAccredited: 10 },
+          })
+          .accountsPartial({
+            authority: issuerKeypair.publicKey,
+            mint: srwaMint,
+            srwaConfig: srwaConfigPda,
+            moduleConfig: moduleConfigPda(MAX_HOLDERS_MODULE),
+          })
+          .remainingAccounts([
+            mirrorAccounts[0],
+            { pubkey: accountAllowlistPda, isSigner: false, isWritable: true },
+          ])
+          .signers([issuerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - wrong compliance config account");
+      } catch (err) {
+        assert.include(err.toString(), "InvalidComplianceConfig");
+        console.log("✓ Params are only mirrored to the module's config");
+      }
+    });
+
+    it("Mirrors params into the compliance config on enable and update", async () => {
+      await factoryProgram.methods
+        .enableModule(MAX_HOLDERS_MODULE, {
+          maxHolders: { maxHolders: 50, maxNonAccredited: 10 },
+        })
+        .accountsPartial({
+          authority: issuerKeypair.publicKey,
+          mint: srwaMint,
+          srwaConfig: srwaConfigPda,
+          moduleConfig: moduleConfigPda(MAX_HOLDERS_MODULE),
+        })
+        .remainingAccounts(mirrorAccounts)
+        .signers([issuerKeypair])
+        .rpc();
+
+      let config = await complianceProgram.account.maxHoldersConfig.fetch(
+        maxHoldersPda
+      );
+      assert.equal(config.maxHolders, 50);
+      assert.equal(config.maxNonAccredited, 10);
+
+      await factoryProgram.methods
+        .updateModuleParams(MAX_HOLDERS_MODULE, {
+          maxHolders: { maxHolders: 2, maxNonAccredited: 1 },
+        })
+        .accountsPartial({
+          authority: issuerKeypair.publicKey,
+          mint: srwaMint,
+          srwaConfig: srwaConfigPda,
+          moduleConfig: moduleConfigPda(MAX_HOLDERS_MODULE),
+        })
+        .remainingAccounts(mirrorAccounts)
+        .signers([issuerKeypair])
+        .rpc();
+
+      config = await complianceProgram.account.maxHoldersConfig.fetch(
+        maxHoldersPda
+      );
+      assert.equal(config.maxHolders, 2);
+      assert.equal(config.maxNonAccredited, 1);
+      console.log("✓ The hook's config follows the module params");
+    });
+
+    it("Rejects enabling a module twice", async () => {
+      try {
+        await factoryProgram.methods
+          .enableModule(MAX_HOLDERS_MODULE, {
+            maxHolders: { maxHolders: 2, maxNonAccredited: 1 },
+          })
+          .accountsPartial({
+            authority: issuerKeypair.publicKey,
+            mint: srwaMint,
+            srwaConfig: srwaConfigPda,
+            moduleConfig: moduleConfigPda(MAX_HOLDERS_MODULE),
+          })
+          .remainingAccounts(mirrorAccounts)
+          .signers([issuerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - module already enabled");
+      } catch (err) {
+        assert.include(err.toString(), "ModuleAlreadyEnabled");
+        console.log("✓ Enabled modules cannot be enabled again");
+      }
+    });
+
+    it("Requires the compliance config to disable a module", async () => {
+      try {
+        await factoryProgram.methods
+          .disableModule(MAX_HOLDERS_MODULE)
+          .accountsPartial({
+            authority: issuerKeypair.publicKey,
+            mint: srwaMint,
+            srwaConfig: srwaConfigPda,
+            moduleConfig: moduleConfigPda(MAX_HOLDERS_MODULE),
+          })
+          .signers([issuerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - compliance config missing");
+      } catch (err) {
+        assert.include(err.toString(), "InvalidComplianceConfig");
+        console.log("✓ The hook's config cannot be left behind");
+      }
+    });
+
+    it("Closes the module and compliance configs on disable", async () => {
+      await factoryProgram.methods
+        .disableModule(MAX_HOLDERS_MODULE)
+        .accountsPartial({
+          authority: issuerKeypair.publicKey,
+          mint: srwaMint,
+          srwaConfig: srwaConfigPda,
+          moduleConfig: moduleConfigPda(MAX_HOLDERS_MODULE),
+        })
+        .remainingAccounts(mirrorAccounts)
+        .signers([issuerKeypair])
+        .rpc();
+
+      assert.isNull(
+        await factoryProgram.account.moduleConfig.fetchNullable(
+          moduleConfigPda(MAX_HOLDERS_MODULE)
+        )
+      );
+      assert.isNull(
+        await complianceProgram.account.maxHoldersConfig.fetchNullable(
+          maxHoldersPda
+        )
+      );
+      console.log("✓ The hook stops enforcing a disabled module");
+    });
+
+    it("Re-enables a disabled module", async () => {
+      await factoryProgram.methods
+        .enableModule(MAX_HOLDERS_MODULE, {
+          maxHolders: { maxHolders: 20, maxNonAccredited: 5 },
+        })
+        .accountsPartial({
+          authority: issuerKeypair.publicKey,
+          mint: srwaMint,
+          srwaConfig: srwaConfigPda,
+          moduleConfig: moduleConfigPda(MAX_HOLDERS_MODULE),
+        })
+        .remainingAccounts(mirrorAccounts)
+        .signers([issuerKeypair])
+        .rpc();
+
+      const moduleConfig = await factoryProgram.account.moduleConfig.fetch(
+        moduleConfigPda(MAX_HOLDERS_MODULE)
+      );
+      assert.deepEqual(moduleConfig.params, {
+        maxHolders: { maxHolders: 20, maxNonAccredited: 5 },
+      });
+      console.log("✓ Re-enabling recreates the module config");
+    });
+  });
+});
@@ -0,0 +1,464 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SrwaFactory } from "../target/types/srwa_factory";
+import { SrwaController } from "../target/types/srwa_controller";
+import { PurchaseOrder } from "../target/types/purchase_order";
+import { IdentityClaims } from "../target/types/identity_claims";
+import { ComplianceModules } from "../target/types/compliance_modules";
+import { Keypair, PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  createMint,
+  createAssociatedTokenAccount,
+  mintTo,
+  getAccount,
+} from "@solana/spl-token";
+import { assert } from "chai";
+
+describe("Purchase orders - escrow and partial fills", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const factoryProgram = anchor.workspace.SrwaFactory as Program<SrwaFactory>;
+  const controllerProgram = anchor.workspace
+    .SrwaController as Program<SrwaController>;
+  const orderProgram = anchor.workspace.PurchaseOrder as Program<PurchaseOrder>;
+  const identityProgram = anchor.workspace
+    .IdentityClaims as Program<IdentityClaims>;
+  const complianceProgram = anchor.workspace
+    .ComplianceModules as Program<ComplianceModules>;
+
+  const payer = provider.wallet as anchor.Wallet;
+  const issuerKeypair = Keypair.generate();
+  const investorKeypair = Keypair.generate();
+  const strangerKeypair = Keypair.generate();
+
+  const PRICE_LAMPORTS = 1_000;
+  const KYC_TOPIC = 1;
+
+  let mint: PublicKey;
+  let srwaConfigPda: PublicKey;
+  let offeringPda: PublicKey;
+  let inventoryPda: PublicKey;
+  let inventoryVaultPda: PublicKey;
+  let investorKycClaimPda: PublicKey;
+  let investorAta: PublicKey;
+
+  const orderPdas = (timestamp: BN) => {
+    const [purchaseOrder] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("purchase_order"),
+        mint.toBuffer(),
+        investorKeypair.publicKey.toBuffer(),
+        timestamp.toArrayLike(Buffer, "le", 8),
+      ],
+      orderProgram.programId
+    );
+    const [escrow] = PublicKey.findProgramAddressSync(
+      [Buffer.from("order_escrow"), purchaseOrder.toBuffer()],
+      orderProgram.programId
+    );
+    return { purchaseOrder, escrow };
+  };
+
+  async function createOrder(quantity: number, expiresAt: BN | null) {
+    const timestamp = new BN(Date.now());
+    const { purchaseOrder, escrow } = orderPdas(timestamp);
+
+    await orderProgram.methods
+      .createPurchaseOrder(
+        new BN(quantity),
+        new BN(PRICE_LAMPORTS),
+        timestamp,
+        expiresAt
+      )
+      .accountsPartial({
+        investor: investorKeypair.publicKey,
+        mint,
+        offering: offeringPda,
+        purchaseOrder,
+        escrow,
+      })
+      .signers([investorKeypair])
+      .rpc();
+
+    return { purchaseOrder, escrow };
+  }
+
+  const approveAccounts = (order: { purchaseOrder: PublicKey; escrow: PublicKey }) => ({
+    admin: issuerKeypair.publicKey,
+    purchaseOrder: order.purchaseOrder,
+    srwaConfig: srwaConfigPda,
+    adminRegistry: null,
+    investorKycClaim: investorKycClaimPda,
+    mint,
+    inventory: inventoryPda,
+    inventoryVault: inventoryVaultPda,
+    investorTokenAccount: investorAta,
+    escrow: order.escrow,
+    offering: offeringPda,
+    issuerTreasury: issuerKeypair.publicKey,
+    investor: investorKeypair.publicKey,
+    quoteMint: null,
+    escrowTokenAccount: null,
+    treasuryQuoteAccount: null,
+    investorQuoteAccount: null,
+    valuationData: null,
+    tokenProgram: TOKEN_2022_PROGRAM_ID,
+    quoteTokenProgram: null,
+  });
+
+  before(async () => {
+    for (const keypair of [issuerKeypair, investorKeypair, strangerKeypair]) {
+      const sig = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        2 * LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(sig);
+    }
+
+    // Token-2022 mint without a transfer hook, registered with the factory
+    mint = await createMint(
+      provider.connection,
+      payer.payer,
+      issuerKeypair.publicKey,
+      null,
+      0,
+      Keypair.generate(),
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    [srwaConfigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("srwa_config"), mint.toBuffer()],
+      factoryProgram.programId
+    );
+    [offeringPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("offering"), mint.toBuffer()],
+      factoryProgram.programId
+    );
+    [inventoryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("inventory"), mint.toBuffer()],
+      orderProgram.programId
+    );
+    [inventoryVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("inventory_vault"), inventoryPda.toBuffer()],
+      orderProgram.programId
+    );
+    const kycTopic = Buffer.alloc(4);
+    kycTopic.writeUInt32LE(KYC_TOPIC);
+    [investorKycClaimPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("claim"), investorKeypair.publicKey.toBuffer(), kycTopic],
+      identityProgram.programId
+    );
+
+    const now = Math.floor(Date.now() / 1000);
+    await factoryProgram.methods
+      .createSrwa(
+        {
+          roles: {
+            issuerAdmin: issuerKeypair.publicKey,
+            complianceOfficer: issuerKeypair.publicKey,
+            transferAgent: issuerKeypair.publicKey,
+          },
+          requiredTopics: [],
+          metadataUri: "https://example.com/metadata.json",
+          defaultFrozen: false,
+          permanentDelegate: PublicKey.default,
+          mintDecimals: 0,
+        },
+        {
+          window: { startTs: new BN(now), endTs: new BN(now + 86400) },
+          target: { softCap: new BN(1), hardCap: new BN(1_000_000) },
+          pricing: {
+            model: { fixed: {} },
+            unitPrice: new BN(PRICE_LAMPORTS),
+            currency: { usd: {} },
+            quoteMint: PublicKey.default,
+          },
+          rules: {
+            minTicket: new BN(1),
+            perInvestorCap: new BN(1_000_000),
+            maxInvestors: 100,
+            eligibility: { jurisdictionsAllow: [], investorTypes: [] },
+          },
+          oversubPolicy: { proRata: {} },
+          feesBps: { originationBps: 0, platformBps: 0, successBps: 0 },
+          issuerTreasury: issuerKeypair.publicKey,
+          feeTreasury: payer.publicKey,
+        }
+      )
+      .accounts({
+        issuer: issuerKeypair.publicKey,
+        mint,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+
+    const [programAllowlistPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("program_allowlist"), mint.toBuffer()],
+      complianceProgram.programId
+    );
+    const [accountAllowlistPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("account_allowlist"), mint.toBuffer()],
+      complianceProgram.programId
+    );
+    const [inventoryCheckpointPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("checkpoint"), inventoryVaultPda.toBuffer()],
+      controllerProgram.programId
+    );
+    await orderProgram.methods
+      .initializeInventory()
+      .accountsPartial({
+        admin: issuerKeypair.publicKey,
+        mint,
+        srwaConfig: srwaConfigPda,
+        adminRegistry: null,
+        inventory: inventoryPda,
+        inventoryVault: inventoryVaultPda,
+        programAllowlist: programAllowlistPda,
+        accountAllowlist: accountAllowlistPda,
+        inventoryCheckpoint: inventoryCheckpointPda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+    await mintTo(
+      provider.connection,
+      payer.payer,
+      mint,
+      inventoryVaultPda,
+      issuerKeypair,
+      100,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    // KYC claim for the investor, issued by a trusted issuer of the mint
+    await factoryProgram.methods
+      .updateTrustedIssuer(KYC_TOPIC, issuerKeypair.publicKey, true)
+      .accounts({
+        authority: issuerKeypair.publicKey,
+        mint,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+    await identityProgram.methods
+      .registerIdentity([])
+      .accounts({ user: investorKeypair.publicKey })
+      .signers([investorKeypair])
+      .rpc();
+    await identityProgram.methods
+      .addClaim(KYC_TOPIC, Array(32).fill(0), new BN(now + 365 * 86400))
+      .accountsPartial({
+        issuer: issuerKeypair.publicKey,
+        holder: investorKeypair.publicKey,
+        claim: investorKycClaimPda,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+    investorAta = await createAssociatedTokenAccount(
+      provider.connection,
+      payer.payer,
+      mint,
+      investorKeypair.publicKey,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+  });
+
+  it("Allowlists the inventory vault as a venue account", async () => {
+    const [accountAllowlistPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("account_allowlist"), mint.toBuffer()],
+      complianceProgram.programId
+    );
+    const allowlist = await complianceProgram.account.accountAllowlist.fetch(
+      accountAllowlistPda
+    );
+    assert.isTrue(
+      allowlist.accounts.some((account) => account.equals(inventoryVaultPda))
+    );
+    console.log("✓ Inventory vault is a venue account of the mint");
+  });
+
+  it("Rejects an order that expires in the past", async () => {
+    try {
+      await createOrder(10, new BN(Math.floor(Date.now() / 1000) - 60));
+      assert.fail("Should have failed - expiry in the past");
+    } catch (err) {
+      assert.include(err.toString(), "InvalidExpiry");
+      console.log("✓ Orders must expire in the future");
+    }
+  });
+
+  describe("Open-ended order", () => {
+    let order: { purchaseOrder: PublicKey; escrow: PublicKey };
+
+    before(async () => {
+      order = await createOrder(10, null);
+    });
+
+    it("Holds the full payment in the order escrow", async () => {
+      const escrowInfo = await provider.connection.getAccountInfo(order.escrow);
+      const rent = await provider.connection.getMinimumBalanceForRentExemption(
+        escrowInfo.data.length
+      );
+      assert.equal(escrowInfo.lamports - rent, 10 * PRICE_LAMPORTS);
+      console.log("✓ Payment escrowed per order");
+    });
+
+    it("Only order admins can reject", async () => {
+      try {
+        await orderProgram.methods
+          .rejectPurchaseOrder("not allowed")
+          .accountsPartial({
+            admin: strangerKeypair.publicKey,
+            purchaseOrder: order.purchaseOrder,
+            srwaConfig: srwaConfigPda,
+            adminRegistry: null,
+            escrow: order.escrow,
+            investor: investorKeypair.publicKey,
+            quoteMint: null,
+            escrowTokenAccount: null,
+            investorQuoteAccount: null,
+            quoteTokenProgram: null,
+          })
+          .signers([strangerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - stranger cannot reject");
+      } catch (err) {
+        assert.include(err.toString(), "UnauthorizedAdmin");
+        console.log("✓ Unauthorized admins cannot reject orders");
+      }
+    });
+
+    it("Cannot be expired without an expiry", async () => {
+      try {
+        await orderProgram.methods
+          .expirePurchaseOrder()
+          .accountsPartial({
+            payer: strangerKeypair.publicKey,
+            purchaseOrder: order.purchaseOrder,
+            escrow: order.escrow,
+            investor: investorKeypair.publicKey,
+            quoteMint: null,
+            escrowTokenAccount: null,
+            investorQuoteAccount: null,
+            quoteTokenProgram: null,
+          })
+          .signers([strangerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - order never expires");
+      } catch (err) {
+        assert.include(err.toString(), "OrderNotExpired");
+        console.log("✓ Open-ended orders cannot be expired");
+      }
+    });
+
+    it("Rejects empty and oversized fills", async () => {
+      for (const fill of [0, 11]) {
+        try {
+          await orderProgram.methods
+            .approvePurchaseOrder(new BN(fill))
+            .accountsPartial(approveAccounts(order))
+            .signers([issuerKeypair])
+            .rpc();
+
+          assert.fail(`Should have failed - fill of ${fill}`);
+        } catch (err) {
+          assert.include(err.toString(), "InvalidFillQuantity");
+        }
+      }
+      console.log("✓ Fill quantity must be within the order");
+    });
+
+    it("Partially fills the order and refunds the rest", async () => {
+      const treasuryBefore = await provider.connection.getBalance(
+        issuerKeypair.publicKey
+      );
+
+      // Fee payer is the provider wallet, so the treasury only gains the cost
+      await orderProgram.methods
+        .approvePurchaseOrder(new BN(4))
+        .accountsPartial(approveAccounts(order))
+        .signers([issuerKeypair])
+        .rpc();
+
+      const delivered = await getAccount(
+        provider.connection,
+        investorAta,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(Number(delivered.amount), 4);
+
+      const treasuryAfter = await provider.connection.getBalance(
+        issuerKeypair.publicKey
+      );
+      assert.equal(treasuryAfter - treasuryBefore, 4 * PRICE_LAMPORTS);
+
+      const purchaseOrder = await orderProgram.account.purchaseOrder.fetch(
+        order.purchaseOrder
+      );
+      assert.equal(purchaseOrder.filledQuantity.toNumber(), 4);
+      assert.deepEqual(purchaseOrder.status, { approved: {} });
+      assert.isNull(await provider.connection.getAccountInfo(order.escrow));
+      console.log("✓ Partial fill paid the treasury and closed the escrow");
+    });
+  });
+
+  describe("Expiring order", () => {
+    let order: { purchaseOrder: PublicKey; escrow: PublicKey };
+
+    before(async () => {
+      order = await createOrder(5, new BN(Math.floor(Date.now() / 1000) + 2));
+      await new Promise((resolve) => setTimeout(resolve, 4000));
+    });
+
+    it("Cannot be approved after it expired", async () => {
+      try {
+        await orderProgram.methods
+          .approvePurchaseOrder(new BN(5))
+          .accountsPartial(approveAccounts(order))
+          .signers([issuerKeypair])
+          .rpc();
+
+        assert.fail("Should have failed - order expired");
+      } catch (err) {
+        assert.include(err.toString(), "OrderExpired");
+        console.log("✓ Expired orders cannot be filled");
+      }
+    });
+
+    it("Is expired by anyone, refunding the investor", async () => {
+      const investorBefore = await provider.connection.getBalance(
+        investorKeypair.publicKey
+      );
+
+      await orderProgram.methods
+        .expirePurchaseOrder()
+        .accountsPartial({
+          payer: strangerKeypair.publicKey,
+          purchaseOrder: order.purchaseOrder,
+          escrow: order.escrow,
+          investor: investorKeypair.publicKey,
+          quoteMint: null,
+          escrowTokenAccount: null,
+          investorQuoteAccount: null,
+          quoteTokenProgram: null,
+        })
+        .signers([strangerKeypair])
+        .rpc();
+
+      const investorAfter = await provider.connection.getBalance(
+        investorKeypair.publicKey
+      );
+      assert.isAtLeast(investorAfter - investorBefore, 5 * PRICE_LAMPORTS);
+      assert.isNull(await provider.connection.getAccountInfo(order.escrow));
+      console.log("✓ Expired order refunded and escrow closed");
+    });
+  });
+});
@@ -284,6 +284,7 @@ describe("SRWA Phase 1 Tests", () => {
           model: { fixed: {} },
           unitPrice: new BN(1_000_000), // $1 with 6 decimals
           currency: { usd: {} },
+          quoteMint: PublicKey.default,
         },
         rules: {
           minTicket: new BN(100_000_000), // 100 tokens
@@ -0,0 +1,586 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { SrwaFactory } from "../target/types/srwa_factory";
+import { SrwaController } from "../target/types/srwa_controller";
+import { ComplianceModules } from "../target/types/compliance_modules";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  AddressLookupTableProgram,
+  AddressLookupTableAccount,
+  ComputeBudgetProgram,
+  Transaction,
+  TransactionMessage,
+  VersionedTransaction,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  createAccount,
+  createAssociatedTokenAccount,
+  createTransferCheckedWithTransferHookInstruction,
+  mintTo,
+} from "@solana/spl-token";
+import { assert } from "chai";
+
+describe("Transfer hook rules", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const factoryProgram = anchor.workspace.SrwaFactory as Program<SrwaFactory>;
+  const controllerProgram = anchor.workspace
+    .SrwaController as Program<SrwaController>;
+  const complianceProgram = anchor.workspace
+    .ComplianceModules as Program<ComplianceModules>;
+
+  const payer = provider.wallet as anchor.Wallet;
+  const issuerKeypair = Keypair.generate();
+  const alice = Keypair.generate();
+  const bob = Keypair.generate();
+  // On-curve wallet owned by the System Program, which gets allowlisted
+  const venueWallet = Keypair.generate();
+  const mintKeypair = Keypair.generate();
+  const mint = mintKeypair.publicKey;
+
+  let adminRegistryPda: PublicKey;
+  let srwaConfigPda: PublicKey;
+  let extraAccountMetaListPda: PublicKey;
+  let holderCountPda: PublicKey;
+
+  let aliceAta: PublicKey;
+  let aliceSecondAccount: PublicKey;
+  let bobAta: PublicKey;
+  let venueAta: PublicKey;
+
+  let lookupTable: PublicKey;
+  const lookupTableKeys = new Set<string>();
+
+  const errorCode = (program: Program<any>, name: string) =>
+    program.idl.errors.find(
+      (error) => error.name.toLowerCase() === name.toLowerCase()
+    ).code;
+
+  const checkResult = (simulation: any, check: string) =>
+    simulation.checks.find(
+      (result: any) => Object.keys(result.check)[0] === check
+    );
+
+  // The hook's extra accounts do not fit a legacy transaction, so the
+  // simulation goes through a v0 transaction with a lookup table
+  async function extendLookupTable(keys: PublicKey[]) {
+    const missing = keys.filter((key) => !lookupTableKeys.has(key.toBase58()));
+    for (let i = 0; i < missing.length; i += 20) {
+      const addresses = missing.slice(i, i + 20);
+      await provider.sendAndConfirm(
+        new Transaction().add(
+          AddressLookupTableProgram.extendLookupTable({
+            lookupTable,
+            authority: payer.publicKey,
+            payer: payer.publicKey,
+            addresses,
+          })
+        )
+      );
+      addresses.forEach((key) => lookupTableKeys.add(key.toBase58()));
+    }
+
+    // Extended addresses are usable from the next slot
+    if (missing.length > 0) {
+      const slot = await provider.connection.getSlot("confirmed");
+      while ((await provider.connection.getSlot("confirmed")) <= slot) {
+        await new Promise((resolve) => setTimeout(resolve, 200));
+      }
+    }
+
+    return (await provider.connection.getAddressLookupTable(lookupTable))
+      .value as AddressLookupTableAccount;
+  }
+
+  async function simulateTransfer(
+    source: PublicKey,
+    destination: PublicKey,
+    owner: PublicKey,
+    amount: number
+  ) {
+    const transferIx = await createTransferCheckedWithTransferHookInstruction(
+      provider.connection,
+      source,
+      mint,
+      destination,
+      owner,
+      BigInt(amount),
+      6,
+      [],
+      "confirmed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    // Drop the base accounts and the trailing hook program and meta list
+    const extraAccounts = transferIx.keys.slice(4, -2);
+
+    const ix = await controllerProgram.methods
+      .simulateTransfer(new BN(amount))
+      .accountsPartial({
+        sourceToken: source,
+        mint,
+        destinationToken: destination,
+        authority: owner,
+        extraAccountMetaList: extraAccountMetaListPda,
+      })
+      .remainingAccounts(extraAccounts)
+      .instruction();
+
+    const table = await extendLookupTable(ix.keys.map((key) => key.pubkey));
+    const { blockhash } = await provider.connection.getLatestBlockhash();
+    const message = new TransactionMessage({
+      payerKey: payer.publicKey,
+      recentBlockhash: blockhash,
+      instructions: [
+        ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 }),
+        ix,
+      ],
+    }).compileToV0Message([table]);
+
+    const result = await provider.connection.simulateTransaction(
+      new VersionedTransaction(message),
+      { sigVerify: false, replaceRecentBlockhash: true }
+    );
+    assert.isNull(result.value.err, result.value.logs?.join("\n"));
+
+    const typeName = controllerProgram.idl.types.find(
+      (type) => type.name.toLowerCase() === "transfersimulation"
+    ).name;
+    return controllerProgram.coder.types.decode(
+      typeName,
+      Buffer.from(result.value.returnData.data[0], "base64")
+    );
+  }
+
+  before(async () => {
+    for (const keypair of [issuerKeypair, alice, bob, venueWallet]) {
+      const sig = await provider.connection.requestAirdrop(
+        keypair.publicKey,
+        2 * LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(sig);
+    }
+
+    [adminRegistryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("admin_registry")],
+      factoryProgram.programId
+    );
+    [srwaConfigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("srwa_config"), mint.toBuffer()],
+      factoryProgram.programId
+    );
+    [extraAccountMetaListPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("extra-account-metas"), mint.toBuffer()],
+      controllerProgram.programId
+    );
+    [holderCountPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("holder_count"), mint.toBuffer()],
+      controllerProgram.programId
+    );
+
+    try {
+      await factoryProgram.methods
+        .initializeAdminRegistry()
+        .accounts({
+          superAdmin: payer.publicKey,
+          adminRegistry: adminRegistryPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    } catch (err) {
+      console.log("Admin registry may already exist:", err.message);
+    }
+
+    try {
+      await factoryProgram.methods
+        .addPlatformAdmin(payer.publicKey)
+        .accounts({
+          superAdmin: payer.publicKey,
+          adminRegistry: adminRegistryPda,
+        })
+        .rpc();
+    } catch (err) {
+      console.log("Admin may already be added:", err.message);
+    }
+
+    // Hooked Token-2022 mint, minted by the issuer
+    const now = Math.floor(Date.now() / 1000);
+    const requestId = new BN(Date.now());
+    const [requestPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("srwa_request"),
+        issuerKeypair.publicKey.toBuffer(),
+        requestId.toArrayLike(Buffer, "le", 8),
+      ],
+      factoryProgram.programId
+    );
+
+    await factoryProgram.methods
+      .requestSrwa(
+        requestId,
+        mint,
+        "Hook Rules SRWA",
+        "HRSRWA",
+        6,
+        {
+          roles: {
+            issuerAdmin: issuerKeypair.publicKey,
+            complianceOfficer: issuerKeypair.publicKey,
+            transferAgent: issuerKeypair.publicKey,
+          },
+          requiredTopics: [],
+          metadataUri: "https://example.com/metadata.json",
+          defaultFrozen: false,
+          permanentDelegate: PublicKey.default,
+          mintDecimals: 6,
+        },
+        {
+          window: { startTs: new BN(now), endTs: new BN(now + 86400) },
+          target: { softCap: new BN(1), hardCap: new BN(1_000_000_000) },
+          pricing: {
+            model: { fixed: {} },
+            unitPrice: new BN(1_000_000),
+            currency: { usd: {} },
+            quoteMint: PublicKey.default,
+          },
+          rules: {
+            minTicket: new BN(1),
+            perInvestorCap: new BN(1_000_000_000),
+            maxInvestors: 100,
+            eligibility: { jurisdictionsAllow: [], investorTypes: [] },
+          },
+          oversubPolicy: { proRata: {} },
+          feesBps: { originationBps: 0, platformBps: 0, successBps: 0 },
+          issuerTreasury: issuerKeypair.publicKey,
+          feeTreasury: payer.publicKey,
+        },
+        { protocol: { marginfi: {} }, targetApyBps: 0 }
+      )
+      .accounts({
+        issuer: issuerKeypair.publicKey,
+        request: requestPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+
+    await factoryProgram.methods
+      .approveSrwa()
+      .accountsPartial({
+        admin: payer.publicKey,
+        adminRegistry: adminRegistryPda,
+        request: requestPda,
+        mint,
+        srwaConfig: srwaConfigPda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([mintKeypair])
+      .rpc();
+
+    await controllerProgram.methods
+      .initializeExtraAccountMetaList()
+      .accountsPartial({
+        payer: payer.publicKey,
+        mint,
+        extraAccountMetaList: extraAccountMetaListPda,
+      })
+      .rpc();
+
+    // Alice holds 1000 units over two accounts; Bob and the venue hold none
+    aliceAta = await createAssociatedTokenAccount(
+      provider.connection,
+      payer.payer,
+      mint,
+      alice.publicKey,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    aliceSecondAccount = await createAccount(
+      provider.connection,
+      payer.payer,
+      mint,
+      alice.publicKey,
+      Keypair.generate(),
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    bobAta = await createAssociatedTokenAccount(
+      provider.connection,
+      payer.payer,
+      mint,
+      bob.publicKey,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    venueAta = await createAssociatedTokenAccount(
+      provider.connection,
+      payer.payer,
+      mint,
+      venueWallet.publicKey,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await mintTo(
+      provider.connection,
+      payer.payer,
+      mint,
+      aliceAta,
+      issuerKeypair,
+      1_000,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    for (const user of [alice.publicKey, bob.publicKey]) {
+      await controllerProgram.methods
+        .initializeKycRegistry(true, true)
+        .accountsPartial({ authority: payer.publicKey, user })
+        .rpc();
+    }
+
+    // Holder tracking: Alice is the only holder and the cap is one
+    await controllerProgram.methods
+      .setHolderCount(0, 0)
+      .accountsPartial({
+        authority: issuerKeypair.publicKey,
+        mint,
+        srwaConfig: srwaConfigPda,
+        holderCount: holderCountPda,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+    await controllerProgram.methods
+      .setHolderPosition(alice.publicKey, new BN(1_000), false)
+      .accountsPartial({
+        authority: issuerKeypair.publicKey,
+        mint,
+        srwaConfig: srwaConfigPda,
+        holderCount: holderCountPda,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+    await controllerProgram.methods
+      .openHolderPosition(bob.publicKey)
+      .accountsPartial({ payer: payer.publicKey, mint })
+      .rpc();
+    await complianceProgram.methods
+      .configureMaxHolders(1, 0)
+      .accountsPartial({
+        authority: issuerKeypair.publicKey,
+        mint,
+        srwaConfig: srwaConfigPda,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+
+    // Per-holder daily cap of 100 units
+    for (const holder of [alice.publicKey, PublicKey.default]) {
+      await controllerProgram.methods
+        .openVolumeUsage(holder)
+        .accountsPartial({ payer: payer.publicKey, mint })
+        .rpc();
+    }
+    await complianceProgram.methods
+      .setVolumeCaps(new BN(0), new BN(0), new BN(0), new BN(100), new BN(0))
+      .accountsPartial({
+        authority: issuerKeypair.publicKey,
+        mint,
+        srwaConfig: srwaConfigPda,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+
+    const slot = await provider.connection.getSlot("finalized");
+    const [createIx, tableAddress] = AddressLookupTableProgram.createLookupTable({
+      authority: payer.publicKey,
+      payer: payer.publicKey,
+      recentSlot: slot,
+    });
+    await provider.sendAndConfirm(new Transaction().add(createIx));
+    lookupTable = tableAddress;
+  });
+
+  it("Approves a transfer between accounts of the same owner", async () => {
+    const simulation = await simulateTransfer(
+      aliceAta,
+      aliceSecondAccount,
+      alice.publicKey,
+      50
+    );
+
+    assert.isTrue(simulation.approved);
+    assert.isTrue(checkResult(simulation, "holderCaps").passed);
+    console.log("✓ Moving tokens between an owner's accounts adds no holder");
+  });
+
+  it("Rejects a new holder beyond the holder cap", async () => {
+    const simulation = await simulateTransfer(
+      aliceAta,
+      bobAta,
+      alice.publicKey,
+      50
+    );
+
+    assert.isFalse(simulation.approved);
+    const holderCaps = checkResult(simulation, "holderCaps");
+    assert.isFalse(holderCaps.passed);
+    assert.equal(
+      holderCaps.reason.toNumber(),
+      errorCode(complianceProgram, "MaxHoldersReached")
+    );
+    console.log("✓ Holders are counted per owner against the cap");
+  });
+
+  it("Reports custom modules as not evaluated when a rule rejects", async () => {
+    const simulation = await simulateTransfer(
+      aliceAta,
+      bobAta,
+      alice.publicKey,
+      50
+    );
+
+    const customModules = checkResult(simulation, "customModules");
+    assert.isFalse(customModules.evaluated);
+    assert.isFalse(customModules.passed);
+    assert.equal(customModules.reason.toNumber(), 0);
+    console.log("✓ Skipped custom modules are not reported as passed");
+  });
+
+  it("Rejects a transfer above the holder daily cap", async () => {
+    const simulation = await simulateTransfer(
+      aliceAta,
+      aliceSecondAccount,
+      alice.publicKey,
+      150
+    );
+
+    assert.isFalse(simulation.approved);
+    const volumeCaps = checkResult(simulation, "volumeCaps");
+    assert.equal(
+      volumeCaps.reason.toNumber(),
+      errorCode(complianceProgram, "HolderDailyCapExceeded")
+    );
+    console.log("✓ Holder daily volume cap is enforced");
+  });
+
+  it("Does not treat an on-curve wallet of an allowlisted program as a venue", async () => {
+    await complianceProgram.methods
+      .allowlistVenue(SystemProgram.programId, [])
+      .accountsPartial({
+        authority: issuerKeypair.publicKey,
+        mint,
+        srwaConfig: srwaConfigPda,
+        adminRegistry: null,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+
+    const simulation = await simulateTransfer(
+      aliceAta,
+      venueAta,
+      alice.publicKey,
+      10
+    );
+
+    const recipientKyc = checkResult(simulation, "recipientKyc");
+    assert.isFalse(recipientKyc.passed);
+    assert.equal(
+      recipientKyc.reason.toNumber(),
+      errorCode(controllerProgram, "InvalidKYCAccount")
+    );
+    console.log("✓ Wallets owned by an allowlisted program still need KYC");
+  });
+
+  it("Rejects a position above the owner's cap across all its accounts", async () => {
+    await complianceProgram.methods
+      .setInvestorProfile(
+        { retailQualified: {} },
+        {
+          dailyVolume: new BN(0),
+          positionCap: new BN(500),
+          concentrationBps: 0,
+        }
+      )
+      .accountsPartial({
+        authority: issuerKeypair.publicKey,
+        mint,
+        user: alice.publicKey,
+        srwaConfig: srwaConfigPda,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+
+    // The receiving account is empty, but Alice's position is 1000
+    const simulation = await simulateTransfer(
+      aliceAta,
+      aliceSecondAccount,
+      alice.publicKey,
+      10
+    );
+
+    const investorLimits = checkResult(simulation, "investorLimits");
+    assert.isFalse(investorLimits.passed);
+    assert.equal(
+      investorLimits.reason.toNumber(),
+      errorCode(complianceProgram, "PositionCapExceeded")
+    );
+    console.log("✓ Position cap applies to the owner's total position");
+  });
+
+  it("Rejects a custom module until the meta list lists the module slots", async () => {
+    await factoryProgram.methods
+      .initializeCustomModules()
+      .accountsPartial({
+        authority: issuerKeypair.publicKey,
+        mint,
+        srwaConfig: srwaConfigPda,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+
+    try {
+      await factoryProgram.methods
+        .addCustomModule()
+        .accountsPartial({
+          authority: issuerKeypair.publicKey,
+          mint,
+          srwaConfig: srwaConfigPda,
+          extraAccountMetaList: extraAccountMetaListPda,
+          moduleProgram: complianceProgram.programId,
+        })
+        .signers([issuerKeypair])
+        .rpc();
+
+      assert.fail("Should have failed - meta list lacks the module slots");
+    } catch (err) {
+      assert.include(err.toString(), "CustomModuleSlotsMissing");
+    }
+
+    await controllerProgram.methods
+      .updateExtraAccountMetaList()
+      .accountsPartial({
+        payer: payer.publicKey,
+        mint,
+        extraAccountMetaList: extraAccountMetaListPda,
+      })
+      .rpc();
+
+    await factoryProgram.methods
+      .addCustomModule()
+      .accountsPartial({
+        authority: issuerKeypair.publicKey,
+        mint,
+        srwaConfig: srwaConfigPda,
+        extraAccountMetaList: extraAccountMetaListPda,
+        moduleProgram: complianceProgram.programId,
+      })
+      .signers([issuerKeypair])
+      .rpc();
+    console.log("✓ Modules are only registered once the hook can reach them");
+  });
+});